@@ -0,0 +1,104 @@
+#![allow(dead_code)]
+
+use paper_plane::auth::Auth;
+use paper_plane::clients::reqwest::Client;
+use serde_json::json;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Number of documents in the "large archive" scenario.
+pub const LARGE_ARCHIVE: usize = 50_000;
+
+/// Builds the JSON body of a single page listing `count` documents, with a few
+/// hundred bytes of OCR content each.
+pub fn documents_page(count: usize) -> Vec<u8> {
+    let content = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(8);
+    let results = (1..=count)
+        .map(|id| {
+            json!({
+                "id": id,
+                "correspondent": id % 17,
+                "document_type": id % 5,
+                "storage_path": null,
+                "title": format!("Scanned document #{id}"),
+                "content": content,
+                "tags": [1, 2, id % 31],
+                "created": "2024-03-01T00:00:00+01:00",
+                "created_date": "2024-03-01",
+                "modified": "2024-03-02T10:11:12.345678+01:00",
+                "added": "2024-03-02T10:11:12.345678+01:00",
+                "deleted_at": null,
+                "archive_serial_number": id,
+                "original_file_name": format!("scan_{id}.pdf"),
+                "archived_file_name": format!("{id}.pdf"),
+                "owner": 1,
+                "permissions": {
+                    "view": { "users": [], "groups": [] },
+                    "change": { "users": [], "groups": [] },
+                },
+                "user_can_change": true,
+                "is_shared_by_requester": false,
+                "notes": [],
+                "custom_fields": [],
+                "page_count": 2,
+                "mime_type": "application/pdf",
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_vec(&json!({
+        "count": count,
+        "next": null,
+        "previous": null,
+        "all": (1..=count).collect::<Vec<_>>(),
+        "results": results,
+    }))
+    .unwrap()
+}
+
+/// Builds an opaque binary payload of `size` bytes, standing in for an
+/// exported archive.
+pub fn archive(size: usize) -> Vec<u8> {
+    (0..size).map(|i| (i % 251) as u8).collect()
+}
+
+/// Starts a minimal HTTP server on a random local port, answering every
+/// request with the given body, and returns its URL.
+pub async fn serve(body: Vec<u8>, content_type: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let body = Arc::new(body);
+    tokio::spawn(async move {
+        while let Ok((socket, _)) = listener.accept().await {
+            tokio::spawn(respond(socket, body.clone(), content_type));
+        }
+    });
+    url
+}
+
+async fn respond(mut socket: TcpStream, body: Arc<Vec<u8>>, content_type: &str) {
+    let header = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: {content_type}\r\ncontent-length: {}\r\n\r\n",
+        body.len()
+    );
+    let mut request = Vec::new();
+    let mut buffer = [0; 4096];
+    loop {
+        match socket.read(&mut buffer).await {
+            Ok(0) | Err(_) => return,
+            Ok(n) => request.extend_from_slice(&buffer[..n]),
+        }
+        while let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+            request.drain(..end + 4);
+            if socket.write_all(header.as_bytes()).await.is_err()
+                || socket.write_all(&body).await.is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+pub fn client(url: String) -> Client {
+    Client::new(url, Auth::Token("benchmark".to_string().into()))
+}
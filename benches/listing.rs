@@ -0,0 +1,53 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use paper_plane::clients::Client as _;
+use paper_plane::schema::api::documents;
+use paper_plane::services::Documents;
+
+mod common;
+
+fn documents_list(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut group = c.benchmark_group("documents_list");
+    group.sample_size(10);
+    for count in [1_000, 10_000, common::LARGE_ARCHIVE] {
+        let body = common::documents_page(count);
+        group.throughput(Throughput::Bytes(body.len() as u64));
+        let client = common::client(runtime.block_on(common::serve(body, "application/json")));
+        group.bench_with_input(BenchmarkId::from_parameter(count), &client, |b, client| {
+            b.to_async(&runtime).iter(|| async {
+                client
+                    .documents()
+                    .list(&documents::list())
+                    .await
+                    .unwrap()
+                    .value
+            });
+        });
+    }
+    group.finish();
+}
+
+fn documents_download(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut group = c.benchmark_group("documents_download");
+    group.sample_size(10);
+    for size in [1 << 20, 16 << 20, 128 << 20] {
+        let body = common::archive(size);
+        group.throughput(Throughput::Bytes(size as u64));
+        let client = common::client(runtime.block_on(common::serve(body, "application/zip")));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &client, |b, client| {
+            b.to_async(&runtime).iter(|| async {
+                client
+                    .documents()
+                    .download(1.into(), None)
+                    .await
+                    .unwrap()
+                    .value
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, documents_list, documents_download);
+criterion_main!(benches);
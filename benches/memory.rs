@@ -0,0 +1,126 @@
+//! Allocation regression checks for the request path.
+//!
+//! This is not a criterion benchmark: it runs each scenario once with a
+//! counting global allocator, and fails if the peak amount of memory allocated
+//! while performing the request exceeds the documented budget, expressed as a
+//! multiple of the size of the payload. See the "Memory budget" section of the
+//! README.
+
+use paper_plane::clients::Client as _;
+use paper_plane::schema::api::documents;
+use paper_plane::services::Documents;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+mod common;
+
+/// Peak memory allowed while listing documents: the raw body, plus the decoded
+/// models.
+const LISTING_BUDGET: f64 = 2.5;
+
+/// Peak memory allowed while downloading a file: the body itself, plus some
+/// slack for transport buffers.
+const DOWNLOAD_BUDGET: f64 = 1.25;
+
+struct Counting;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            track(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        CURRENT.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            track(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+fn track(allocated: usize, freed: usize) {
+    let current = CURRENT.fetch_add(allocated, Ordering::SeqCst) + allocated;
+    PEAK.fetch_max(current, Ordering::SeqCst);
+    CURRENT.fetch_sub(freed, Ordering::SeqCst);
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// Runs the given future, and returns the peak amount of memory allocated
+/// above what was already allocated before it started.
+async fn peak_during<F: Future>(f: F) -> usize {
+    let baseline = CURRENT.load(Ordering::SeqCst);
+    PEAK.store(baseline, Ordering::SeqCst);
+    drop(f.await);
+    PEAK.load(Ordering::SeqCst) - baseline
+}
+
+fn check(name: &str, payload: usize, peak: usize, budget: f64) -> bool {
+    #[allow(clippy::cast_precision_loss)]
+    let ratio = peak as f64 / payload as f64;
+    let ok = ratio <= budget;
+    println!(
+        "{name}: payload {:.1} MiB, peak {:.1} MiB, ratio {ratio:.2} (budget {budget:.2}) {}",
+        payload as f64 / 1_048_576.0,
+        peak as f64 / 1_048_576.0,
+        if ok { "ok" } else { "FAILED" },
+    );
+    ok
+}
+
+fn main() -> ExitCode {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let ok = runtime.block_on(async {
+        let listing = common::documents_page(common::LARGE_ARCHIVE);
+        let listing_size = listing.len();
+        let client = common::client(common::serve(listing, "application/json").await);
+        let list = documents::list();
+        // warm up the connection pool, so that it isn't accounted for
+        client.documents().list(&list).await.unwrap();
+        let listing_peak =
+            peak_during(async { client.documents().list(&list).await.unwrap() }).await;
+
+        let archive_size = 128 << 20;
+        let client =
+            common::client(common::serve(common::archive(archive_size), "application/zip").await);
+        client.documents().download(1.into(), None).await.unwrap();
+        let download_peak =
+            peak_during(async { client.documents().download(1.into(), None).await.unwrap() }).await;
+
+        let listing_ok = check(
+            "list 50k documents",
+            listing_size,
+            listing_peak,
+            LISTING_BUDGET,
+        );
+        let download_ok = check(
+            "download archive",
+            archive_size,
+            download_peak,
+            DOWNLOAD_BUDGET,
+        );
+        listing_ok && download_ok
+    });
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -1,24 +1,268 @@
+use async_trait::async_trait;
 use base64::engine::{Engine, general_purpose::STANDARD};
 use secure_string::SecureString;
+use std::fmt;
+use std::sync::Arc;
 
-#[derive(Debug, Clone)]
+use crate::error::{Error, Result};
+
+/// Credentials sent with each request, usually in the `Authorization`
+/// header. Secrets are shown as `***` by `Debug`.
+#[derive(Clone)]
 pub enum Auth {
+    /// HTTP basic authentication, with the username and password of a
+    /// Paperless user, for those who'd rather not manage API tokens.
     Basic {
         username: SecureString,
         password: SecureString,
     },
+    /// An API token, as generated in the Paperless profile page.
     Token(SecureString),
+    /// Credentials obtained on demand, for instance short-lived tokens issued
+    /// by an OIDC proxy; see [`CredentialProvider`]. Only supported by the
+    /// full `reqwest` client: the other clients fail to build the requests.
+    Dynamic(Arc<dyn CredentialProvider>),
+    /// Authentication by a single sign-on proxy, for servers configured with
+    /// `PAPERLESS_ENABLE_HTTP_REMOTE_USER_API`: the user is given in the
+    /// `header` trusted by Paperless, and no `Authorization` header is sent.
+    /// The session cookies of the proxy, if any, are sent with each request.
+    RemoteUser {
+        header: String,
+        username: String,
+        cookies: Vec<(String, SecureString)>,
+    },
+}
+
+/// Supplies the `Authorization` header of each request, for credentials that
+/// expire or that can't be known in advance.
+///
+/// The provider is asked for the header before each request is sent, and
+/// should therefore cache it. If the server answers with `401 Unauthorized`,
+/// the provider is asked again with `refresh` set, and the request is retried
+/// once with the new value.
+///
+/// Any async closure with the same signature as `authorization` implements
+/// this trait.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait CredentialProvider: Send + Sync {
+    async fn authorization(&self, refresh: bool) -> anyhow::Result<String>;
 }
 
 impl Auth {
     #[must_use]
-    pub fn header_value(&self) -> String {
+    pub fn basic(username: impl Into<SecureString>, password: impl Into<SecureString>) -> Self {
+        Auth::Basic {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn token(token: impl Into<SecureString>) -> Self {
+        Auth::Token(token.into())
+    }
+
+    /// Remote user authentication with the default header of Paperless,
+    /// `Remote-User`, and no cookies; see [`Auth::RemoteUser`].
+    #[must_use]
+    pub fn remote_user(username: impl Into<String>) -> Self {
+        Auth::RemoteUser {
+            header: "Remote-User".to_string(),
+            username: username.into(),
+            cookies: vec![],
+        }
+    }
+
+    /// Reads the credentials from the environment: `PAPERLESS_TOKEN` if set,
+    /// and otherwise `PAPERLESS_USERNAME` and `PAPERLESS_PASSWORD`.
+    pub fn from_env() -> Result<Self> {
+        if let Some(token) = env_var("PAPERLESS_TOKEN")? {
+            return Ok(Auth::token(token));
+        }
+        match (
+            env_var("PAPERLESS_USERNAME")?,
+            env_var("PAPERLESS_PASSWORD")?,
+        ) {
+            (Some(username), Some(password)) => Ok(Auth::basic(username, password)),
+            (Some(_), None) => Err(env_error("PAPERLESS_PASSWORD is not set")),
+            _ => Err(env_error(
+                "neither PAPERLESS_TOKEN nor PAPERLESS_USERNAME is set",
+            )),
+        }
+    }
+
+    /// Reads the API token of the given server from the platform's keychain,
+    /// when it is first needed; see [`KeyringToken`](crate::keyring::KeyringToken).
+    #[cfg(all(feature = "keyring", not(target_arch = "wasm32")))]
+    #[must_use]
+    pub fn keyring(server_url: impl Into<String>) -> Self {
+        Self::dynamic(crate::keyring::KeyringToken::new(server_url.into()))
+    }
+
+    #[must_use]
+    pub fn dynamic(provider: impl CredentialProvider + 'static) -> Self {
+        Auth::Dynamic(Arc::new(provider))
+    }
+
+    /// Value of the `Authorization` header; `None` for [`Auth::RemoteUser`],
+    /// which uses other headers, see [`Auth::headers`], and for
+    /// [`Auth::Dynamic`], whose value is only known asynchronously, see
+    /// [`Auth::authorization`].
+    #[must_use]
+    pub fn header_value(&self) -> Option<String> {
         match self {
-            Auth::Basic { username, password } => format!(
+            Auth::Basic { username, password } => Some(format!(
                 "Basic {}",
                 STANDARD.encode(format!("{}:{}", username.unsecure(), password.unsecure())),
-            ),
-            Auth::Token(token) => format!("Token {}", token.unsecure()),
+            )),
+            Auth::Token(token) => Some(format!("Token {}", token.unsecure())),
+            Auth::Dynamic(_) | Auth::RemoteUser { .. } => None,
+        }
+    }
+
+    /// Headers carrying the credentials; empty for [`Auth::Dynamic`].
+    #[must_use]
+    pub fn headers(&self) -> Vec<(String, String)> {
+        match self {
+            Auth::Basic { .. } | Auth::Token(_) => self
+                .header_value()
+                .map(|value| ("Authorization".to_string(), value))
+                .into_iter()
+                .collect(),
+            Auth::Dynamic(_) => vec![],
+            Auth::RemoteUser {
+                header,
+                username,
+                cookies,
+            } => {
+                let mut headers = vec![(header.clone(), username.clone())];
+                if !cookies.is_empty() {
+                    let cookies: Vec<String> = cookies
+                        .iter()
+                        .map(|(name, value)| format!("{name}={}", value.unsecure()))
+                        .collect();
+                    headers.push(("Cookie".to_string(), cookies.join("; ")));
+                }
+                headers
+            }
         }
     }
+
+    /// Headers carrying the credentials, for the clients that can't ask a
+    /// [`CredentialProvider`]: fails for [`Auth::Dynamic`], rather than
+    /// silently sending anonymous requests.
+    #[cfg_attr(
+        not(any(
+            feature = "hyper",
+            feature = "isahc",
+            feature = "reqwest",
+            feature = "tower",
+            feature = "ureq"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn static_headers(&self) -> anyhow::Result<Vec<(String, String)>> {
+        match self {
+            Auth::Dynamic(_) => Err(anyhow::anyhow!(
+                "dynamic credentials are not supported by this backend"
+            )),
+            _ => Ok(self.headers()),
+        }
+    }
+
+    /// Value of the `Authorization` header, obtained from the provider for
+    /// [`Auth::Dynamic`]; `refresh` is set when the previous value was
+    /// rejected by the server. `None` for [`Auth::RemoteUser`].
+    pub async fn authorization(&self, refresh: bool) -> anyhow::Result<Option<String>> {
+        match self {
+            Auth::Dynamic(provider) => provider.authorization(refresh).await.map(Some),
+            _ => Ok(self.header_value()),
+        }
+    }
+}
+
+/// Reads a variable of the environment; empty variables are considered unset.
+pub(crate) fn env_var(name: &str) -> Result<Option<String>> {
+    match std::env::var(name) {
+        Ok(value) if value.is_empty() => Ok(None),
+        Ok(value) => Ok(Some(value)),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(source) => Err(Error::ClientBuild {
+            source: anyhow::Error::new(source).context(format!("invalid {name}")),
+        }),
+    }
+}
+
+pub(crate) fn env_error(message: &'static str) -> Error {
+    Error::ClientBuild {
+        source: anyhow::anyhow!(message),
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<F, R> CredentialProvider for F
+where
+    F: Fn(bool) -> R + Send + Sync,
+    R: Future<Output = anyhow::Result<String>> + Send,
+{
+    async fn authorization(&self, refresh: bool) -> anyhow::Result<String> {
+        self(refresh).await
+    }
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Auth::Basic { .. } => f
+                .debug_struct("Basic")
+                .field("username", &"***")
+                .field("password", &"***")
+                .finish(),
+            Auth::Token(_) => f.debug_tuple("Token").field(&"***").finish(),
+            Auth::Dynamic(provider) => f.debug_tuple("Dynamic").field(provider).finish(),
+            Auth::RemoteUser {
+                header,
+                username,
+                cookies,
+            } => f
+                .debug_struct("RemoteUser")
+                .field("header", header)
+                .field("username", username)
+                .field(
+                    "cookies",
+                    &cookies
+                        .iter()
+                        .map(|(name, _)| (name, "***"))
+                        .collect::<Vec<_>>(),
+                )
+                .finish(),
+        }
+    }
+}
+
+impl fmt::Debug for dyn CredentialProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CredentialProvider")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Auth;
+
+    #[test]
+    fn header_value() {
+        assert_eq!(
+            Auth::token("abc").header_value().as_deref(),
+            Some("Token abc")
+        );
+        assert_eq!(
+            Auth::basic("user", "pass").header_value().as_deref(),
+            Some("Basic dXNlcjpwYXNz")
+        );
+        assert_eq!(Auth::remote_user("user").header_value(), None);
+        assert_eq!(Auth::remote_user("user").headers()[0].0, "Remote-User");
+    }
 }
@@ -0,0 +1,108 @@
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use std::thread::{self, Thread};
+
+use crate::clients::Client as ClientTrait;
+
+mod services;
+pub use services::*;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Synchronous wrapper around an asynchronous client.
+///
+/// This client drives calls to the wrapped client to completion on the
+/// current thread. It doesn't start any runtime: it is meant for clients that
+/// don't need one, such as the [`ureq`](super::ureq) and
+/// [`isahc`](super::isahc) ones; the `reqwest` client, which requires a
+/// `tokio` runtime, can't be used with it.
+///
+/// Each service has a blocking counterpart in this module, such as [`Tags`],
+/// with the same methods and accessors as the asynchronous one; [`Client::run`]
+/// can be used for anything else, such as streams.
+///
+/// ```no_run
+/// # use paper_plane::auth::Auth;
+/// # use paper_plane::clients::{blocking, ureq};
+/// # use paper_plane::schema::api::tags;
+/// # use paper_plane::clients::blocking::Tags;
+/// # fn main() -> paper_plane::error::Result<()> {
+/// # let (url, auth) = ("http://localhost:8000".to_string(), Auth::token("token"));
+/// let client = blocking::Client::new(ureq::Client::new(url, auth));
+/// let tags = client.tags().list(&tags::list())?.value;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Client<C = super::ureq::Client> {
+    inner: C,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl<C: ClientTrait> Client<C> {
+    #[must_use]
+    pub fn new(inner: C) -> Self {
+        Self { inner }
+    }
+
+    #[must_use]
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    #[must_use]
+    pub fn inner_mut(&mut self) -> &mut C {
+        &mut self.inner
+    }
+
+    /// Calls the given asynchronous closure with the wrapped client, and
+    /// blocks until it completes. The closure owns what it captures, such as
+    /// the parameters of the request, for as long as the call lasts.
+    pub fn run<F, R>(&self, f: F) -> R
+    where
+        F: AsyncFnOnce(&C) -> R,
+    {
+        block_on(f(&self.inner))
+    }
+
+    /// Blocks until the given future completes; useful for helpers that
+    /// combine several calls, such as the ones in [`crate::helpers`].
+    pub fn block_on<Fut: Future>(&self, future: Fut) -> Fut::Output {
+        block_on(future)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+/// Wakes the thread blocked on a future.
+struct Unpark(Thread);
+
+impl Wake for Unpark {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Polls the given future on the current thread, parking it until the future
+/// is woken up.
+fn block_on<Fut: Future>(future: Fut) -> Fut::Output {
+    let mut future = pin!(future);
+    let waker = Arc::new(Unpark(thread::current())).into();
+    let mut context = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
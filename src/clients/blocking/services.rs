@@ -0,0 +1,361 @@
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+
+use super::{Client, block_on};
+use crate::clients::Client as ClientTrait;
+use crate::error::Result;
+use crate::multipart::Part;
+use crate::response::Response;
+use crate::schema::{api, model};
+use crate::services;
+use crate::utils::Method;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Declares, for each service, a synchronous counterpart of its trait, with
+/// the same methods, implemented for the blocking client by driving the
+/// asynchronous ones to completion; and the corresponding accessor on the
+/// client.
+macro_rules! blocking_services {
+    ($(
+        $accessor:ident: $service:ident {
+            $(
+                $(#[$attr:meta])*
+                fn $method:ident(&self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty;
+            )*
+        }
+    )*) => {
+        $(
+            #[doc = concat!("Blocking version of [`services::", stringify!($service), "`].")]
+            pub trait $service<E = ()> {
+                $(
+                    $(#[$attr])*
+                    fn $method(&self $(, $arg: $ty)*) -> $ret;
+                )*
+            }
+
+            impl<C, E> $service<E> for Client<C>
+            where
+                C: ClientTrait<Extra = E>,
+            {
+                $(
+                    fn $method(&self $(, $arg: $ty)*) -> $ret {
+                        block_on(services::$service::$method(&self.inner $(, $arg)*))
+                    }
+                )*
+            }
+        )*
+
+        impl<C: ClientTrait> Client<C> {
+            $(
+                pub fn $accessor(&self) -> &impl $service<C::Extra> {
+                    self
+                }
+            )*
+        }
+    };
+}
+
+blocking_services! {
+    config: Config {
+        fn list(&self) -> Result<Response<Vec<model::ApplicationConfiguration>, E>>;
+        fn create(&self, body: &api::config::Create) -> Result<Response<model::ApplicationConfiguration, E>>;
+        fn retrieve(&self) -> Result<Response<model::ApplicationConfiguration, E>>;
+        fn patch(&self, body: &api::config::Patch) -> Result<Response<model::ApplicationConfiguration, E>>;
+        fn destroy(&self) -> Result<Response<(), E>>;
+    }
+    correspondents: Correspondents {
+        fn list(&self, params: &api::correspondents::List) -> Result<Response<model::Paginated<model::Correspondent>, E>>;
+        fn create(&self, body: &api::correspondents::Create) -> Result<Response<model::Correspondent, E>>;
+        fn retrieve(&self, id: model::CorrespondentId) -> Result<Response<model::Correspondent, E>>;
+        fn patch(&self, id: model::CorrespondentId, body: &api::correspondents::Patch) -> Result<Response<model::Correspondent, E>>;
+        fn destroy(&self, id: model::CorrespondentId) -> Result<Response<(), E>>;
+        fn previous_page(&self, current: &model::Paginated<model::Correspondent>) -> Result<Option<Response<model::Paginated<model::Correspondent>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::Correspondent>) -> Result<Option<Response<model::Paginated<model::Correspondent>, E>>>;
+        fn page(&self, current: &model::Paginated<model::Correspondent>, number: u32) -> Result<Option<Response<model::Paginated<model::Correspondent>, E>>>;
+    }
+    custom_fields: CustomFields {
+        fn list(&self, params: &api::custom_fields::List) -> Result<Response<model::Paginated<model::CustomField>, E>>;
+        fn create(&self, body: &api::custom_fields::Create) -> Result<Response<model::CustomField, E>>;
+        fn retrieve(&self, id: model::CustomFieldId) -> Result<Response<model::CustomField, E>>;
+        fn patch(&self, id: model::CustomFieldId, body: &api::custom_fields::Patch) -> Result<Response<model::CustomField, E>>;
+        fn destroy(&self, id: model::CustomFieldId) -> Result<Response<(), E>>;
+        fn previous_page(&self, current: &model::Paginated<model::CustomField>) -> Result<Option<Response<model::Paginated<model::CustomField>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::CustomField>) -> Result<Option<Response<model::Paginated<model::CustomField>, E>>>;
+        fn page(&self, current: &model::Paginated<model::CustomField>, number: u32) -> Result<Option<Response<model::Paginated<model::CustomField>, E>>>;
+    }
+    document_types: DocumentTypes {
+        fn list(&self, params: &api::document_types::List) -> Result<Response<model::Paginated<model::DocumentType>, E>>;
+        fn create(&self, body: &api::document_types::Create) -> Result<Response<model::DocumentType, E>>;
+        fn retrieve(&self, id: model::DocumentTypeId) -> Result<Response<model::DocumentType, E>>;
+        fn patch(&self, id: model::DocumentTypeId, body: &api::document_types::Patch) -> Result<Response<model::DocumentType, E>>;
+        fn destroy(&self, id: model::DocumentTypeId) -> Result<Response<(), E>>;
+        fn previous_page(&self, current: &model::Paginated<model::DocumentType>) -> Result<Option<Response<model::Paginated<model::DocumentType>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::DocumentType>) -> Result<Option<Response<model::Paginated<model::DocumentType>, E>>>;
+        fn page(&self, current: &model::Paginated<model::DocumentType>, number: u32) -> Result<Option<Response<model::Paginated<model::DocumentType>, E>>>;
+    }
+    documents: Documents {
+        fn list(&self, params: &api::documents::List) -> Result<Response<model::Paginated<model::Document>, E>>;
+        /// Lists the documents without their content, which is by far the
+        /// largest part of a document; the `fields` parameter is ignored.
+        fn list_summaries(&self, params: &api::documents::List) -> Result<Response<model::Paginated<model::DocumentSummary>, E>>;
+        /// Lists the documents with only the fields selected by the `fields`
+        /// parameter, such as `id`, `title` and `tags`.
+        fn list_partial(&self, params: &api::documents::List) -> Result<Response<model::Paginated<model::PartialDocument>, E>>;
+        fn retrieve(&self, id: model::DocumentId) -> Result<Response<model::Document, E>>;
+        fn patch(&self, id: model::DocumentId, body: &api::documents::Patch) -> Result<Response<model::Document, E>>;
+        fn destroy(&self, id: model::DocumentId) -> Result<Response<(), E>>;
+        fn preview(&self, id: model::DocumentId) -> Result<Response<Bytes, E>>;
+        fn thumbnail(&self, id: model::DocumentId) -> Result<Response<Bytes, E>>;
+        fn download(&self, id: model::DocumentId, original: Option<bool>) -> Result<Response<Bytes, E>>;
+        // `download_stream` is left out: its stream can only be consumed asynchronously
+        fn history(&self, id: model::DocumentId, params: &api::documents::History) -> Result<Response<model::Paginated<model::LogEntry>, E>>;
+        fn metadata(&self, id: model::DocumentId) -> Result<Response<model::DocumentMetadata, E>>;
+        fn share_links(&self, id: model::DocumentId) -> Result<Response<Vec<model::ShareLink>, E>>;
+        fn sugestions(&self, id: model::DocumentId) -> Result<Response<model::Suggestions, E>>;
+        fn next_asn(&self) -> Result<Response<model::Asn, E>>;
+        fn post_document(&self, params: &api::documents::Upload, document: Part) -> Result<Response<String, E>>;
+        fn previous_page(&self, current: &model::Paginated<model::Document>) -> Result<Option<Response<model::Paginated<model::Document>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::Document>) -> Result<Option<Response<model::Paginated<model::Document>, E>>>;
+        fn page(&self, current: &model::Paginated<model::Document>, number: u32) -> Result<Option<Response<model::Paginated<model::Document>, E>>>;
+    }
+    groups: Groups {
+        fn list(&self, params: &api::groups::List) -> Result<Response<model::Paginated<model::Group>, E>>;
+        fn create(&self, body: &api::groups::Create) -> Result<Response<model::Group, E>>;
+        fn retrieve(&self, id: model::GroupId) -> Result<Response<model::Group, E>>;
+        fn patch(&self, id: model::GroupId, body: &api::groups::Patch) -> Result<Response<model::Group, E>>;
+        fn destroy(&self, id: model::GroupId) -> Result<Response<(), E>>;
+        fn previous_page(&self, current: &model::Paginated<model::Group>) -> Result<Option<Response<model::Paginated<model::Group>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::Group>) -> Result<Option<Response<model::Paginated<model::Group>, E>>>;
+        fn page(&self, current: &model::Paginated<model::Group>, number: u32) -> Result<Option<Response<model::Paginated<model::Group>, E>>>;
+    }
+    logs: Logs {
+        fn list(&self) -> Result<Response<Vec<String>, E>>;
+        fn retrieve(&self, log_type: &str) -> Result<Response<Vec<String>, E>>;
+    }
+    mail_accounts: MailAccounts {
+        fn list(&self, params: &api::mail_accounts::List) -> Result<Response<model::Paginated<model::MailAccount>, E>>;
+        fn test(&self, body: &api::mail_accounts::Create) -> Result<Response<model::MailAccount, E>>;
+        fn create(&self, body: &api::mail_accounts::Create) -> Result<Response<model::MailAccount, E>>;
+        fn retrieve(&self, id: model::MailAccountId) -> Result<Response<model::MailAccount, E>>;
+        fn patch(&self, id: model::MailAccountId, body: &api::mail_accounts::Patch) -> Result<Response<model::MailAccount, E>>;
+        fn destroy(&self, id: model::MailAccountId) -> Result<Response<(), E>>;
+        fn process(&self, id: model::MailAccountId) -> Result<Response<(), E>>;
+        fn previous_page(&self, current: &model::Paginated<model::MailAccount>) -> Result<Option<Response<model::Paginated<model::MailAccount>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::MailAccount>) -> Result<Option<Response<model::Paginated<model::MailAccount>, E>>>;
+        fn page(&self, current: &model::Paginated<model::MailAccount>, number: u32) -> Result<Option<Response<model::Paginated<model::MailAccount>, E>>>;
+    }
+    mail_rules: MailRules {
+        fn list(&self, params: &api::mail_rules::List) -> Result<Response<model::Paginated<model::MailRule>, E>>;
+        fn create(&self, body: &api::mail_rules::Create) -> Result<Response<model::MailRule, E>>;
+        fn retrieve(&self, id: model::MailRuleId) -> Result<Response<model::MailRule, E>>;
+        fn patch(&self, id: model::MailRuleId, body: &api::mail_rules::Patch) -> Result<Response<model::MailRule, E>>;
+        fn destroy(&self, id: model::MailRuleId) -> Result<Response<(), E>>;
+        fn previous_page(&self, current: &model::Paginated<model::MailRule>) -> Result<Option<Response<model::Paginated<model::MailRule>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::MailRule>) -> Result<Option<Response<model::Paginated<model::MailRule>, E>>>;
+        fn page(&self, current: &model::Paginated<model::MailRule>, number: u32) -> Result<Option<Response<model::Paginated<model::MailRule>, E>>>;
+    }
+    profile: Profile {
+        fn retrieve(&self) -> Result<Response<model::Profile, E>>;
+        fn patch(&self, body: &api::profile::Patch) -> Result<Response<model::Profile, E>>;
+        fn generate_auth_token(&self) -> Result<Response<model::Secret, E>>;
+        fn totp_secret(&self) -> Result<Response<model::TotpSecret, E>>;
+        fn activate_totp(&self, body: &api::profile::ActivateTotp) -> Result<Response<model::TotpActivation, E>>;
+        fn deactivate_totp(&self) -> Result<Response<(), E>>;
+        fn disconnect_social_account(
+            &self,
+            id: model::SocialAccountId,
+        ) -> Result<Response<model::SocialAccountId, E>>;
+    }
+    saved_views: SavedViews {
+        fn list(&self, params: &api::saved_views::List) -> Result<Response<model::Paginated<model::SavedView>, E>>;
+        fn create(&self, body: &api::saved_views::Create) -> Result<Response<model::SavedView, E>>;
+        fn retrieve(&self, id: model::SavedViewId) -> Result<Response<model::SavedView, E>>;
+        fn patch(&self, id: model::SavedViewId, body: &api::saved_views::Patch) -> Result<Response<model::SavedView, E>>;
+        fn destroy(&self, id: model::SavedViewId) -> Result<Response<(), E>>;
+        fn previous_page(&self, current: &model::Paginated<model::SavedView>) -> Result<Option<Response<model::Paginated<model::SavedView>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::SavedView>) -> Result<Option<Response<model::Paginated<model::SavedView>, E>>>;
+        fn page(&self, current: &model::Paginated<model::SavedView>, number: u32) -> Result<Option<Response<model::Paginated<model::SavedView>, E>>>;
+    }
+    search: Search {
+        fn global(&self, params: &api::search::Global) -> Result<Response<model::GlobalSearchResult, E>>;
+        fn autocomplete(&self, params: &api::search::Autocomplete) -> Result<Response<Vec<String>, E>>;
+    }
+    share_links: ShareLinks {
+        fn list(&self, params: &api::share_links::List) -> Result<Response<model::Paginated<model::ShareLink>, E>>;
+        fn create(&self, body: &api::share_links::Create) -> Result<Response<model::ShareLink, E>>;
+        fn retrieve(&self, id: model::ShareLinkId) -> Result<Response<model::ShareLink, E>>;
+        fn patch(&self, id: model::ShareLinkId, body: &api::share_links::Patch) -> Result<Response<model::ShareLink, E>>;
+        fn destroy(&self, id: model::ShareLinkId) -> Result<Response<(), E>>;
+        fn previous_page(&self, current: &model::Paginated<model::ShareLink>) -> Result<Option<Response<model::Paginated<model::ShareLink>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::ShareLink>) -> Result<Option<Response<model::Paginated<model::ShareLink>, E>>>;
+        fn page(&self, current: &model::Paginated<model::ShareLink>, number: u32) -> Result<Option<Response<model::Paginated<model::ShareLink>, E>>>;
+    }
+    storage_paths: StoragePaths {
+        fn list(&self, params: &api::storage_paths::List) -> Result<Response<model::Paginated<model::StoragePath>, E>>;
+        fn create(&self, body: &api::storage_paths::Create) -> Result<Response<model::StoragePath, E>>;
+        fn retrieve(&self, id: model::StoragePathId) -> Result<Response<model::StoragePath, E>>;
+        fn patch(&self, id: model::StoragePathId, body: &api::storage_paths::Patch) -> Result<Response<model::StoragePath, E>>;
+        fn destroy(&self, id: model::StoragePathId) -> Result<Response<(), E>>;
+        fn previous_page(&self, current: &model::Paginated<model::StoragePath>) -> Result<Option<Response<model::Paginated<model::StoragePath>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::StoragePath>) -> Result<Option<Response<model::Paginated<model::StoragePath>, E>>>;
+        fn page(&self, current: &model::Paginated<model::StoragePath>, number: u32) -> Result<Option<Response<model::Paginated<model::StoragePath>, E>>>;
+    }
+    tags: Tags {
+        fn list(&self, params: &api::tags::List) -> Result<Response<model::Paginated<model::Tag>, E>>;
+        fn create(&self, body: &api::tags::Create) -> Result<Response<model::Tag, E>>;
+        fn retrieve(&self, id: model::TagId) -> Result<Response<model::Tag, E>>;
+        fn patch(&self, id: model::TagId, body: &api::tags::Patch) -> Result<Response<model::Tag, E>>;
+        fn destroy(&self, id: model::TagId) -> Result<Response<(), E>>;
+        fn previous_page(&self, current: &model::Paginated<model::Tag>) -> Result<Option<Response<model::Paginated<model::Tag>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::Tag>) -> Result<Option<Response<model::Paginated<model::Tag>, E>>>;
+        fn page(&self, current: &model::Paginated<model::Tag>, number: u32) -> Result<Option<Response<model::Paginated<model::Tag>, E>>>;
+    }
+    tasks: Tasks {
+        fn list(&self, params: &api::tasks::List) -> Result<Response<Vec<model::TaskView>, E>>;
+        fn retrieve(&self, id: model::TaskId) -> Result<Response<model::TaskView, E>>;
+        fn run(&self, body: &api::tasks::Create) -> Result<Response<model::TaskView, E>>;
+        fn acknowledge(&self, body: &[model::TaskId]) -> Result<Response<Vec<model::TaskId>, E>>;
+    }
+    token: Token {
+        /// Returns the API token of the given user, creating it if needed; the
+        /// credentials are those of the user, not of the client. Tokens are
+        /// rotated with [`Profile::generate_auth_token`](crate::services::Profile::generate_auth_token).
+        fn obtain(&self, body: &api::token::Obtain) -> Result<Response<model::AuthToken, E>>;
+    }
+    ui_settings: UiSettings {
+        fn retrieve(&self) -> Result<Response<model::UiSettings, E>>;
+        fn update(&self, body: &api::ui_settings::Create) -> Result<Response<(), E>>;
+    }
+    users: Users {
+        fn list(&self, params: &api::users::List) -> Result<Response<model::Paginated<model::User>, E>>;
+        fn create(&self, body: &api::users::Create) -> Result<Response<model::User, E>>;
+        fn retrieve(&self, id: model::UserId) -> Result<Response<model::User, E>>;
+        fn patch(&self, id: model::UserId, body: &api::users::Patch) -> Result<Response<model::User, E>>;
+        fn destroy(&self, id: model::UserId) -> Result<Response<(), E>>;
+        fn previous_page(&self, current: &model::Paginated<model::User>) -> Result<Option<Response<model::Paginated<model::User>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::User>) -> Result<Option<Response<model::Paginated<model::User>, E>>>;
+        fn page(&self, current: &model::Paginated<model::User>, number: u32) -> Result<Option<Response<model::Paginated<model::User>, E>>>;
+    }
+    workflows: Workflows {
+        fn list(&self, params: &api::workflows::List) -> Result<Response<model::Paginated<model::Workflow>, E>>;
+        fn create(&self, body: &api::workflows::Create) -> Result<Response<model::Workflow, E>>;
+        fn retrieve(&self, id: model::WorkflowId) -> Result<Response<model::Workflow, E>>;
+        fn patch(&self, id: model::WorkflowId, body: &api::workflows::Patch) -> Result<Response<model::Workflow, E>>;
+        fn destroy(&self, item: &model::Workflow) -> Result<Response<(), E>>;
+        fn previous_page(&self, current: &model::Paginated<model::Workflow>) -> Result<Option<Response<model::Paginated<model::Workflow>, E>>>;
+        fn next_page(&self, current: &model::Paginated<model::Workflow>) -> Result<Option<Response<model::Paginated<model::Workflow>, E>>>;
+        fn page(&self, current: &model::Paginated<model::Workflow>, number: u32) -> Result<Option<Response<model::Paginated<model::Workflow>, E>>>;
+    }
+}
+
+/// Blocking version of [`services::Raw`].
+pub trait Raw<E = ()> {
+    fn call<P, B, T>(
+        &self,
+        method: Method,
+        path: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<T, E>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+        T: for<'a> Deserialize<'a>;
+
+    /// Like [`Raw::call`], but returns the response as an untyped JSON value.
+    fn call_json<P, B>(
+        &self,
+        method: Method,
+        path: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<serde_json::Value, E>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync;
+}
+
+impl<C, E> Raw<E> for Client<C>
+where
+    C: ClientTrait<Extra = E>,
+{
+    fn call<P, B, T>(
+        &self,
+        method: Method,
+        path: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<T, E>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+        T: for<'a> Deserialize<'a>,
+    {
+        block_on(services::Raw::call(&self.inner, method, path, params, body))
+    }
+
+    fn call_json<P, B>(
+        &self,
+        method: Method,
+        path: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<serde_json::Value, E>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        block_on(services::Raw::call_json(
+            &self.inner,
+            method,
+            path,
+            params,
+            body,
+        ))
+    }
+}
+
+impl<C: ClientTrait> Client<C> {
+    pub fn raw(&self) -> &impl Raw<C::Extra> {
+        self
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use crate::clients::blocking::{self, Logs, Tags};
+    use crate::clients::mock;
+    use crate::schema::model::TagId;
+    use crate::utils::Method;
+
+    #[test]
+    fn services() {
+        let client = blocking::Client::new(mock::Client::new());
+        let logs = vec!["celery".to_string(), "paperless".to_string()];
+        client
+            .inner()
+            .enqueue(Method::GET, "/api/logs/", mock::Reply::json(&logs));
+        client
+            .inner()
+            .enqueue(Method::DELETE, "/api/tags/1/", mock::Reply::empty());
+
+        assert_eq!(client.logs().list().unwrap().value, logs);
+        Tags::destroy(&client, TagId::from(1)).unwrap();
+        let endpoints: Vec<_> = client
+            .inner()
+            .requests()
+            .into_iter()
+            .map(|request| (request.method, request.endpoint))
+            .collect();
+        assert_eq!(
+            endpoints,
+            [
+                (Method::GET, "/api/logs/".to_string()),
+                (Method::DELETE, "/api/tags/1/".to_string()),
+            ]
+        );
+        assert!(client.tags().retrieve(TagId::from(1)).is_err());
+    }
+}
@@ -0,0 +1,374 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::any::Any;
+use std::fmt;
+use std::marker::Sync;
+use std::sync::Arc;
+
+use crate::clients::{ByteStream, Client as ClientTrait};
+use crate::error::{Error, Result};
+use crate::multipart::Form;
+use crate::response;
+use crate::schema::model::ServerVersion;
+use crate::utils::Method;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Type-erased client, for applications that need to store clients of
+/// different types behind a single field, or to choose the backend at
+/// runtime:
+///
+/// ```ignore
+/// let client = if offline {
+///     DynClient::new(vcr::Client::replay(path)?)
+/// } else {
+///     DynClient::new(reqwest::Client::new(url, auth))
+/// };
+/// let tags = client.tags().list(&params).await?;
+/// ```
+///
+/// Parameters and bodies go through [`serde_json::Value`] before being
+/// handed to the wrapped client, and so do JSON responses before being
+/// decoded; the `Extra` of the wrapped client can be recovered with
+/// [`Any::downcast_ref`]. The client is cheap to clone.
+#[derive(Clone)]
+pub struct DynClient {
+    inner: Arc<dyn ErasedClient>,
+}
+
+/// The `Extra` of the wrapped client.
+pub type Extra = Box<dyn Any + Send + Sync>;
+
+pub type Response<R> = response::Response<R, Extra>;
+
+/// Object-safe version of [`Client`](ClientTrait), implemented for all
+/// clients; see [`DynClient`].
+#[async_trait]
+pub trait ErasedClient: Send + Sync {
+    async fn erased_json(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(String, String)],
+        body: Option<&Value>,
+    ) -> Result<Response<Value>>;
+
+    async fn erased_bytes(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(String, String)],
+        body: Option<&Value>,
+    ) -> Result<Response<Bytes>>;
+
+    async fn erased_unit(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(String, String)],
+        body: Option<&Value>,
+    ) -> Result<Response<()>>;
+
+    async fn erased_multipart(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(String, String)],
+        form: Form,
+    ) -> Result<Response<Value>>;
+
+    async fn erased_stream(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(String, String)],
+        body: Option<&Value>,
+    ) -> Result<Response<ByteStream>>;
+
+    fn erased_server_version(&self) -> Option<ServerVersion>;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl DynClient {
+    #[must_use]
+    pub fn new(client: impl ErasedClient + 'static) -> Self {
+        Self {
+            inner: Arc::new(client),
+        }
+    }
+
+    #[must_use]
+    pub fn inner(&self) -> &dyn ErasedClient {
+        self.inner.as_ref()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+fn erase<R, E>(resp: response::Response<R, E>) -> Response<R>
+where
+    E: Any + Send + Sync,
+{
+    Response {
+        value: resp.value,
+        extra: Box::new(resp.extra),
+    }
+}
+
+/// Converts the parameters to the pairs of the query string, the way
+/// `serde_urlencoded` would: fields that are `None` are skipped.
+fn erase_params<P>(method: Method, endpoint: &str, params: &P) -> Result<Vec<(String, String)>>
+where
+    P: Serialize,
+{
+    let error = |message: String| Error::RequestBuild {
+        method,
+        endpoint: endpoint.to_string(),
+        source: anyhow::anyhow!(message),
+    };
+    let pairs: Vec<(String, Value)> = match serde_json::to_value(params) {
+        Ok(Value::Null) => vec![],
+        Ok(Value::Object(fields)) => fields.into_iter().collect(),
+        Ok(Value::Array(items)) => items
+            .into_iter()
+            .map(|item| serde_json::from_value(item).map_err(|e| error(e.to_string())))
+            .collect::<Result<_>>()?,
+        Ok(value) => return Err(error(format!("unsupported parameters: {value}"))),
+        Err(e) => return Err(error(e.to_string())),
+    };
+    let mut result = Vec::with_capacity(pairs.len());
+    for (key, value) in pairs {
+        match value {
+            Value::Null => {}
+            Value::String(value) => result.push((key, value)),
+            Value::Bool(_) | Value::Number(_) => result.push((key, value.to_string())),
+            value => return Err(error(format!("unsupported parameter {key}: {value}"))),
+        }
+    }
+    Ok(result)
+}
+
+fn erase_body<B>(method: Method, endpoint: &str, body: Option<&B>) -> Result<Option<Value>>
+where
+    B: Serialize,
+{
+    body.map(serde_json::to_value)
+        .transpose()
+        .map_err(|source| Error::RequestBuild {
+            method,
+            endpoint: endpoint.to_string(),
+            source: source.into(),
+        })
+}
+
+fn restore<R>(method: Method, endpoint: &str, value: Value) -> Result<R>
+where
+    R: for<'a> Deserialize<'a>,
+{
+    let content = value.to_string();
+    #[cfg(not(feature = "strict"))]
+    let result = serde_json::from_value(value);
+    #[cfg(feature = "strict")]
+    let result = crate::utils::deserialize_strict(value);
+    result.map_err(|source| Error::Deserializing {
+        method,
+        endpoint: endpoint.to_string(),
+        typename: std::any::type_name::<R>(),
+        content,
+        source,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+#[async_trait]
+impl<C> ErasedClient for C
+where
+    C: ClientTrait + Send,
+    C::Extra: Any + Send + Sync,
+{
+    async fn erased_json(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(String, String)],
+        body: Option<&Value>,
+    ) -> Result<Response<Value>> {
+        let resp = self.request_json(method, endpoint, &params, body).await?;
+        Ok(erase(resp))
+    }
+
+    async fn erased_bytes(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(String, String)],
+        body: Option<&Value>,
+    ) -> Result<Response<Bytes>> {
+        let resp = self.request_bytes(method, endpoint, &params, body).await?;
+        Ok(erase(resp))
+    }
+
+    async fn erased_unit(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(String, String)],
+        body: Option<&Value>,
+    ) -> Result<Response<()>> {
+        let resp = self.request_unit(method, endpoint, &params, body).await?;
+        Ok(erase(resp))
+    }
+
+    async fn erased_multipart(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(String, String)],
+        form: Form,
+    ) -> Result<Response<Value>> {
+        let resp = self
+            .request_multipart(method, endpoint, &params, form)
+            .await?;
+        Ok(erase(resp))
+    }
+
+    async fn erased_stream(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(String, String)],
+        body: Option<&Value>,
+    ) -> Result<Response<ByteStream>> {
+        let resp = self.request_stream(method, endpoint, &params, body).await?;
+        Ok(erase(resp))
+    }
+
+    fn erased_server_version(&self) -> Option<ServerVersion> {
+        self.server_version()
+    }
+}
+
+#[async_trait]
+impl ClientTrait for DynClient {
+    type Extra = Extra;
+
+    async fn request_json<P, B, R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<R>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+        R: for<'a> Deserialize<'a>,
+    {
+        let params = erase_params(method, endpoint, params)?;
+        let body = erase_body(method, endpoint, body)?;
+        let resp = self
+            .inner
+            .erased_json(method, endpoint, &params, body.as_ref())
+            .await?;
+        let value = restore(method, endpoint, resp.value)?;
+        Ok(Response {
+            value,
+            extra: resp.extra,
+        })
+    }
+
+    async fn request_bytes<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<Bytes>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let params = erase_params(method, endpoint, params)?;
+        let body = erase_body(method, endpoint, body)?;
+        self.inner
+            .erased_bytes(method, endpoint, &params, body.as_ref())
+            .await
+    }
+
+    async fn request_unit<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<()>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let params = erase_params(method, endpoint, params)?;
+        let body = erase_body(method, endpoint, body)?;
+        self.inner
+            .erased_unit(method, endpoint, &params, body.as_ref())
+            .await
+    }
+
+    async fn request_multipart<P, R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        form: Form,
+    ) -> Result<Response<R>>
+    where
+        P: Serialize + Sync,
+        R: for<'a> Deserialize<'a>,
+    {
+        let params = erase_params(method, endpoint, params)?;
+        let resp = self
+            .inner
+            .erased_multipart(method, endpoint, &params, form)
+            .await?;
+        let value = restore(method, endpoint, resp.value)?;
+        Ok(Response {
+            value,
+            extra: resp.extra,
+        })
+    }
+
+    async fn request_stream<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<ByteStream>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let params = erase_params(method, endpoint, params)?;
+        let body = erase_body(method, endpoint, body)?;
+        self.inner
+            .erased_stream(method, endpoint, &params, body.as_ref())
+            .await
+    }
+
+    fn server_version(&self) -> Option<ServerVersion> {
+        self.inner.erased_server_version()
+    }
+}
+
+impl fmt::Debug for DynClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynClient").finish_non_exhaustive()
+    }
+}
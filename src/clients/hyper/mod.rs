@@ -0,0 +1,370 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::StreamExt;
+use http_body_util::{BodyExt, Full};
+use hyper_util::client::legacy::connect::{Connect, HttpConnector};
+use hyper_util::rt::TokioExecutor;
+use serde::{Deserialize, Serialize};
+use std::marker::Sync;
+use std::time::{Duration, Instant};
+
+use crate::auth::Auth;
+use crate::clients::{ByteStream, Client as ClientTrait, json};
+use crate::error::{Error, Result};
+use crate::response;
+use crate::utils::Method;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// The underlying `hyper` client used by [`Client`].
+pub type Inner<C> = hyper_util::client::legacy::Client<C, Full<Bytes>>;
+
+/// Client based directly on `hyper`, for applications that already maintain a
+/// tuned `hyper` stack.
+///
+/// The client is generic over the connector, which determines how
+/// connections are established: the default `HttpConnector` only supports
+/// plain HTTP; for HTTPS, use [`Client::with_inner`] with a TLS connector,
+/// such as the ones provided by `hyper-rustls` or `hyper-tls`.
+#[derive(Debug, Clone)]
+pub struct Client<C = HttpConnector> {
+    inner: Inner<C>,
+    server_url: String,
+    auth: Auth,
+    additional_headers: Vec<(String, String)>,
+    max_error_content: Option<usize>,
+}
+
+pub struct Extra {
+    pub method: Method,
+    pub endpoint: String,
+    pub status: hyper::StatusCode,
+    pub headers: hyper::HeaderMap,
+    pub duration: Duration,
+    pub content_type: Option<String>,
+}
+
+pub type Response<R> = response::Response<R, Extra>;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl Extra {
+    /// Length of the response body, as advertised by the server; for
+    /// instance, the total of a [`progress::track`](crate::progress::track)ed
+    /// download.
+    #[must_use]
+    pub fn content_length(&self) -> Option<u64> {
+        self.headers
+            .get(hyper::header::CONTENT_LENGTH)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|h| h.parse().ok())
+    }
+}
+
+impl Client {
+    /// Creates a client for a server reachable over plain HTTP.
+    #[must_use]
+    pub fn new(server_url: String, auth: Auth) -> Self {
+        let inner = hyper_util::client::legacy::Client::builder(TokioExecutor::new())
+            .build(HttpConnector::new());
+        Self::with_inner(inner, server_url, auth)
+    }
+}
+
+impl<C> Client<C> {
+    #[must_use]
+    pub fn with_inner(inner: Inner<C>, server_url: String, auth: Auth) -> Self {
+        Self {
+            inner,
+            server_url,
+            auth,
+            additional_headers: vec![],
+            max_error_content: None,
+        }
+    }
+
+    #[must_use]
+    pub fn inner(&self) -> &Inner<C> {
+        &self.inner
+    }
+
+    #[must_use]
+    pub fn auth(&self) -> &Auth {
+        &self.auth
+    }
+
+    pub fn set_auth(&mut self, auth: Auth) {
+        self.auth = auth;
+    }
+
+    #[must_use]
+    pub fn additional_headers(&self) -> &[(String, String)] {
+        &self.additional_headers
+    }
+
+    #[must_use]
+    pub fn additional_headers_mut(&mut self) -> &mut Vec<(String, String)> {
+        &mut self.additional_headers
+    }
+
+    /// Maximum number of bytes of a response body that are kept in
+    /// [`Error::Deserializing`] when decoding fails. Unbounded by default.
+    #[must_use]
+    pub fn max_error_content(&self) -> Option<usize> {
+        self.max_error_content
+    }
+
+    #[must_use]
+    pub fn max_error_content_mut(&mut self) -> &mut Option<usize> {
+        &mut self.max_error_content
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public helpers
+
+#[must_use]
+pub fn translate_method(method: Method) -> hyper::Method {
+    match method {
+        Method::GET => hyper::Method::GET,
+        Method::PUT => hyper::Method::PUT,
+        Method::POST => hyper::Method::POST,
+        Method::PATCH => hyper::Method::PATCH,
+        Method::DELETE => hyper::Method::DELETE,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl<C> Client<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    fn build<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> anyhow::Result<hyper::Request<Full<Bytes>>>
+    where
+        P: Serialize,
+        B: Serialize,
+    {
+        let query = serde_urlencoded::to_string(params)?;
+        let mut uri = format!("{}{endpoint}", self.server_url);
+        if !query.is_empty() {
+            uri.push(if uri.contains('?') { '&' } else { '?' });
+            uri.push_str(&query);
+        }
+        let mut request = hyper::Request::builder()
+            .method(translate_method(method))
+            .uri(uri)
+            .header(hyper::header::ACCEPT, "application/json; version=9");
+        let content = match body {
+            Some(body) => {
+                request = request.header(hyper::header::CONTENT_TYPE, "application/json");
+                serde_json::to_vec(body)?
+            }
+            None => vec![],
+        };
+        for (header_name, header_value) in self
+            .auth
+            .static_headers()?
+            .iter()
+            .chain(&self.additional_headers)
+        {
+            request = request.header(header_name, header_value);
+        }
+        Ok(request.body(Full::new(content.into()))?)
+    }
+
+    /// Sends the request, and returns the response if its status is a
+    /// success.
+    async fn send<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<(hyper::Response<hyper::body::Incoming>, Extra)>
+    where
+        P: Serialize,
+        B: Serialize,
+    {
+        let request = self
+            .build(method, endpoint, params, body)
+            .map_err(|source| Error::RequestBuild {
+                method,
+                endpoint: endpoint.to_string(),
+                source,
+            })?;
+        let start = Instant::now();
+        let resp = self
+            .inner
+            .request(request)
+            .await
+            .map_err(|source| Error::RequestSend {
+                method,
+                endpoint: endpoint.to_string(),
+                request_id: None,
+                source: source.into(),
+            })?;
+        let duration = start.elapsed();
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let content_type = headers
+            .get(hyper::header::CONTENT_TYPE)
+            .and_then(|h| h.to_str().ok())
+            .map(String::from);
+
+        if status.is_client_error() || status.is_server_error() {
+            let content = match read_body(method, endpoint, resp).await {
+                Err(_) => serde_json::Value::String("<failed to retrieve content>".to_string()),
+                Ok(content) => serde_json::from_slice(&content).unwrap_or_else(|_| {
+                    serde_json::Value::String(String::from_utf8_lossy(&content).into_owned())
+                }),
+            };
+            return Err(Error::Server {
+                method,
+                endpoint: endpoint.to_string(),
+                request_id: None,
+                status: format!("{status}"),
+                content,
+                source: anyhow::anyhow!("HTTP status {status}"),
+            });
+        }
+
+        Ok((
+            resp,
+            Extra {
+                method,
+                endpoint: endpoint.to_string(),
+                status,
+                headers,
+                duration,
+                content_type,
+            },
+        ))
+    }
+}
+
+async fn read_body(
+    method: Method,
+    endpoint: &str,
+    resp: hyper::Response<hyper::body::Incoming>,
+) -> Result<Bytes> {
+    Ok(resp
+        .into_body()
+        .collect()
+        .await
+        .map_err(|source| Error::ResponseBody {
+            method,
+            endpoint: endpoint.to_string(),
+            source: source.into(),
+        })?
+        .to_bytes())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+#[async_trait]
+impl<C> ClientTrait for Client<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    type Extra = Extra;
+
+    async fn request_json<P, B, R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<R>>
+    where
+        B: Serialize + Sync,
+        P: Serialize + Sync,
+        R: for<'a> Deserialize<'a>,
+    {
+        let (resp, extra) = self.send(method, endpoint, params, body).await?;
+
+        if extra.content_type != Some("application/json".to_string()) {
+            return Err(Error::ContentType {
+                method,
+                endpoint: endpoint.to_string(),
+                expected: vec!["application/json".to_string()],
+                received: extra.content_type,
+            });
+        }
+
+        let content = read_body(method, endpoint, resp).await?;
+        Ok(Response {
+            value: json::decode(method, endpoint, content.into(), self.max_error_content)?,
+            extra,
+        })
+    }
+
+    async fn request_bytes<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<Bytes>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let (resp, extra) = self.send(method, endpoint, params, body).await?;
+        Ok(Response {
+            value: read_body(method, endpoint, resp).await?,
+            extra,
+        })
+    }
+
+    async fn request_unit<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<()>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let (_, extra) = self.send(method, endpoint, params, body).await?;
+        Ok(Response { value: (), extra })
+    }
+
+    async fn request_stream<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<ByteStream>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let (resp, extra) = self.send(method, endpoint, params, body).await?;
+        let endpoint = endpoint.to_string();
+        let stream = resp.into_body().into_data_stream().map(move |chunk| {
+            chunk.map_err(|source| Error::ResponseBody {
+                method,
+                endpoint: endpoint.clone(),
+                source: source.into(),
+            })
+        });
+        Ok(Response {
+            value: Box::pin(stream),
+            extra,
+        })
+    }
+}
@@ -0,0 +1,108 @@
+use crate::error::{Error, Result};
+use crate::utils::Method;
+
+/// Decodes a JSON body directly from the received bytes, without copying it
+/// into an intermediate `String` first. The raw content is only converted to a
+/// string, and optionally truncated, when decoding fails.
+///
+/// With the `simd-json` feature, decoding is done with `simd-json` instead of
+/// `serde_json`, in place, in the buffer handed over by the backend. Since
+/// `simd-json` errors don't say where decoding failed, failed bodies are
+/// decoded again with `serde_json`, so that [`Error::Deserializing`], and the
+/// line and column it reports, are the same regardless of the backend.
+/// `simd-json` only rewrites the strings that contain escape sequences: when
+/// the body has some, it can't be decoded again, and the error only reports
+/// the message of `simd-json`, and the body as it was left.
+///
+/// With the `strict` feature, decoding is always done with `serde_json`, and
+/// fails on unknown fields; see [`deserialize_strict`](crate::utils::deserialize_strict).
+pub(crate) fn decode<R>(
+    method: Method,
+    endpoint: &str,
+    mut content: Vec<u8>,
+    max_error_content: Option<usize>,
+) -> Result<R>
+where
+    R: for<'a> serde::Deserialize<'a>,
+{
+    from_slice(&mut content).map_err(|source| {
+        let mut content = String::from_utf8_lossy(&content).into_owned();
+        if let Some(limit) = max_error_content
+            && content.len() > limit
+        {
+            let mut end = limit;
+            while !content.is_char_boundary(end) {
+                end -= 1;
+            }
+            content.truncate(end);
+            content.push_str("...");
+        }
+        Error::Deserializing {
+            method,
+            endpoint: endpoint.to_string(),
+            typename: std::any::type_name::<R>(),
+            content,
+            source,
+        }
+    })
+}
+
+#[cfg(not(any(feature = "simd-json", feature = "strict")))]
+fn from_slice<R>(content: &mut [u8]) -> serde_json::Result<R>
+where
+    R: for<'a> serde::Deserialize<'a>,
+{
+    serde_json::from_slice(content)
+}
+
+#[cfg(feature = "strict")]
+fn from_slice<R>(content: &mut [u8]) -> serde_json::Result<R>
+where
+    R: for<'a> serde::Deserialize<'a>,
+{
+    let mut de = serde_json::Deserializer::from_slice(content);
+    let value = crate::utils::deserialize_strict(&mut de)?;
+    de.end()?;
+    Ok(value)
+}
+
+#[cfg(all(feature = "simd-json", not(feature = "strict")))]
+fn from_slice<R>(content: &mut [u8]) -> serde_json::Result<R>
+where
+    R: for<'a> serde::Deserialize<'a>,
+{
+    let escaped = content.contains(&b'\\');
+    simd_json::serde::from_slice(content).or_else(|error| {
+        if escaped {
+            Err(serde::de::Error::custom(error))
+        } else {
+            serde_json::from_slice(content)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+    use crate::error::Error;
+    use crate::utils::Method;
+
+    #[test]
+    fn errors_report_their_position() {
+        let content = b"{\n  \"id\": 1,\n  \"name\": oops\n}".to_vec();
+        let error =
+            decode::<serde_json::Value>(Method::GET, "/api/tags/1/", content, None).unwrap_err();
+        let Error::Deserializing { source, .. } = error else {
+            panic!("unexpected error: {error:?}");
+        };
+        assert_eq!((source.line(), source.column()), (3, 11));
+    }
+
+    #[test]
+    fn escaped_strings_are_decoded() {
+        let content = br#"{"name": "a\"b\u00e9", "other": "c"}"#.to_vec();
+        let value: serde_json::Value = decode(Method::GET, "/api/tags/1/", content, None).unwrap();
+        assert_eq!(value["name"], "a\"b\u{e9}");
+        assert_eq!(value["other"], "c");
+    }
+}
@@ -0,0 +1,299 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::marker::Sync;
+use std::sync::{Mutex, PoisonError};
+
+use crate::clients::{Client as ClientTrait, json};
+use crate::error::{Error, Result};
+use crate::multipart::Form;
+use crate::response;
+use crate::utils::Method;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Client that doesn't send any request, for tests.
+///
+/// Responses are queued per method and endpoint with [`Client::enqueue`], and
+/// returned in order; calling an endpoint for which no response is queued
+/// fails with [`Error::RequestSend`]. All requests are recorded, so that tests
+/// can check what was sent:
+///
+/// ```ignore
+/// let client = mock::Client::new();
+/// client.enqueue(Method::GET, "/api/tags/1/", mock::Reply::json(&tag));
+/// let result = client.tags().retrieve(1).await?;
+/// assert_eq!(client.requests()[0].endpoint, "/api/tags/1/");
+/// ```
+#[derive(Debug, Default)]
+pub struct Client {
+    replies: Mutex<BTreeMap<(Method, String), VecDeque<Reply>>>,
+    requests: Mutex<Vec<Request>>,
+}
+
+/// A canned response.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reply {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Bytes,
+}
+
+/// A request received by the mock client.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Request {
+    pub method: Method,
+    pub endpoint: String,
+    pub params: Vec<(String, String)>,
+    pub body: Option<serde_json::Value>,
+}
+
+pub struct Extra {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+}
+
+pub type Response<R> = response::Response<R, Extra>;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl Client {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a reply for the given method and endpoint, after the ones
+    /// already queued for them.
+    pub fn enqueue(&self, method: Method, endpoint: &str, reply: Reply) {
+        lock(&self.replies)
+            .entry((method, endpoint.to_string()))
+            .or_default()
+            .push_back(reply);
+    }
+
+    /// Number of queued replies that haven't been used yet.
+    #[must_use]
+    pub fn pending(&self) -> usize {
+        lock(&self.replies).values().map(VecDeque::len).sum()
+    }
+
+    /// All requests received so far, in order.
+    #[must_use]
+    pub fn requests(&self) -> Vec<Request> {
+        lock(&self.requests).clone()
+    }
+
+    /// Returns all requests received so far, and forgets them.
+    #[must_use]
+    pub fn take_requests(&self) -> Vec<Request> {
+        std::mem::take(&mut *lock(&self.requests))
+    }
+}
+
+impl Reply {
+    /// A `200 OK` reply with the given value as JSON body.
+    #[must_use]
+    pub fn json<T: Serialize>(value: &T) -> Self {
+        Self {
+            status: 200,
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body: serde_json::to_vec(value).unwrap_or_default().into(),
+        }
+    }
+
+    /// A `200 OK` reply with the given raw body.
+    #[must_use]
+    pub fn bytes(body: Bytes) -> Self {
+        Self {
+            status: 200,
+            headers: vec![],
+            body,
+        }
+    }
+
+    /// A `204 No Content` reply.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            status: 204,
+            headers: vec![],
+            body: Bytes::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn status(mut self, value: u16) -> Self {
+        self.status = value;
+        self
+    }
+
+    #[must_use]
+    pub fn header(mut self, name: String, value: String) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl Client {
+    /// Records the request, and returns the corresponding reply if its status
+    /// is a success.
+    fn reply<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<(Bytes, Extra)>
+    where
+        P: Serialize,
+        B: Serialize,
+    {
+        let request =
+            record(method, endpoint, params, body).map_err(|source| Error::RequestBuild {
+                method,
+                endpoint: endpoint.to_string(),
+                source,
+            })?;
+        lock(&self.requests).push(request);
+
+        let reply = lock(&self.replies)
+            .get_mut(&(method, endpoint.to_string()))
+            .and_then(VecDeque::pop_front)
+            .ok_or_else(|| Error::RequestSend {
+                method,
+                endpoint: endpoint.to_string(),
+                request_id: None,
+                source: anyhow::anyhow!("no reply queued for {method:?} {endpoint}"),
+            })?;
+
+        if reply.status >= 400 {
+            let content = serde_json::from_slice(&reply.body).unwrap_or_else(|_| {
+                serde_json::Value::String(String::from_utf8_lossy(&reply.body).into_owned())
+            });
+            return Err(Error::Server {
+                method,
+                endpoint: endpoint.to_string(),
+                request_id: None,
+                status: reply.status.to_string(),
+                content,
+                source: anyhow::anyhow!("HTTP status {}", reply.status),
+            });
+        }
+
+        Ok((
+            reply.body,
+            Extra {
+                status: reply.status,
+                headers: reply.headers,
+            },
+        ))
+    }
+}
+
+pub(crate) fn record<P, B>(
+    method: Method,
+    endpoint: &str,
+    params: &P,
+    body: Option<&B>,
+) -> anyhow::Result<Request>
+where
+    P: Serialize,
+    B: Serialize,
+{
+    let query = serde_urlencoded::to_string(params)?;
+    Ok(Request {
+        method,
+        endpoint: endpoint.to_string(),
+        params: serde_urlencoded::from_str(&query)?,
+        body: body.map(serde_json::to_value).transpose()?,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ClientTrait for Client {
+    type Extra = Extra;
+
+    async fn request_json<P, B, R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<R>>
+    where
+        B: Serialize + Sync,
+        P: Serialize + Sync,
+        R: for<'a> Deserialize<'a>,
+    {
+        let (content, extra) = self.reply(method, endpoint, params, body)?;
+        Ok(Response {
+            value: json::decode(method, endpoint, content.into(), None)?,
+            extra,
+        })
+    }
+
+    async fn request_bytes<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<Bytes>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let (value, extra) = self.reply(method, endpoint, params, body)?;
+        Ok(Response { value, extra })
+    }
+
+    /// Records the fields of the form as the body of the request, as a list
+    /// of name and value pairs; files are described by their name and type.
+    async fn request_multipart<P, R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        form: Form,
+    ) -> Result<Response<R>>
+    where
+        P: Serialize + Sync,
+        R: for<'a> Deserialize<'a>,
+    {
+        let (content, extra) = self.reply(method, endpoint, params, Some(&form.summary()))?;
+        Ok(Response {
+            value: json::decode(method, endpoint, content.into(), None)?,
+            extra,
+        })
+    }
+
+    async fn request_unit<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<()>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let (_, extra) = self.reply(method, endpoint, params, body)?;
+        Ok(Response { value: (), extra })
+    }
+}
@@ -1,24 +1,85 @@
 use async_trait::async_trait;
 use bytes::Bytes;
+use futures_util::Stream;
 use serde::{Deserialize, Serialize};
 use std::marker::Sync;
+use std::pin::Pin;
+use url::Url;
 
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::multipart::Form;
 use crate::response::Response;
-use crate::schema::model::Paginated;
+use crate::schema::model::{Paginated, ServerVersion};
 use crate::services;
 use crate::utils::{Method, body, extract_params};
 
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+#[cfg(any(
+    feature = "hyper",
+    feature = "isahc",
+    feature = "mock",
+    feature = "reqwest",
+    feature = "tower",
+    feature = "ureq"
+))]
+pub(crate) mod json;
+
 ////////////////////////////////////////////////////////////////////////////////
 // Public implementations
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod dynamic;
+
+#[cfg(feature = "hyper")]
+pub mod hyper;
+
+#[cfg(feature = "isahc")]
+pub mod isahc;
+
+#[cfg(feature = "mock")]
+pub mod mock;
+
 #[cfg(feature = "reqwest")]
 pub mod reqwest;
 
+pub mod servers;
+
+#[cfg(feature = "tower")]
+pub mod tower;
+
+#[cfg(feature = "ureq")]
+pub mod ureq;
+
+#[cfg(feature = "vcr")]
+pub mod vcr;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Version of the Paperless-ngx REST API that this crate's schema is written
+/// against, and that clients request by default.
+pub const DEFAULT_API_VERSION: u32 = 9;
+
+/// Stream of the chunks of a response body, as returned by
+/// [`Client::request_stream`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>;
+
+/// Stream of the chunks of a response body, as returned by
+/// [`Client::request_stream`].
+#[cfg(target_arch = "wasm32")]
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Bytes>>>>;
+
 ////////////////////////////////////////////////////////////////////////////////
 // Public trait
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait Client: Sized + Sync {
     // network
 
@@ -58,8 +119,72 @@ pub trait Client: Sized + Sync {
         P: Serialize + Sync,
         B: Serialize + Sync;
 
+    /// Sends a `multipart/form-data` request, and decodes the JSON response.
+    /// Streamed parts of the form are sent as they are read; since they can
+    /// only be read once, such requests are never retried. The default
+    /// implementation fails: not all clients support multipart requests.
+    async fn request_multipart<P, R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        form: Form,
+    ) -> Result<Response<R, Self::Extra>>
+    where
+        P: Serialize + Sync,
+        R: for<'a> Deserialize<'a>,
+    {
+        let _ = (params, form);
+        Err(Error::RequestBuild {
+            method,
+            endpoint: endpoint.to_string(),
+            source: anyhow::anyhow!("this client doesn't support multipart requests"),
+        })
+    }
+
+    /// Like [`Client::request_bytes`], but returns the body as a stream of
+    /// chunks, to avoid holding large files in memory. The default
+    /// implementation buffers the whole body and yields it as a single chunk;
+    /// clients that can do better override it.
+    async fn request_stream<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<ByteStream, Self::Extra>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let resp = self.request_bytes(method, endpoint, params, body).await?;
+        let chunk = Ok(resp.value.clone());
+        Ok(resp.replace(Box::pin(futures_util::stream::once(async { chunk }))))
+    }
+
+    // compatibility
+
+    /// Version of the server, if known; requests to parts of the API that
+    /// this version doesn't provide then fail early with
+    /// [`Error::Unsupported`], instead of with a server error. The default
+    /// implementation doesn't know the version, and lets all requests go.
+    fn server_version(&self) -> Option<ServerVersion> {
+        None
+    }
+
     // pagination
 
+    /// Fetches the page of a listing at the given URL, such as the `next` link
+    /// of a [`Paginated`] value; works for any paginated endpoint, including
+    /// those called with [`Client::request_json`].
+    async fn follow_page_url<T>(&self, url: &Url) -> Result<Response<Paginated<T>, Self::Extra>>
+    where
+        T: for<'a> Deserialize<'a>,
+    {
+        self.request_json(Method::GET, url.path(), &extract_params(url), body::NONE)
+            .await
+    }
+
     async fn previous_page<T>(
         &self,
         current: &Paginated<T>,
@@ -67,12 +192,9 @@ pub trait Client: Sized + Sync {
     where
         T: for<'a> Deserialize<'a> + Sync,
     {
-        if let Some(url) = current.raw_previous_url() {
-            self.request_json(Method::GET, url.path(), &extract_params(url), body::NONE)
-                .await
-                .map(Some)
-        } else {
-            Ok(None)
+        match current.raw_previous_url() {
+            Some(url) => self.follow_page_url(url).await.map(Some),
+            None => Ok(None),
         }
     }
 
@@ -83,12 +205,26 @@ pub trait Client: Sized + Sync {
     where
         T: for<'a> Deserialize<'a> + Sync,
     {
-        if let Some(url) = current.raw_next_url() {
-            self.request_json(Method::GET, url.path(), &extract_params(url), body::NONE)
-                .await
-                .map(Some)
-        } else {
-            Ok(None)
+        match current.raw_next_url() {
+            Some(url) => self.follow_page_url(url).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches the given page of the same listing as `current`, for random
+    /// access; pages are numbered from 1. Returns `None` if the listing has a
+    /// single page, see [`Paginated::page_url`].
+    async fn page<T>(
+        &self,
+        current: &Paginated<T>,
+        number: u32,
+    ) -> Result<Option<Response<Paginated<T>, Self::Extra>>>
+    where
+        T: for<'a> Deserialize<'a> + Sync,
+    {
+        match current.page_url(number) {
+            Some(url) => self.follow_page_url(&url).await.map(Some),
+            None => Ok(None),
         }
     }
 
@@ -164,6 +300,13 @@ pub trait Client: Sized + Sync {
         self
     }
 
+    fn raw(&self) -> &impl services::Raw<Self::Extra>
+    where
+        Self: Sized,
+    {
+        self
+    }
+
     fn saved_views(&self) -> &impl services::SavedViews<Self::Extra>
     where
         Self: Sized,
@@ -171,6 +314,13 @@ pub trait Client: Sized + Sync {
         self
     }
 
+    fn search(&self) -> &impl services::Search<Self::Extra>
+    where
+        Self: Sized,
+    {
+        self
+    }
+
     fn share_links(&self) -> &impl services::ShareLinks<Self::Extra>
     where
         Self: Sized,
@@ -199,6 +349,20 @@ pub trait Client: Sized + Sync {
         self
     }
 
+    fn token(&self) -> &impl services::Token<Self::Extra>
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    fn ui_settings(&self) -> &impl services::UiSettings<Self::Extra>
+    where
+        Self: Sized,
+    {
+        self
+    }
+
     fn users(&self) -> &impl services::Users<Self::Extra>
     where
         Self: Sized,
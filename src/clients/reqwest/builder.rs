@@ -0,0 +1,619 @@
+use std::collections::BTreeMap;
+#[cfg(unix)]
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use url::Url;
+
+#[cfg(feature = "tracing")]
+use super::Logging;
+#[cfg(feature = "lru-cache")]
+use super::LruCache;
+use super::{
+    CircuitBreaker, Client, Interceptor, RateLimiter, RedirectPolicy, ResponseCache, RetryAfter,
+    RetryPolicy,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use super::{ConcurrencyLimiter, Deduplicator};
+use crate::auth::{Auth, env_error, env_var};
+use crate::clients::{Client as ClientTrait, DEFAULT_API_VERSION};
+use crate::error::{Error, Result};
+use crate::metrics::Metrics;
+use crate::schema::api::token;
+use crate::schema::model::ServerVersion;
+use crate::services::Token as _;
+use crate::signing::Signer;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Configures a [`Client`], including the connection options of the
+/// underlying `reqwest` client. In the browser, connections are managed by
+/// `fetch`, and most of those options are unavailable.
+#[derive(Debug)]
+pub struct ClientBuilder {
+    inner: reqwest::ClientBuilder,
+    server_url: String,
+    auth: Auth,
+    api_version: u32,
+    server_version: Option<ServerVersion>,
+    additional_headers: Vec<(String, String)>,
+    max_error_content: Option<usize>,
+    signer: Option<Arc<dyn Signer>>,
+    retry_policy: Option<RetryPolicy>,
+    retry_after: Option<RetryAfter>,
+    rate_limiter: Option<RateLimiter>,
+    circuit_breaker: Option<CircuitBreaker>,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    metrics: Option<Arc<dyn Metrics>>,
+    #[cfg(feature = "tracing")]
+    logging: Option<Logging>,
+    response_cache: Option<ResponseCache>,
+    redirect_policy: Option<RedirectPolicy>,
+    request_id_header: Option<reqwest::header::HeaderName>,
+    #[cfg(not(target_arch = "wasm32"))]
+    concurrency_limiter: Option<ConcurrencyLimiter>,
+    #[cfg(not(target_arch = "wasm32"))]
+    deduplicator: Option<Deduplicator>,
+    #[cfg(feature = "lru-cache")]
+    lru_cache: Option<LruCache>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl ClientBuilder {
+    #[must_use]
+    pub fn new(server_url: String, auth: Auth) -> Self {
+        Self {
+            inner: reqwest::Client::builder(),
+            server_url,
+            auth,
+            api_version: DEFAULT_API_VERSION,
+            server_version: None,
+            additional_headers: vec![],
+            max_error_content: None,
+            signer: None,
+            retry_policy: None,
+            retry_after: None,
+            rate_limiter: None,
+            circuit_breaker: None,
+            interceptors: vec![],
+            metrics: None,
+            #[cfg(feature = "tracing")]
+            logging: None,
+            response_cache: None,
+            redirect_policy: None,
+            request_id_header: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            concurrency_limiter: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            deduplicator: None,
+            #[cfg(feature = "lru-cache")]
+            lru_cache: None,
+        }
+    }
+
+    /// Configures a client from the environment, as other Paperless tools
+    /// do: the server is read from `PAPERLESS_URL`, the credentials as
+    /// described in [`Auth::from_env`], and additional headers, if any, from
+    /// `PAPERLESS_HEADERS`, as a JSON object mapping names to values.
+    pub fn from_env() -> Result<Self> {
+        let url = env_var("PAPERLESS_URL")?.ok_or_else(|| env_error("PAPERLESS_URL is not set"))?;
+        Url::parse(&url).map_err(|source| Error::ClientBuild {
+            source: anyhow::Error::new(source).context("invalid PAPERLESS_URL"),
+        })?;
+        let mut builder = Self::new(url.trim_end_matches('/').to_string(), Auth::from_env()?);
+        if let Some(headers) = env_var("PAPERLESS_HEADERS")? {
+            let headers: BTreeMap<String, String> =
+                serde_json::from_str(&headers).map_err(|source| Error::ClientBuild {
+                    source: anyhow::Error::new(source).context("invalid PAPERLESS_HEADERS"),
+                })?;
+            builder = builder.additional_headers(headers.into_iter().collect());
+        }
+        Ok(builder)
+    }
+
+    /// Timeout for establishing a connection to the server.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn connect_timeout(mut self, value: Duration) -> Self {
+        self.inner = self.inner.connect_timeout(value);
+        self
+    }
+
+    /// Timeout for each read of the response; the timer is reset after each
+    /// successful read, so that large downloads aren't interrupted.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn read_timeout(mut self, value: Duration) -> Self {
+        self.inner = self.inner.read_timeout(value);
+        self
+    }
+
+    /// Timeout for the whole request, from connection to the end of the
+    /// response body.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.inner = self.inner.timeout(value);
+        self
+    }
+
+    /// Maximum number of idle connections kept open per host; unbounded by
+    /// default.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn pool_max_idle_per_host(mut self, value: usize) -> Self {
+        self.inner = self.inner.pool_max_idle_per_host(value);
+        self
+    }
+
+    /// How long idle connections are kept open; 90 seconds by default, and
+    /// forever if `None`.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn pool_idle_timeout(mut self, value: Option<Duration>) -> Self {
+        self.inner = self.inner.pool_idle_timeout(value);
+        self
+    }
+
+    /// Interval of the TCP keepalive probes, which keep long-lived idle
+    /// connections from being dropped by firewalls; 15 seconds by default,
+    /// and disabled if `None`.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn tcp_keepalive(mut self, value: Option<Duration>) -> Self {
+        self.inner = self.inner.tcp_keepalive(value);
+        self
+    }
+
+    /// Whether sockets set `TCP_NODELAY`, disabling Nagle's algorithm; true
+    /// by default.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn tcp_nodelay(mut self, value: bool) -> Self {
+        self.inner = self.inner.tcp_nodelay(value);
+        self
+    }
+
+    /// Speaks HTTP/2 without negotiating it first, for servers or proxies
+    /// that accept cleartext HTTP/2 (h2c). Over TLS, HTTP/2 is negotiated
+    /// automatically with the `http2` feature.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.inner = self.inner.http2_prior_knowledge();
+        self
+    }
+
+    /// Never uses HTTP/2, even if the server supports it.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn http1_only(mut self) -> Self {
+        self.inner = self.inner.http1_only();
+        self
+    }
+
+    /// Adapts the HTTP/2 flow control windows to the bandwidth of the
+    /// connection, which helps when many streams share a single connection;
+    /// this overrides the window sizes.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn http2_adaptive_window(mut self, value: bool) -> Self {
+        self.inner = self.inner.http2_adaptive_window(value);
+        self
+    }
+
+    /// Flow control window of each HTTP/2 stream, in bytes.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn http2_initial_stream_window_size(mut self, value: u32) -> Self {
+        self.inner = self.inner.http2_initial_stream_window_size(value);
+        self
+    }
+
+    /// Flow control window of each HTTP/2 connection, shared by all its
+    /// streams, in bytes.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn http2_initial_connection_window_size(mut self, value: u32) -> Self {
+        self.inner = self.inner.http2_initial_connection_window_size(value);
+        self
+    }
+
+    /// Interval of the HTTP/2 pings that keep idle connections alive;
+    /// disabled by default.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn http2_keep_alive_interval(mut self, value: Duration) -> Self {
+        self.inner = self.inner.http2_keep_alive_interval(value);
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn proxy(mut self, value: reqwest::Proxy) -> Self {
+        self.inner = self.inner.proxy(value);
+        self
+    }
+
+    /// Connects to the server through the given Unix domain socket, for
+    /// instance one exposed by a local proxy or sidecar, rather than over TCP.
+    /// The server URL is still used for the `Host` header and the path of
+    /// requests, and its scheme still determines whether TLS is used:
+    ///
+    /// ```ignore
+    /// let client = Client::builder("http://paperless".to_string(), auth)
+    ///     .unix_socket("/run/paperless/api.sock")
+    ///     .build()?;
+    /// ```
+    ///
+    /// This disables proxies, and the other TCP connection options.
+    #[cfg(unix)]
+    #[must_use]
+    pub fn unix_socket(mut self, path: impl Into<PathBuf>) -> Self {
+        self.inner = self.inner.unix_socket(path.into());
+        self
+    }
+
+    /// Ignores the proxies set in the environment, such as `HTTPS_PROXY`.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn no_proxy(mut self) -> Self {
+        self.inner = self.inner.no_proxy();
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn min_tls_version(mut self, value: reqwest::tls::Version) -> Self {
+        self.inner = self.inner.min_tls_version(value);
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn max_tls_version(mut self, value: reqwest::tls::Version) -> Self {
+        self.inner = self.inner.max_tls_version(value);
+        self
+    }
+
+    /// Trusts the certificate authority in the given PEM file, in addition to
+    /// the system's, for instance for self-hosted servers whose certificate is
+    /// signed by an internal authority:
+    ///
+    /// ```ignore
+    /// let client = Client::builder(url, auth)
+    ///     .add_root_certificate_pem(&std::fs::read("internal-ca.pem")?)?
+    ///     .build()?;
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn add_root_certificate_pem(mut self, pem: &[u8]) -> Result<Self> {
+        let certificate =
+            reqwest::Certificate::from_pem(pem).map_err(|source| Error::ClientBuild {
+                source: source.into(),
+            })?;
+        self.inner = self.inner.add_root_certificate(certificate);
+        Ok(self)
+    }
+
+    /// Authenticates with the given client certificate, for deployments where
+    /// a reverse proxy enforces mutual TLS; `der` is a PKCS#12 archive
+    /// containing the certificate and its private key.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn identity_pkcs12(mut self, der: &[u8], password: &str) -> Result<Self> {
+        let identity = reqwest::Identity::from_pkcs12_der(der, password).map_err(|source| {
+            Error::ClientBuild {
+                source: source.into(),
+            }
+        })?;
+        self.inner = self.inner.identity(identity);
+        Ok(self)
+    }
+
+    /// Authenticates with the given client certificate, for deployments where
+    /// a reverse proxy enforces mutual TLS; `certificate` is a PEM chain, and
+    /// `key` the matching PEM private key, in PKCS#8 format.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn identity_pem(mut self, certificate: &[u8], key: &[u8]) -> Result<Self> {
+        let identity = reqwest::Identity::from_pkcs8_pem(certificate, key).map_err(|source| {
+            Error::ClientBuild {
+                source: source.into(),
+            }
+        })?;
+        self.inner = self.inner.identity(identity);
+        Ok(self)
+    }
+
+    /// Accepts any certificate, including self-signed and expired ones.
+    ///
+    /// This makes the connection vulnerable to man-in-the-middle attacks: it
+    /// should only be used for testing, or on trusted networks; prefer
+    /// [`ClientBuilder::add_root_certificate_pem`] whenever possible.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn danger_accept_invalid_certs(mut self, value: bool) -> Self {
+        self.inner = self.inner.danger_accept_invalid_certs(value);
+        self
+    }
+
+    /// Refuses to send requests over plain HTTP.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn https_only(mut self, value: bool) -> Self {
+        self.inner = self.inner.https_only(value);
+        self
+    }
+
+    /// Whether to accept gzip-compressed responses; enabled by default with
+    /// the `gzip` feature. Responses are decompressed transparently.
+    #[cfg(all(feature = "gzip", not(target_arch = "wasm32")))]
+    #[must_use]
+    pub fn gzip(mut self, value: bool) -> Self {
+        self.inner = self.inner.gzip(value);
+        self
+    }
+
+    /// Whether to accept brotli-compressed responses; enabled by default with
+    /// the `brotli` feature. Responses are decompressed transparently.
+    #[cfg(all(feature = "brotli", not(target_arch = "wasm32")))]
+    #[must_use]
+    pub fn brotli(mut self, value: bool) -> Self {
+        self.inner = self.inner.brotli(value);
+        self
+    }
+
+    /// Follows redirects according to the given policy; see
+    /// [`RedirectPolicy`].
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn redirect_policy(mut self, value: RedirectPolicy) -> Self {
+        self.inner = self.inner.redirect(reqwest::redirect::Policy::none());
+        self.redirect_policy = Some(value);
+        self
+    }
+
+    /// Headers sent with every request by the underlying `reqwest` client.
+    #[must_use]
+    pub fn default_headers(mut self, value: reqwest::header::HeaderMap) -> Self {
+        self.inner = self.inner.default_headers(value);
+        self
+    }
+
+    /// Adds a single header to the ones sent with every request by the
+    /// underlying `reqwest` client, replacing any previous value.
+    #[must_use]
+    pub fn default_header(
+        mut self,
+        name: reqwest::header::HeaderName,
+        value: reqwest::header::HeaderValue,
+    ) -> Self {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(name, value);
+        self.inner = self.inner.default_headers(headers);
+        self
+    }
+
+    #[must_use]
+    pub fn user_agent(mut self, value: String) -> Self {
+        self.inner = self.inner.user_agent(value);
+        self
+    }
+
+    /// Languages preferred for the messages returned by the server, such as
+    /// validation errors; for instance `"fr-FR, fr;q=0.9, en;q=0.5"`.
+    pub fn accept_language(self, value: &str) -> Result<Self> {
+        let value =
+            reqwest::header::HeaderValue::from_str(value).map_err(|source| Error::ClientBuild {
+                source: source.into(),
+            })?;
+        Ok(self.default_header(reqwest::header::ACCEPT_LANGUAGE, value))
+    }
+
+    /// Version of the Paperless-ngx API to request, to talk to older or
+    /// newer servers; defaults to [`DEFAULT_API_VERSION`], the version this
+    /// crate's schema is written against. The latest version supported by
+    /// the server is reported by [`Extra::api_version`](super::Extra::api_version).
+    #[must_use]
+    pub fn api_version(mut self, value: u32) -> Self {
+        self.api_version = value;
+        self
+    }
+
+    /// Version of the server the client talks to, if known ahead; requests
+    /// to parts of the API that it doesn't provide then fail early, with
+    /// [`Error::Unsupported`]. The version is reported by
+    /// [`Extra::server_version`](super::Extra::server_version).
+    #[must_use]
+    pub fn server_version(mut self, value: ServerVersion) -> Self {
+        self.server_version = Some(value);
+        self
+    }
+
+    /// See [`Client::additional_headers`].
+    #[must_use]
+    pub fn additional_headers(mut self, value: Vec<(String, String)>) -> Self {
+        self.additional_headers = value;
+        self
+    }
+
+    /// See [`Client::max_error_content`].
+    #[must_use]
+    pub fn max_error_content(mut self, value: usize) -> Self {
+        self.max_error_content = Some(value);
+        self
+    }
+
+    /// See [`Client::set_signer`].
+    #[must_use]
+    pub fn signer(mut self, value: Arc<dyn Signer>) -> Self {
+        self.signer = Some(value);
+        self
+    }
+
+    /// See [`Client::retry_policy`].
+    #[must_use]
+    pub fn retry_policy(mut self, value: RetryPolicy) -> Self {
+        self.retry_policy = Some(value);
+        self
+    }
+
+    /// See [`Client::retry_after`].
+    #[must_use]
+    pub fn retry_after(mut self, value: RetryAfter) -> Self {
+        self.retry_after = Some(value);
+        self
+    }
+
+    /// See [`RateLimiter`].
+    #[must_use]
+    pub fn rate_limiter(mut self, value: RateLimiter) -> Self {
+        self.rate_limiter = Some(value);
+        self
+    }
+
+    /// See [`CircuitBreaker`].
+    #[must_use]
+    pub fn circuit_breaker(mut self, value: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(value);
+        self
+    }
+
+    /// See [`ConcurrencyLimiter`].
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn concurrency_limiter(mut self, value: ConcurrencyLimiter) -> Self {
+        self.concurrency_limiter = Some(value);
+        self
+    }
+
+    /// Coalesces identical `GET` requests sent concurrently: while one is in
+    /// flight, the others wait for its response instead of being sent too.
+    /// Disabled by default.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn deduplicate(mut self, value: bool) -> Self {
+        self.deduplicator = value.then(Deduplicator::default);
+        self
+    }
+
+    /// Adds an [`Interceptor`], invoked after the ones already added.
+    #[must_use]
+    pub fn interceptor(mut self, value: Arc<dyn Interceptor>) -> Self {
+        self.interceptors.push(value);
+        self
+    }
+
+    /// See [`Client::set_metrics`].
+    #[must_use]
+    pub fn metrics(mut self, value: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(value);
+        self
+    }
+
+    /// See [`Logging`].
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    pub fn logging(mut self, value: Logging) -> Self {
+        self.logging = Some(value);
+        self
+    }
+
+    /// Sends a random correlation id (a UUID) in the given header, such as
+    /// `X-Request-Id`, with each request, so that server logs can be matched
+    /// with client failures. The id is the same for all the attempts of a
+    /// request, and is exposed in `Extra` and in errors.
+    #[must_use]
+    pub fn request_id_header(mut self, value: reqwest::header::HeaderName) -> Self {
+        self.request_id_header = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn response_cache(mut self, value: ResponseCache) -> Self {
+        self.response_cache = Some(value);
+        self
+    }
+
+    /// See [`LruCache`].
+    #[cfg(feature = "lru-cache")]
+    #[must_use]
+    pub fn lru_cache(mut self, value: LruCache) -> Self {
+        self.lru_cache = Some(value);
+        self
+    }
+
+    /// Builds the client; fails if the underlying `reqwest` client can't be
+    /// built, for instance because the TLS backend can't be initialized.
+    pub fn build(mut self) -> Result<Client> {
+        let inner =
+            std::mem::take(&mut self.inner)
+                .build()
+                .map_err(|source| Error::ClientBuild {
+                    source: source.into(),
+                })?;
+        Ok(self.build_with(inner))
+    }
+
+    /// Builds the client, and exchanges its username and password for an API
+    /// token, used for all subsequent requests:
+    ///
+    /// ```ignore
+    /// let client = Client::builder(url, Auth::basic(username, password))
+    ///     .login()
+    ///     .await?;
+    /// ```
+    ///
+    /// If the client was given a token already, it is simply built.
+    pub async fn login(self) -> Result<Client> {
+        let mut client = self.build()?;
+        let Auth::Basic { username, password } = client.auth() else {
+            return Ok(client);
+        };
+        let body = token::obtain(username.unsecure().into(), password.unsecure().into());
+        let token = client.token().obtain(&body).await?.value.token;
+        // same user: unlike `set_auth`, keep the caches given to the builder
+        client.auth = Auth::token(token.expose());
+        Ok(client)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl ClientBuilder {
+    /// Creates the client with the given `reqwest` client, ignoring the
+    /// connection options of this builder.
+    pub(super) fn build_with(self, inner: reqwest::Client) -> Client {
+        Client {
+            inner,
+            server_url: self.server_url,
+            auth: self.auth,
+            api_version: self.api_version,
+            server_version: self.server_version,
+            additional_headers: self.additional_headers,
+            max_error_content: self.max_error_content,
+            signer: self.signer,
+            retry_policy: self.retry_policy,
+            retry_after: self.retry_after,
+            rate_limiter: self.rate_limiter,
+            circuit_breaker: self.circuit_breaker,
+            request_timeout: None,
+            cancellation: None,
+            interceptors: self.interceptors,
+            metrics: self.metrics,
+            #[cfg(feature = "tracing")]
+            logging: self.logging,
+            response_cache: self.response_cache,
+            redirect_policy: self.redirect_policy,
+            request_id_header: self.request_id_header,
+            #[cfg(not(target_arch = "wasm32"))]
+            concurrency_limiter: self.concurrency_limiter,
+            #[cfg(not(target_arch = "wasm32"))]
+            deduplicator: self.deduplicator,
+            #[cfg(feature = "lru-cache")]
+            lru_cache: self.lru_cache,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use bytes::Bytes;
+use reqwest::header::{
+    ETAG, HeaderMap, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use url::Url;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Cache of the responses to `GET` requests that carry an `ETag` or a
+/// `Last-Modified` header. When a client has a cache, it sends the matching
+/// `If-None-Match` and `If-Modified-Since` headers with subsequent requests to
+/// the same URL; if the server answers with `304 Not Modified`, the cached
+/// body is returned instead, and the status in `Extra` is left as is.
+///
+/// Responses are kept in memory until the cache is cleared; the cache is
+/// shared by all its clones, and therefore by all clones of a client, except
+/// those whose credentials were replaced with
+/// [`Client::set_auth`](super::Client::set_auth), which get a new one.
+#[derive(Clone, Debug, Default)]
+pub struct ResponseCache {
+    entries: Arc<Mutex<HashMap<Url, Entry>>>,
+}
+
+#[derive(Clone, Debug)]
+struct Entry {
+    etag: Option<HeaderValue>,
+    last_modified: Option<HeaderValue>,
+    content_type: Option<String>,
+    body: Bytes,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl ResponseCache {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl ResponseCache {
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<Url, Entry>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Adds the conditional headers matching the cached response, if any.
+    pub(crate) fn apply(&self, request: &mut reqwest::Request) {
+        let Some(entry) = self.lock().get(request.url()).cloned() else {
+            return;
+        };
+        let headers = request.headers_mut();
+        if let Some(etag) = entry.etag {
+            headers.insert(IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = entry.last_modified {
+            headers.insert(IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    /// Returns the cached body and content type for the given URL.
+    pub(crate) fn get(&self, url: &Url) -> Option<(Bytes, Option<String>)> {
+        self.lock()
+            .get(url)
+            .map(|entry| (entry.body.clone(), entry.content_type.clone()))
+    }
+
+    /// Caches the response, if it can be validated later.
+    pub(crate) fn store(
+        &self,
+        url: &Url,
+        headers: &HeaderMap,
+        content_type: Option<String>,
+        body: &Bytes,
+    ) {
+        let etag = headers.get(ETAG).cloned();
+        let last_modified = headers.get(LAST_MODIFIED).cloned();
+        if etag.is_none() && last_modified.is_none() {
+            return;
+        }
+        self.lock().insert(
+            url.clone(),
+            Entry {
+                etag,
+                last_modified,
+                content_type,
+                body: body.clone(),
+            },
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Waker};
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Aborts the requests of a client, for instance when the user navigates away
+/// from a page that is still downloading documents; see
+/// [`Client::with_cancellation`](super::Client::with_cancellation).
+///
+/// Once cancelled, a token stays cancelled: pending and subsequent requests
+/// fail with [`Error::Cancelled`](crate::error::Error::Cancelled), and so do
+/// the body streams being read. The token is shared by all its clones.
+///
+/// Dropping the future of a request also aborts it, and releases its
+/// connection; the token is for the cases where the futures are owned by
+/// someone else, such as spawned tasks.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+/// Completes when the [`CancellationToken`] is cancelled.
+#[derive(Debug)]
+pub struct Cancelled {
+    token: CancellationToken,
+    id: u64,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    next_id: AtomicU64,
+    wakers: Mutex<HashMap<u64, Waker>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl CancellationToken {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        let wakers = std::mem::take(&mut *self.wakers());
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns a future that completes once the token is cancelled.
+    #[must_use]
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            token: self.clone(),
+            id: self.inner.next_id.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl CancellationToken {
+    fn wakers(&self) -> MutexGuard<'_, HashMap<u64, Waker>> {
+        self.inner
+            .wakers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        self.token.wakers().insert(self.id, cx.waker().clone());
+        // The token may have been cancelled before the waker was registered.
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled {
+    fn drop(&mut self) {
+        self.token.wakers().remove(&self.id);
+    }
+}
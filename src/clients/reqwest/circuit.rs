@@ -0,0 +1,124 @@
+use std::fmt;
+use std::num::NonZeroU32;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use web_time::Instant;
+
+use crate::error::Error;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Stops sending requests to a server that keeps failing, for instance while
+/// Paperless restarts, instead of stacking up timeouts.
+///
+/// After `threshold` consecutive failures, that is requests that couldn't be
+/// sent or whose status is a server error, the circuit opens: requests fail
+/// immediately with [`Error::RequestSend`], whose source is a
+/// [`CircuitOpen`], for the duration of the cool-down. Requests are then let
+/// through again; the first success closes the circuit, and another failure
+/// opens it for another cool-down. Retries count as a single request.
+///
+/// The state is shared by all clones of the breaker, and therefore by all
+/// clones of a client.
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker {
+    threshold: NonZeroU32,
+    cool_down: Duration,
+    state: Arc<Mutex<State>>,
+}
+
+/// Source of the errors returned while a [`CircuitBreaker`] is open.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CircuitOpen {
+    /// Time left before requests are let through again.
+    pub retry_in: Duration,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    failures: u32,
+    open_until: Option<Instant>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl CircuitBreaker {
+    #[must_use]
+    pub fn new(threshold: NonZeroU32, cool_down: Duration) -> Self {
+        Self {
+            threshold,
+            cool_down,
+            state: Arc::default(),
+        }
+    }
+
+    /// Whether requests currently fail without being sent.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.lock()
+            .open_until
+            .is_some_and(|until| until > Instant::now())
+    }
+
+    /// Closes the circuit, and forgets the failures so far.
+    pub fn reset(&self) {
+        *self.lock() = State::default();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl CircuitBreaker {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Fails if the circuit is open.
+    pub(crate) fn check(&self) -> Result<(), CircuitOpen> {
+        let now = Instant::now();
+        match self.lock().open_until {
+            Some(until) if until > now => Err(CircuitOpen {
+                retry_in: until - now,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Records the outcome of a request; only failures to reach the server
+    /// and server errors count as failures.
+    pub(crate) fn record<T>(&self, result: &Result<T, Error>) {
+        let failed = match result {
+            Ok(_) => false,
+            Err(Error::RequestSend { .. }) => true,
+            Err(Error::Server { status, .. }) => status.starts_with('5'),
+            Err(_) => return,
+        };
+        let mut state = self.lock();
+        if !failed {
+            *state = State::default();
+            return;
+        }
+        state.failures = state.failures.saturating_add(1);
+        if state.failures >= self.threshold.get() {
+            state.open_until = Some(Instant::now() + self.cool_down);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+impl fmt::Display for CircuitOpen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "circuit breaker open, retrying in {:.1}s",
+            self.retry_in.as_secs_f64()
+        )
+    }
+}
+
+impl std::error::Error for CircuitOpen {}
@@ -0,0 +1,54 @@
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Bounds the number of requests in flight at the same time; further requests
+/// wait for one of them to complete. A request is in flight from the moment it
+/// is first sent until its response body has been read, including retries.
+///
+/// The limit is shared by all clones of the limiter, and therefore by all
+/// clones of a client.
+#[derive(Clone, Debug)]
+pub struct ConcurrencyLimiter {
+    max: usize,
+    semaphore: Arc<Semaphore>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl ConcurrencyLimiter {
+    #[must_use]
+    pub fn new(max: NonZeroUsize) -> Self {
+        Self {
+            max: max.get(),
+            semaphore: Arc::new(Semaphore::new(max.get())),
+        }
+    }
+
+    #[must_use]
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Number of requests that can currently be sent without waiting.
+    #[must_use]
+    pub fn available(&self) -> usize {
+        self.semaphore.available_permits()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl ConcurrencyLimiter {
+    /// Waits until a request can be sent; it is in flight until the returned
+    /// permit is dropped. The semaphore is never closed, so this never returns
+    /// `None` in practice.
+    pub(crate) async fn acquire(&self) -> Option<OwnedSemaphorePermit> {
+        Arc::clone(&self.semaphore).acquire_owned().await.ok()
+    }
+}
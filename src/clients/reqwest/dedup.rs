@@ -0,0 +1,191 @@
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use tokio::sync::oneshot;
+
+use super::Extra;
+use crate::error::{Error, Result};
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal types
+
+type Fetched = Result<(Bytes, Extra)>;
+
+/// Coalesces identical requests sent concurrently: the first one is sent,
+/// and the others wait for its result. The requests in flight are shared by
+/// all clones of a client.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Deduplicator {
+    in_flight: Arc<Mutex<HashMap<String, Vec<oneshot::Sender<Fetched>>>>>,
+}
+
+/// Forgets the request in flight if it gets cancelled, so that the requests
+/// waiting for it send their own.
+struct Guard<'a> {
+    deduplicator: &'a Deduplicator,
+    key: Option<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal implementation
+
+impl Deduplicator {
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Vec<oneshot::Sender<Fetched>>>> {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Runs `fetch`, unless an identical request is already in flight, in
+    /// which case its result is returned instead.
+    pub(crate) async fn run(&self, key: String, fetch: impl Future<Output = Fetched>) -> Fetched {
+        let receiver = {
+            let mut in_flight = self.lock();
+            if let Some(waiters) = in_flight.get_mut(&key) {
+                let (sender, receiver) = oneshot::channel();
+                waiters.push(sender);
+                Some(receiver)
+            } else {
+                in_flight.insert(key.clone(), vec![]);
+                None
+            }
+        };
+        if let Some(receiver) = receiver {
+            return match receiver.await {
+                Ok(result) => result,
+                Err(_) => fetch.await,
+            };
+        }
+
+        let guard = Guard {
+            deduplicator: self,
+            key: Some(key),
+        };
+        let result = fetch.await;
+        for waiter in guard.release() {
+            let _ = waiter.send(duplicate(&result));
+        }
+        result
+    }
+}
+
+impl Guard<'_> {
+    /// Forgets the request, which completed, and returns the requests
+    /// waiting for it.
+    fn release(mut self) -> Vec<oneshot::Sender<Fetched>> {
+        let key = self.key.take().unwrap_or_default();
+        self.deduplicator.lock().remove(&key).unwrap_or_default()
+    }
+}
+
+/// Copies a result for a waiting request; since errors can't be cloned,
+/// their sources are replaced by their messages.
+fn duplicate(result: &Fetched) -> Fetched {
+    let error = match result {
+        Ok((content, extra)) => return Ok((content.clone(), extra.clone())),
+        Err(error) => error,
+    };
+    Err(match error {
+        Error::ClientBuild { source } => Error::ClientBuild {
+            source: anyhow::anyhow!("{source:#}"),
+        },
+        Error::UnknownServer { name } => Error::UnknownServer { name: name.clone() },
+        Error::RequestBuild {
+            method,
+            endpoint,
+            source,
+        } => Error::RequestBuild {
+            method: *method,
+            endpoint: endpoint.clone(),
+            source: anyhow::anyhow!("{source:#}"),
+        },
+        Error::RequestSend {
+            method,
+            endpoint,
+            request_id,
+            source,
+        } => Error::RequestSend {
+            method: *method,
+            endpoint: endpoint.clone(),
+            request_id: request_id.clone(),
+            source: anyhow::anyhow!("{source:#}"),
+        },
+        Error::Cancelled { method, endpoint } => Error::Cancelled {
+            method: *method,
+            endpoint: endpoint.clone(),
+        },
+        Error::Unsupported {
+            method,
+            endpoint,
+            feature,
+            version,
+        } => Error::Unsupported {
+            method: *method,
+            endpoint: endpoint.clone(),
+            feature: *feature,
+            version: *version,
+        },
+        Error::Server {
+            method,
+            endpoint,
+            request_id,
+            status,
+            content,
+            source,
+        } => Error::Server {
+            method: *method,
+            endpoint: endpoint.clone(),
+            request_id: request_id.clone(),
+            status: status.clone(),
+            content: content.clone(),
+            source: anyhow::anyhow!("{source:#}"),
+        },
+        Error::ContentType {
+            method,
+            endpoint,
+            expected,
+            received,
+        } => Error::ContentType {
+            method: *method,
+            endpoint: endpoint.clone(),
+            expected: expected.clone(),
+            received: received.clone(),
+        },
+        Error::ResponseBody {
+            method,
+            endpoint,
+            source,
+        } => Error::ResponseBody {
+            method: *method,
+            endpoint: endpoint.clone(),
+            source: anyhow::anyhow!("{source:#}"),
+        },
+        Error::Deserializing {
+            method,
+            endpoint,
+            typename,
+            content,
+            source,
+        } => Error::Deserializing {
+            method: *method,
+            endpoint: endpoint.clone(),
+            typename,
+            content: content.clone(),
+            source: serde::de::Error::custom(source),
+        },
+        Error::Internal { source } => Error::Internal {
+            source: anyhow::anyhow!("{source:#}"),
+        },
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.deduplicator.lock().remove(&key);
+        }
+    }
+}
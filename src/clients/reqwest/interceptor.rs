@@ -0,0 +1,25 @@
+use std::fmt;
+
+use super::Extra;
+use crate::error::Error;
+
+/// Hooks invoked around every call made by a [`Client`](super::Client).
+///
+/// `before_send` is invoked on each attempt, right before the request is
+/// signed and sent, and can modify it; returning an error aborts the call with
+/// [`Error::RequestBuild`]. `after_receive` is invoked once per call, after the
+/// last attempt, with either the details of the successful response, or the
+/// error the call failed with.
+pub trait Interceptor: Send + Sync {
+    fn before_send(&self, _request: &mut reqwest::Request) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn after_receive(&self, _result: Result<&Extra, &Error>) {}
+}
+
+impl fmt::Debug for dyn Interceptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Interceptor")
+    }
+}
@@ -3,9 +3,9 @@ use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use std::marker::Sync;
 
-use super::translate_method;
+use super::{read_body, translate_method};
 use crate::auth::Auth;
-use crate::clients::Client as ClientTrait;
+use crate::clients::{Client as ClientTrait, json};
 use crate::error::{Error, Result};
 use crate::response;
 use crate::utils::Method;
@@ -34,6 +34,34 @@ impl Client {
             auth,
         }
     }
+
+    /// Uses an existing `reqwest` client, for instance to share its
+    /// connection pool with the rest of the application.
+    #[must_use]
+    pub fn with_inner(inner: reqwest::Client, server_url: String, auth: Auth) -> Self {
+        Self {
+            inner,
+            server_url,
+            auth,
+        }
+    }
+
+    #[must_use]
+    pub fn inner(&self) -> &reqwest::Client {
+        &self.inner
+    }
+
+    #[must_use]
+    pub fn auth(&self) -> &Auth {
+        &self.auth
+    }
+
+    /// Replaces the credentials used for subsequent requests, for instance
+    /// after rotating the API token with
+    /// [`Profile::generate_auth_token`](crate::services::Profile::generate_auth_token).
+    pub fn set_auth(&mut self, auth: Auth) {
+        self.auth = auth;
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -56,8 +84,18 @@ impl Client {
             .inner
             .request(translate_method(method), &uri)
             .header(reqwest::header::ACCEPT, "application/json; version=9")
-            .header(reqwest::header::AUTHORIZATION, self.auth.header_value())
             .query(params);
+        let headers = self
+            .auth
+            .static_headers()
+            .map_err(|source| Error::RequestBuild {
+                method,
+                endpoint: endpoint.to_string(),
+                source,
+            })?;
+        for (header_name, header_value) in headers {
+            request = request.header(header_name, header_value);
+        }
         if let Some(body) = body {
             request = request.json(body);
         }
@@ -72,7 +110,8 @@ impl Client {
 ////////////////////////////////////////////////////////////////////////////////
 // Traits
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl ClientTrait for Client {
     type Extra = ();
 
@@ -96,6 +135,7 @@ impl ClientTrait for Client {
             .map_err(|source| Error::RequestSend {
                 method,
                 endpoint: endpoint.to_string(),
+                request_id: None,
                 source: source.into(),
             })?;
         let status = resp.status();
@@ -115,6 +155,7 @@ impl ClientTrait for Client {
             return Err(Error::Server {
                 method,
                 endpoint: endpoint.to_string(),
+                request_id: None,
                 status: format!("{status}"),
                 content,
                 source: source.into(),
@@ -130,20 +171,16 @@ impl ClientTrait for Client {
             });
         }
 
-        let content = resp.text().await.map_err(|source| Error::ResponseBody {
-            method,
-            endpoint: endpoint.to_string(),
-            source: source.into(),
-        })?;
-
-        Ok(Response {
-            value: serde_json::from_str(&content).map_err(|source| Error::Deserializing {
+        let content = read_body(resp)
+            .await
+            .map_err(|source| Error::ResponseBody {
                 method,
                 endpoint: endpoint.to_string(),
-                typename: std::any::type_name::<R>(),
-                content,
-                source,
-            })?,
+                source: source.into(),
+            })?;
+
+        Ok(Response {
+            value: json::decode(method, endpoint, content.into(), None)?,
             extra: (),
         })
     }
@@ -167,6 +204,7 @@ impl ClientTrait for Client {
             .map_err(|source| Error::RequestSend {
                 method,
                 endpoint: endpoint.to_string(),
+                request_id: None,
                 source: source.into(),
             })?;
         let status = resp.status();
@@ -181,6 +219,7 @@ impl ClientTrait for Client {
             return Err(Error::Server {
                 method,
                 endpoint: endpoint.to_string(),
+                request_id: None,
                 status: format!("{status}"),
                 content,
                 source: source.into(),
@@ -188,11 +227,13 @@ impl ClientTrait for Client {
         }
 
         Ok(Response {
-            value: resp.bytes().await.map_err(|source| Error::ResponseBody {
-                method,
-                endpoint: endpoint.to_string(),
-                source: source.into(),
-            })?,
+            value: read_body(resp)
+                .await
+                .map_err(|source| Error::ResponseBody {
+                    method,
+                    endpoint: endpoint.to_string(),
+                    source: source.into(),
+                })?,
             extra: (),
         })
     }
@@ -216,6 +257,7 @@ impl ClientTrait for Client {
             .map_err(|source| Error::RequestSend {
                 method,
                 endpoint: endpoint.to_string(),
+                request_id: None,
                 source: source.into(),
             })?;
         let status = resp.status();
@@ -230,6 +272,7 @@ impl ClientTrait for Client {
             return Err(Error::Server {
                 method,
                 endpoint: endpoint.to_string(),
+                request_id: None,
                 status: format!("{status}"),
                 content,
                 source: source.into(),
@@ -0,0 +1,194 @@
+use reqwest::header::{AUTHORIZATION, COOKIE, HeaderMap, PROXY_AUTHORIZATION, SET_COOKIE};
+use serde_json::Value;
+use std::time::Duration;
+use url::Url;
+
+use crate::utils::{Method, REDACTED, Redaction};
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Logs the requests sent by a client, and their responses, as `tracing`
+/// events with the `paper_plane::http` target, at the `DEBUG` level.
+///
+/// Each request is logged with its method, URL, headers and body, and each
+/// response with its status, duration and headers. Bodies are only logged
+/// if enabled with [`Logging::bodies`]; only JSON bodies are logged in full,
+/// others are summarized by their size.
+///
+/// Secrets are redacted: the `Authorization`, `Proxy-Authorization`, `Cookie`
+/// and `Set-Cookie` headers, and the headers, query parameters and JSON fields
+/// whose names contain `password`, `token` or `secret`, or any name added
+/// with [`Logging::redact`].
+#[derive(Clone, Debug)]
+pub struct Logging {
+    max_body: Option<usize>,
+    redacted: Redaction,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl Logging {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            max_body: None,
+            redacted: Redaction::default(),
+        }
+    }
+
+    /// Logs the bodies of the requests and responses, truncated to the given
+    /// number of bytes.
+    #[must_use]
+    pub fn bodies(mut self, max_length: usize) -> Self {
+        self.max_body = Some(max_length);
+        self
+    }
+
+    /// Also redacts the headers, query parameters and JSON fields whose names
+    /// contain the given string, regardless of case.
+    #[must_use]
+    pub fn redact(mut self, name: &str) -> Self {
+        self.redacted.add(name);
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl Logging {
+    pub(crate) fn request(&self, method: Method, request: &reqwest::Request) {
+        let body = request
+            .body()
+            .map(|body| self.body(body.as_bytes(), Some("application/json")));
+        tracing::debug!(
+            target: "paper_plane::http",
+            ?method,
+            url = %self.url(request.url()),
+            headers = ?self.headers(request.headers()),
+            body,
+            "request",
+        );
+    }
+
+    pub(crate) fn response(
+        &self,
+        method: Method,
+        url: &Url,
+        result: &reqwest::Result<reqwest::Response>,
+        duration: Duration,
+    ) {
+        match result {
+            Ok(resp) => tracing::debug!(
+                target: "paper_plane::http",
+                ?method,
+                url = %self.url(url),
+                status = resp.status().as_u16(),
+                ?duration,
+                headers = ?self.headers(resp.headers()),
+                "response",
+            ),
+            Err(error) => tracing::debug!(
+                target: "paper_plane::http",
+                ?method,
+                url = %self.url(url),
+                ?duration,
+                %error,
+                "request failed",
+            ),
+        }
+    }
+
+    pub(crate) fn response_body(
+        &self,
+        method: Method,
+        url: &Url,
+        content_type: Option<&str>,
+        content: &[u8],
+    ) {
+        if self.max_body.is_none() {
+            return;
+        }
+        tracing::debug!(
+            target: "paper_plane::http",
+            ?method,
+            url = %self.url(url),
+            body = self.body(Some(content), content_type),
+            "response body",
+        );
+    }
+
+    fn url(&self, url: &Url) -> Url {
+        let mut url = url.clone();
+        if url.password().is_some() {
+            let _ = url.set_password(Some(REDACTED));
+        }
+        if url.query().is_some() {
+            let pairs: Vec<(String, String)> = url
+                .query_pairs()
+                .map(|(key, value)| {
+                    let value = if self.redacted.is_secret(&key) {
+                        REDACTED.to_string()
+                    } else {
+                        value.into_owned()
+                    };
+                    (key.into_owned(), value)
+                })
+                .collect();
+            url.query_pairs_mut().clear().extend_pairs(pairs);
+        }
+        url
+    }
+
+    fn headers(&self, headers: &HeaderMap) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let secret = [AUTHORIZATION, PROXY_AUTHORIZATION, COOKIE, SET_COOKIE]
+                    .contains(name)
+                    || self.redacted.is_secret(name.as_str());
+                let value = if secret {
+                    REDACTED.to_string()
+                } else {
+                    String::from_utf8_lossy(value.as_bytes()).into_owned()
+                };
+                (name.to_string(), value)
+            })
+            .collect()
+    }
+
+    /// Redacts and truncates a body; `None` if bodies aren't logged.
+    fn body(&self, content: Option<&[u8]>, content_type: Option<&str>) -> Option<String> {
+        let limit = self.max_body?;
+        let Some(content) = content else {
+            return Some("<stream>".to_string());
+        };
+        let json = content_type.is_some_and(|t| t.starts_with("application/json"));
+        let mut value = match serde_json::from_slice::<Value>(content) {
+            Ok(value) if json => value,
+            _ => return Some(format!("<{} bytes>", content.len())),
+        };
+        self.redacted.json(&mut value);
+        let mut result = value.to_string();
+        if result.len() > limit {
+            let mut end = limit;
+            while !result.is_char_boundary(end) {
+                end -= 1;
+            }
+            result.truncate(end);
+            result.push_str("...");
+        }
+        Some(result)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+impl Default for Logging {
+    fn default() -> Self {
+        Self::new()
+    }
+}
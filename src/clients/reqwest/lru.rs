@@ -0,0 +1,221 @@
+use bytes::Bytes;
+use std::collections::{BTreeMap, HashMap};
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use web_time::Instant;
+
+use super::Extra;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// In-memory cache of the responses to `GET` requests, keyed on their
+/// endpoint and parameters, for resources that rarely change, such as tags,
+/// correspondents or document types. Unlike [`ResponseCache`](super::ResponseCache),
+/// a cached response is returned without contacting the server at all, until
+/// it expires.
+///
+/// When the cache is full, the least recently used response is evicted. The
+/// cache is shared by all its clones, and therefore by all clones of a
+/// client; whether a response came from it is reported in
+/// [`Extra::cache`]. A client whose credentials are replaced with
+/// [`Client::set_auth`](super::Client::set_auth) gets an empty cache of its
+/// own, so that it is never served the responses of another user.
+///
+/// ```ignore
+/// let cache = LruCache::new(NonZeroUsize::new(500).unwrap(), Duration::from_secs(300))
+///     .endpoint("/api/tags/")
+///     .endpoint("/api/correspondents/");
+/// ```
+#[derive(Clone, Debug)]
+pub struct LruCache {
+    capacity: NonZeroUsize,
+    ttl: Duration,
+    max_bytes: Option<usize>,
+    endpoints: Vec<String>,
+    state: Arc<Mutex<State>>,
+}
+
+/// Whether a response was served by the [`LruCache`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CacheStatus {
+    Hit,
+    Miss,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    entries: HashMap<String, Entry>,
+    /// Keys of the entries, from the least to the most recently used.
+    order: BTreeMap<u64, String>,
+    tick: u64,
+    bytes: usize,
+}
+
+#[derive(Debug)]
+struct Entry {
+    content: Bytes,
+    extra: Extra,
+    expires: Instant,
+    used: u64,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl LruCache {
+    /// Keeps at most `capacity` responses, each for `ttl`.
+    #[must_use]
+    pub fn new(capacity: NonZeroUsize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            max_bytes: None,
+            endpoints: vec![],
+            state: Arc::default(),
+        }
+    }
+
+    /// Also bounds the total size of the cached bodies; larger responses are
+    /// never cached.
+    #[must_use]
+    pub fn max_bytes(mut self, value: usize) -> Self {
+        self.max_bytes = Some(value);
+        self
+    }
+
+    /// Restricts the cache to the endpoints that start with the given
+    /// prefix, such as `/api/tags/`; every `GET` request is cached if no
+    /// prefix is given.
+    #[must_use]
+    pub fn endpoint(mut self, prefix: impl Into<String>) -> Self {
+        self.endpoints.push(prefix.into());
+        self
+    }
+
+    #[must_use]
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.capacity
+    }
+
+    #[must_use]
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    /// Drops the cached responses of the endpoints that start with the given
+    /// prefix, for instance after creating or modifying a tag.
+    pub fn invalidate(&self, prefix: &str) {
+        let mut state = self.lock();
+        let keys: Vec<String> = state
+            .entries
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect();
+        for key in keys {
+            state.remove(&key);
+        }
+    }
+
+    pub fn clear(&self) {
+        *self.lock() = State::default();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl LruCache {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// A cache with the same settings, but empty, and not shared with this
+    /// one.
+    pub(crate) fn detached(&self) -> Self {
+        Self {
+            state: Arc::default(),
+            ..self.clone()
+        }
+    }
+
+    /// Whether responses from this endpoint are cached.
+    pub(crate) fn covers(&self, endpoint: &str) -> bool {
+        self.endpoints.is_empty()
+            || self
+                .endpoints
+                .iter()
+                .any(|prefix| endpoint.starts_with(prefix))
+    }
+
+    /// Returns the cached response for the given key, unless it expired.
+    pub(crate) fn get(&self, key: &str) -> Option<(Bytes, Extra)> {
+        let mut state = self.lock();
+        let expired = state.entries.get(key)?.expires <= Instant::now();
+        if expired {
+            state.remove(key);
+            return None;
+        }
+        state.tick += 1;
+        let tick = state.tick;
+        let entry = state.entries.get_mut(key)?;
+        let used = std::mem::replace(&mut entry.used, tick);
+        let result = (entry.content.clone(), entry.extra.clone());
+        state.order.remove(&used);
+        state.order.insert(tick, key.to_string());
+        Some(result)
+    }
+
+    /// Caches a response, evicting the least recently used ones if needed.
+    pub(crate) fn store(&self, key: String, content: &Bytes, extra: &Extra) {
+        if self.max_bytes.is_some_and(|max| content.len() > max) {
+            return;
+        }
+        let mut state = self.lock();
+        state.remove(&key);
+        while state.entries.len() >= self.capacity.get()
+            || self
+                .max_bytes
+                .is_some_and(|max| state.bytes + content.len() > max)
+        {
+            let Some((_, oldest)) = state.order.pop_first() else {
+                break;
+            };
+            state.remove(&oldest);
+        }
+        state.tick += 1;
+        let tick = state.tick;
+        state.bytes += content.len();
+        state.order.insert(tick, key.clone());
+        state.entries.insert(
+            key,
+            Entry {
+                content: content.clone(),
+                extra: extra.clone(),
+                expires: Instant::now() + self.ttl,
+                used: tick,
+            },
+        );
+    }
+}
+
+impl State {
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.order.remove(&entry.used);
+            self.bytes -= entry.content.len();
+        }
+    }
+}
@@ -1,14 +1,26 @@
 use async_trait::async_trait;
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
+use futures_util::future::{self, Either};
+use futures_util::{StreamExt, stream};
 use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
 use std::marker::Sync;
-use std::time::{Duration, SystemTime};
+use std::pin::pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use web_time::{Instant, SystemTime};
 
 use crate::auth::Auth;
-use crate::clients::Client as ClientTrait;
+use crate::clients::{ByteStream, Client as ClientTrait, json};
 use crate::error::{Error, Result};
+use crate::metrics::{Metrics, RequestFinished, RequestStarted};
+use crate::multipart::{Content, Form};
 use crate::response;
-use crate::utils::Method;
+use crate::schema::model::ServerVersion;
+use crate::signing::{SignableRequest, Signer};
+use crate::utils::{Method, sleep};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Public modules
@@ -18,21 +30,88 @@ pub mod lite;
 ////////////////////////////////////////////////////////////////////////////////
 // Public types
 
+mod builder;
+pub use builder::*;
+mod cache;
+pub use cache::*;
+mod cancel;
+pub use cancel::*;
+mod circuit;
+pub use circuit::*;
+#[cfg(not(target_arch = "wasm32"))]
+mod concurrency;
+#[cfg(not(target_arch = "wasm32"))]
+pub use concurrency::*;
+#[cfg(not(target_arch = "wasm32"))]
+mod dedup;
+#[cfg(not(target_arch = "wasm32"))]
+use dedup::Deduplicator;
+mod interceptor;
+pub use interceptor::*;
+#[cfg(feature = "tracing")]
+mod logging;
+#[cfg(feature = "tracing")]
+pub use logging::*;
+#[cfg(feature = "lru-cache")]
+mod lru;
+#[cfg(feature = "lru-cache")]
+pub use lru::*;
+mod rate_limit;
+pub use rate_limit::*;
+mod redirect;
+pub use redirect::*;
+mod retry;
+pub use retry::*;
+
 #[derive(Debug, Clone)]
 pub struct Client {
     inner: reqwest::Client,
     server_url: String,
     auth: Auth,
+    api_version: u32,
+    server_version: Option<ServerVersion>,
     additional_headers: Vec<(String, String)>,
+    max_error_content: Option<usize>,
+    signer: Option<Arc<dyn Signer>>,
+    retry_policy: Option<RetryPolicy>,
+    retry_after: Option<RetryAfter>,
+    rate_limiter: Option<RateLimiter>,
+    circuit_breaker: Option<CircuitBreaker>,
+    request_timeout: Option<Duration>,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    metrics: Option<Arc<dyn Metrics>>,
+    #[cfg(feature = "tracing")]
+    logging: Option<Logging>,
+    response_cache: Option<ResponseCache>,
+    redirect_policy: Option<RedirectPolicy>,
+    request_id_header: Option<reqwest::header::HeaderName>,
+    #[cfg(not(target_arch = "wasm32"))]
+    concurrency_limiter: Option<ConcurrencyLimiter>,
+    #[cfg(not(target_arch = "wasm32"))]
+    deduplicator: Option<Deduplicator>,
+    #[cfg(feature = "lru-cache")]
+    lru_cache: Option<LruCache>,
+    cancellation: Option<CancellationToken>,
 }
 
+#[derive(Debug, Clone)]
 pub struct Extra {
     pub method: Method,
     pub endpoint: String,
+    /// Correlation id sent with the request, if any; see
+    /// [`ClientBuilder::request_id_header`].
+    pub request_id: Option<String>,
     pub status: reqwest::StatusCode,
+    /// Headers of the response. When the response was decompressed (see the
+    /// `gzip` and `brotli` features), `Content-Encoding` and `Content-Length`
+    /// are removed, since they only applied to the compressed body.
     pub headers: reqwest::header::HeaderMap,
     pub duration: Duration,
     pub content_type: Option<String>,
+    /// Whether the response was served by the [`LruCache`], if the request
+    /// could be.
+    #[cfg(feature = "lru-cache")]
+    pub cache: Option<CacheStatus>,
 }
 
 pub type Response<R> = response::Response<R, Extra>;
@@ -40,24 +119,116 @@ pub type Response<R> = response::Response<R, Extra>;
 ////////////////////////////////////////////////////////////////////////////////
 // Public implementation
 
+impl Extra {
+    /// Length of the response body, as advertised by the server; for
+    /// instance, the total of a [`progress::track`](crate::progress::track)ed
+    /// download.
+    #[must_use]
+    pub fn content_length(&self) -> Option<u64> {
+        self.headers
+            .get(reqwest::header::CONTENT_LENGTH)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|h| h.parse().ok())
+    }
+
+    /// Latest API version supported by the server, as reported in the
+    /// `X-Api-Version` header of its responses.
+    #[must_use]
+    pub fn api_version(&self) -> Option<u32> {
+        self.headers
+            .get("x-api-version")
+            .and_then(|h| h.to_str().ok())
+            .and_then(|h| h.trim().parse().ok())
+    }
+
+    /// Version of the server, as reported in the `X-Version` header of its
+    /// responses; see [`Client::set_server_version`].
+    #[must_use]
+    pub fn server_version(&self) -> Option<ServerVersion> {
+        self.headers
+            .get("x-version")
+            .and_then(|h| h.to_str().ok())
+            .and_then(|h| h.parse().ok())
+    }
+}
+
 impl Client {
     #[must_use]
     pub fn new(server_url: String, auth: Auth) -> Self {
-        Self {
-            inner: reqwest::Client::new(),
-            server_url,
-            auth,
-            additional_headers: vec![],
-        }
+        Self::with_inner(reqwest::Client::new(), server_url, auth)
     }
 
     #[must_use]
     pub fn with_headers(server_url: String, auth: Auth, headers: Vec<(String, String)>) -> Self {
-        Self {
-            inner: reqwest::Client::new(),
-            server_url,
-            auth,
-            additional_headers: headers,
+        ClientBuilder::new(server_url, auth)
+            .additional_headers(headers)
+            .build_with(reqwest::Client::new())
+    }
+
+    /// Uses an existing `reqwest` client, for instance to share its
+    /// connection pool with the rest of the application.
+    #[must_use]
+    pub fn with_inner(inner: reqwest::Client, server_url: String, auth: Auth) -> Self {
+        ClientBuilder::new(server_url, auth).build_with(inner)
+    }
+
+    /// Creates a client from the environment; see [`ClientBuilder::from_env`].
+    pub fn from_env() -> Result<Self> {
+        ClientBuilder::from_env()?.build()
+    }
+
+    /// Starts configuring a client; see [`ClientBuilder`].
+    #[must_use]
+    pub fn builder(server_url: String, auth: Auth) -> ClientBuilder {
+        ClientBuilder::new(server_url, auth)
+    }
+
+    #[must_use]
+    pub fn inner(&self) -> &reqwest::Client {
+        &self.inner
+    }
+
+    #[must_use]
+    pub fn auth(&self) -> &Auth {
+        &self.auth
+    }
+
+    /// Version of the API requested in the `Accept` header; see
+    /// [`ClientBuilder::api_version`].
+    #[must_use]
+    pub fn api_version(&self) -> u32 {
+        self.api_version
+    }
+
+    pub fn set_api_version(&mut self, api_version: u32) {
+        self.api_version = api_version;
+    }
+
+    /// Sets the version of the server, for instance as reported by
+    /// [`Extra::server_version`]; see [`ClientBuilder::server_version`].
+    pub fn set_server_version(&mut self, server_version: Option<ServerVersion>) {
+        self.server_version = server_version;
+    }
+
+    /// Replaces the credentials used for subsequent requests, for instance
+    /// after rotating the API token with
+    /// [`Profile::generate_auth_token`](crate::services::Profile::generate_auth_token).
+    ///
+    /// The caches and the deduplicator of the client are shared with its
+    /// clones; they are replaced with new, empty ones, so that responses
+    /// obtained with the previous credentials are never returned.
+    pub fn set_auth(&mut self, auth: Auth) {
+        self.auth = auth;
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.deduplicator.is_some() {
+            self.deduplicator = Some(Deduplicator::default());
+        }
+        if self.response_cache.is_some() {
+            self.response_cache = Some(ResponseCache::new());
+        }
+        #[cfg(feature = "lru-cache")]
+        if let Some(cache) = &self.lru_cache {
+            self.lru_cache = Some(cache.detached());
         }
     }
 
@@ -70,6 +241,223 @@ impl Client {
     pub fn additional_headers_mut(&mut self) -> &mut Vec<(String, String)> {
         &mut self.additional_headers
     }
+
+    /// Maximum number of bytes of a response body that are kept in
+    /// [`Error::Deserializing`] when decoding fails. Unbounded by default.
+    #[must_use]
+    pub fn max_error_content(&self) -> Option<usize> {
+        self.max_error_content
+    }
+
+    #[must_use]
+    pub fn max_error_content_mut(&mut self) -> &mut Option<usize> {
+        &mut self.max_error_content
+    }
+
+    #[must_use]
+    pub fn signer(&self) -> Option<&Arc<dyn Signer>> {
+        self.signer.as_ref()
+    }
+
+    /// Installs a [`Signer`], invoked right before sending each request.
+    pub fn set_signer(&mut self, signer: Option<Arc<dyn Signer>>) {
+        self.signer = signer;
+    }
+
+    /// Policy used to retry failed requests; requests are never retried by
+    /// default.
+    #[must_use]
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    pub fn set_retry_policy(&mut self, retry_policy: Option<RetryPolicy>) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Whether and how long to wait when the server answers with `429 Too
+    /// Many Requests`; such responses are returned as errors by default.
+    #[must_use]
+    pub fn retry_after(&self) -> Option<&RetryAfter> {
+        self.retry_after.as_ref()
+    }
+
+    pub fn set_retry_after(&mut self, retry_after: Option<RetryAfter>) {
+        self.retry_after = retry_after;
+    }
+
+    #[must_use]
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
+    pub fn set_rate_limiter(&mut self, rate_limiter: Option<RateLimiter>) {
+        self.rate_limiter = rate_limiter;
+    }
+
+    #[must_use]
+    pub fn circuit_breaker(&self) -> Option<&CircuitBreaker> {
+        self.circuit_breaker.as_ref()
+    }
+
+    pub fn set_circuit_breaker(&mut self, circuit_breaker: Option<CircuitBreaker>) {
+        self.circuit_breaker = circuit_breaker;
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn concurrency_limiter(&self) -> Option<&ConcurrencyLimiter> {
+        self.concurrency_limiter.as_ref()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_concurrency_limiter(&mut self, concurrency_limiter: Option<ConcurrencyLimiter>) {
+        self.concurrency_limiter = concurrency_limiter;
+    }
+
+    /// Whether identical `GET` requests sent concurrently are coalesced; see
+    /// [`ClientBuilder::deduplicate`]. Clones of a client share their
+    /// requests in flight, unless their credentials differ.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn deduplicates(&self) -> bool {
+        self.deduplicator.is_some()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn set_deduplicate(&mut self, value: bool) {
+        if value != self.deduplicates() {
+            self.deduplicator = value.then(Deduplicator::default);
+        }
+    }
+
+    /// Timeout applied to each request sent by this client, overriding the
+    /// one of the underlying `reqwest` client.
+    #[must_use]
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    pub fn set_request_timeout(&mut self, request_timeout: Option<Duration>) {
+        self.request_timeout = request_timeout;
+    }
+
+    /// See [`Interceptor`]; interceptors are invoked in order.
+    #[must_use]
+    pub fn interceptors(&self) -> &[Arc<dyn Interceptor>] {
+        &self.interceptors
+    }
+
+    #[must_use]
+    pub fn interceptors_mut(&mut self) -> &mut Vec<Arc<dyn Interceptor>> {
+        &mut self.interceptors
+    }
+
+    #[must_use]
+    pub fn metrics(&self) -> Option<&Arc<dyn Metrics>> {
+        self.metrics.as_ref()
+    }
+
+    /// Installs a [`Metrics`] recorder, notified of each request sent.
+    pub fn set_metrics(&mut self, metrics: Option<Arc<dyn Metrics>>) {
+        self.metrics = metrics;
+    }
+
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    pub fn logging(&self) -> Option<&Logging> {
+        self.logging.as_ref()
+    }
+
+    /// Logs the requests and responses; see [`Logging`].
+    #[cfg(feature = "tracing")]
+    pub fn set_logging(&mut self, logging: Option<Logging>) {
+        self.logging = logging;
+    }
+
+    /// The policy used to follow redirects, if set with
+    /// [`ClientBuilder::redirect_policy`]; otherwise, redirects are followed
+    /// by the underlying `reqwest` client.
+    #[must_use]
+    pub fn redirect_policy(&self) -> Option<&RedirectPolicy> {
+        self.redirect_policy.as_ref()
+    }
+
+    #[must_use]
+    pub fn request_id_header(&self) -> Option<&reqwest::header::HeaderName> {
+        self.request_id_header.as_ref()
+    }
+
+    /// Sends a random correlation id in the given header with each request;
+    /// see [`ClientBuilder::request_id_header`].
+    pub fn set_request_id_header(&mut self, header: Option<reqwest::header::HeaderName>) {
+        self.request_id_header = header;
+    }
+
+    #[must_use]
+    pub fn response_cache(&self) -> Option<&ResponseCache> {
+        self.response_cache.as_ref()
+    }
+
+    /// Sets the cache used to send conditional `GET` requests; see
+    /// [`ResponseCache`].
+    pub fn set_response_cache(&mut self, response_cache: Option<ResponseCache>) {
+        self.response_cache = response_cache;
+    }
+
+    #[cfg(feature = "lru-cache")]
+    #[must_use]
+    pub fn lru_cache(&self) -> Option<&LruCache> {
+        self.lru_cache.as_ref()
+    }
+
+    /// Sets the cache that serves `GET` requests without contacting the
+    /// server; see [`LruCache`].
+    #[cfg(feature = "lru-cache")]
+    pub fn set_lru_cache(&mut self, lru_cache: Option<LruCache>) {
+        self.lru_cache = lru_cache;
+    }
+
+    /// Returns a copy of this client with a different request timeout, for
+    /// calls that need one:
+    ///
+    /// ```ignore
+    /// let archive = client
+    ///     .with_request_timeout(Duration::from_secs(600))
+    ///     .documents()
+    ///     .download(id, None)
+    ///     .await?;
+    /// ```
+    #[must_use]
+    pub fn with_request_timeout(&self, request_timeout: Duration) -> Self {
+        Self {
+            request_timeout: Some(request_timeout),
+            ..self.clone()
+        }
+    }
+
+    #[must_use]
+    pub fn cancellation(&self) -> Option<&CancellationToken> {
+        self.cancellation.as_ref()
+    }
+
+    /// Returns a copy of this client whose requests are aborted when the
+    /// given token is cancelled, including the downloads being streamed:
+    ///
+    /// ```ignore
+    /// let token = CancellationToken::new();
+    /// let client = client.with_cancellation(token.clone());
+    /// tokio::spawn(async move { client.documents().download(id, None).await });
+    /// // later, when the user navigates away
+    /// token.cancel();
+    /// ```
+    #[must_use]
+    pub fn with_cancellation(&self, token: CancellationToken) -> Self {
+        Self {
+            cancellation: Some(token),
+            ..self.clone()
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -89,6 +477,159 @@ pub fn translate_method(method: Method) -> reqwest::Method {
 ////////////////////////////////////////////////////////////////////////////////
 // Internal helpers
 
+/// Upper bound on the amount of memory reserved upfront for a response body,
+/// to avoid trusting an absurd `Content-Length` header blindly.
+const MAX_BODY_PREALLOCATION: usize = 256 * 1024 * 1024;
+
+/// Converts a form to its `reqwest` equivalent; streamed parts stay streamed.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn translate_form(form: Form) -> anyhow::Result<reqwest::multipart::Form> {
+    let mut result = reqwest::multipart::Form::new();
+    for (name, part) in form.parts {
+        let mut translated = match part.content {
+            Content::Text(text) => reqwest::multipart::Part::text(text),
+            Content::Bytes(bytes) => reqwest::multipart::Part::stream(bytes),
+            Content::Stream {
+                stream,
+                length: Some(length),
+            } => reqwest::multipart::Part::stream_with_length(
+                reqwest::Body::wrap_stream(stream),
+                length,
+            ),
+            Content::Stream {
+                stream,
+                length: None,
+            } => reqwest::multipart::Part::stream(reqwest::Body::wrap_stream(stream)),
+        };
+        if let Some(file_name) = part.file_name {
+            translated = translated.file_name(file_name);
+        }
+        if let Some(mime_type) = part.mime_type {
+            translated = translated.mime_str(&mime_type)?;
+        }
+        result = result.part(name, translated);
+    }
+    Ok(result)
+}
+
+/// Reads the whole body of a response into a single buffer, reserved upfront
+/// when the length of the body is known. Unlike [`reqwest::Response::bytes`],
+/// which keeps every received chunk alive while concatenating them, this never
+/// holds more than one copy of the body in memory.
+#[cfg(not(target_arch = "wasm32"))]
+async fn read_body(mut resp: reqwest::Response) -> reqwest::Result<Bytes> {
+    let capacity = resp
+        .content_length()
+        .and_then(|length| usize::try_from(length).ok())
+        .unwrap_or(0)
+        .min(MAX_BODY_PREALLOCATION);
+    let mut buffer = BytesMut::with_capacity(capacity);
+    while let Some(chunk) = resp.chunk().await? {
+        buffer.extend_from_slice(&chunk);
+    }
+    Ok(buffer.freeze())
+}
+
+/// Reads the whole body of a response; in the browser, the body is received
+/// by `fetch` as a whole.
+#[cfg(target_arch = "wasm32")]
+async fn read_body(resp: reqwest::Response) -> reqwest::Result<Bytes> {
+    resp.bytes().await
+}
+
+/// Turns error statuses, and redirects that weren't followed, into errors.
+async fn check_status(
+    method: Method,
+    endpoint: &str,
+    request_id: Option<&String>,
+    resp: reqwest::Response,
+) -> Result<reqwest::Response> {
+    let status = resp.status();
+    if status.is_redirection() && status != reqwest::StatusCode::NOT_MODIFIED {
+        let location = resp
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|h| h.to_str().ok())
+            .unwrap_or_default();
+        return Err(Error::Server {
+            method,
+            endpoint: endpoint.to_string(),
+            request_id: request_id.cloned(),
+            status: format!("{status}"),
+            content: serde_json::Value::String(location.to_string()),
+            source: anyhow::anyhow!("redirect to {location:?} not followed"),
+        });
+    }
+
+    if let Err(source) = resp.error_for_status_ref() {
+        let content = match resp.text().await {
+            Err(_) => serde_json::Value::String("<failed to retrieve content>".to_string()),
+            Ok(content) => {
+                serde_json::from_str(&content).unwrap_or(serde_json::Value::String(content))
+            }
+        };
+        return Err(Error::Server {
+            method,
+            endpoint: endpoint.to_string(),
+            request_id: request_id.cloned(),
+            status: format!("{status}"),
+            content,
+            source: source.into(),
+        });
+    }
+
+    Ok(resp)
+}
+
+/// Fails unless the response is JSON.
+fn check_json(extra: &Extra) -> Result<()> {
+    if extra.content_type.as_deref() == Some("application/json") {
+        return Ok(());
+    }
+    Err(Error::ContentType {
+        method: extra.method,
+        endpoint: extra.endpoint.clone(),
+        expected: vec!["application/json".to_string()],
+        received: extra.content_type.clone(),
+    })
+}
+
+/// Generates a random UUID (version 4), used as a correlation id.
+fn generate_request_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let state = RandomState::new();
+    let high = state.hash_one(COUNTER.fetch_add(1, Ordering::Relaxed));
+    let low = state.hash_one(high);
+    let bits = (u128::from(high) << 64) | u128::from(low);
+    let bits = (bits & !(0xF << 76) & !(0x3 << 62)) | (0x4 << 76) | (0x2 << 62);
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        bits >> 96,
+        (bits >> 80) & 0xFFFF,
+        (bits >> 64) & 0xFFFF,
+        (bits >> 48) & 0xFFFF,
+        bits & 0xFFFF_FFFF_FFFF,
+    )
+}
+
+fn sign(signer: &dyn Signer, method: Method, request: &mut reqwest::Request) -> anyhow::Result<()> {
+    let headers = signer.sign(&SignableRequest {
+        method,
+        url: request.url(),
+        body: request
+            .body()
+            .and_then(reqwest::Body::as_bytes)
+            .unwrap_or_default(),
+    })?;
+    for (name, value) in headers {
+        request.headers_mut().insert(
+            reqwest::header::HeaderName::from_bytes(name.as_bytes())?,
+            reqwest::header::HeaderValue::from_str(&value)?,
+        );
+    }
+    Ok(())
+}
+
 impl Client {
     fn build<P, B>(
         &self,
@@ -100,139 +641,539 @@ impl Client {
     where
         P: Serialize,
         B: Serialize,
+    {
+        let mut request = self.prepare(method, endpoint, params);
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        self.finish(method, endpoint, request)
+    }
+
+    /// Starts building a request, with everything but its body.
+    fn prepare<P>(&self, method: Method, endpoint: &str, params: &P) -> reqwest::RequestBuilder
+    where
+        P: Serialize,
     {
         let uri = format!("{}{endpoint}", self.server_url);
         let mut request = self
             .inner
             .request(translate_method(method), &uri)
-            .header(reqwest::header::ACCEPT, "application/json; version=9")
-            .header(reqwest::header::AUTHORIZATION, self.auth.header_value())
+            .header(
+                reqwest::header::ACCEPT,
+                format!("application/json; version={}", self.api_version),
+            )
             .query(params);
-        if let Some(body) = body {
-            request = request.json(body);
+        if let Some(timeout) = self.request_timeout {
+            request = request.timeout(timeout);
         }
-        for (header_name, header_value) in &self.additional_headers {
+        for (header_name, header_value) in
+            self.auth.headers().iter().chain(&self.additional_headers)
+        {
             request = request.header(header_name, header_value);
         }
-        request.build().map_err(|e| Error::RequestBuild {
+        request
+    }
+
+    /// Builds the request, and applies the interceptors and the signer.
+    fn finish(
+        &self,
+        method: Method,
+        endpoint: &str,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Request> {
+        let mut request = request.build().map_err(|e| Error::RequestBuild {
             method,
             endpoint: endpoint.to_string(),
             source: e.into(),
-        })
+        })?;
+        for interceptor in &self.interceptors {
+            interceptor
+                .before_send(&mut request)
+                .map_err(|source| Error::RequestBuild {
+                    method,
+                    endpoint: endpoint.to_string(),
+                    source,
+                })?;
+        }
+        if let Some(cache) = &self.response_cache
+            && method == Method::GET
+        {
+            cache.apply(&mut request);
+        }
+        if let Some(signer) = &self.signer {
+            sign(signer.as_ref(), method, &mut request).map_err(|source| Error::RequestBuild {
+                method,
+                endpoint: endpoint.to_string(),
+                source,
+            })?;
+        }
+        Ok(request)
     }
-}
 
-////////////////////////////////////////////////////////////////////////////////
-// Traits
+    /// Reads and decodes the JSON body of a response.
+    async fn decode<R>(&self, resp: reqwest::Response, mut extra: Extra) -> Result<Response<R>>
+    where
+        R: for<'a> Deserialize<'a>,
+    {
+        let cached = self.cached(&resp, &mut extra);
+        check_json(&extra)?;
+        let content = match cached {
+            Some(content) => content,
+            None => self.read(resp, &extra).await?,
+        };
+        self.parse(content, extra)
+    }
 
-#[async_trait]
-impl ClientTrait for Client {
-    type Extra = Extra;
+    /// Decodes a JSON body that was already read.
+    fn parse<R>(&self, content: Bytes, extra: Extra) -> Result<Response<R>>
+    where
+        R: for<'a> Deserialize<'a>,
+    {
+        let value = json::decode(
+            extra.method,
+            &extra.endpoint,
+            content.into(),
+            self.max_error_content,
+        )?;
+        Ok(Response { value, extra })
+    }
 
-    async fn request_json<P, B, R>(
+    /// Returns the response from the [`LruCache`] if possible; otherwise,
+    /// sends the request and reads its whole body.
+    async fn fetch<P, B>(
         &self,
         method: Method,
         endpoint: &str,
         params: &P,
         body: Option<&B>,
-    ) -> Result<Response<R>>
+    ) -> Result<(Bytes, Extra)>
     where
-        B: Serialize + Sync,
-        P: Serialize + Sync,
-        R: for<'a> Deserialize<'a>,
+        P: Serialize,
+        B: Serialize,
     {
-        let request = self.build(method, endpoint, params, body)?;
-        let start = SystemTime::now();
-        let resp = self
-            .inner
-            .execute(request)
+        #[cfg(feature = "lru-cache")]
+        if let Some(cache) = &self.lru_cache
+            && method == Method::GET
+            && body.is_none()
+            && cache.covers(endpoint)
+            && let Ok(key) = serde_json::to_string(params)
+        {
+            let key = format!("{endpoint} {key}");
+            if let Some((content, mut extra)) = cache.get(&key) {
+                extra.cache = Some(CacheStatus::Hit);
+                return Ok((content, extra));
+            }
+            let (content, mut extra) = self.download(method, endpoint, params, body).await?;
+            extra.cache = Some(CacheStatus::Miss);
+            cache.store(key, &content, &extra);
+            return Ok((content, extra));
+        }
+        self.download(method, endpoint, params, body).await
+    }
+
+    /// Sends the request and reads its whole body; identical `GET` requests
+    /// sent concurrently share a single response if deduplication is enabled.
+    async fn download<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<(Bytes, Extra)>
+    where
+        P: Serialize,
+        B: Serialize,
+    {
+        let fetch = async {
+            let _permit = self.permit().await;
+            let (resp, mut extra) = self.send(method, endpoint, params, body).await?;
+            let content = match self.cached(&resp, &mut extra) {
+                Some(content) => content,
+                None => self.read(resp, &extra).await?,
+            };
+            Ok((content, extra))
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(deduplicator) = &self.deduplicator
+            && method == Method::GET
+            && body.is_none()
+            && let Ok(params) = serde_json::to_string(params)
+        {
+            return deduplicator
+                .run(format!("{endpoint} {params}"), fetch)
+                .await;
+        }
+        fetch.await
+    }
+
+    /// Returns the cached body if the server answered that it wasn't
+    /// modified, and restores the corresponding content type.
+    fn cached(&self, resp: &reqwest::Response, extra: &mut Extra) -> Option<Bytes> {
+        if resp.status() != reqwest::StatusCode::NOT_MODIFIED {
+            return None;
+        }
+        let (content, content_type) = self.response_cache.as_ref()?.get(resp.url())?;
+        extra.content_type = content_type;
+        Some(content)
+    }
+
+    /// Reads the whole body of the response, and caches it if possible.
+    async fn read(&self, resp: reqwest::Response, extra: &Extra) -> Result<Bytes> {
+        let url = resp.url().clone();
+        let content = read_body(resp)
             .await
-            .map_err(|source| Error::RequestSend {
-                method,
-                endpoint: endpoint.to_string(),
+            .map_err(|source| Error::ResponseBody {
+                method: extra.method,
+                endpoint: extra.endpoint.clone(),
                 source: source.into(),
             })?;
-        let duration = start.elapsed().unwrap_or(Duration::from_secs(0));
-        let status = resp.status();
-        let headers = resp.headers().clone();
-        let content_type = headers
-            .get(reqwest::header::CONTENT_TYPE)
-            .and_then(|h| h.to_str().ok())
-            .map(String::from);
+        if let Some(cache) = &self.response_cache
+            && extra.method == Method::GET
+        {
+            cache.store(&url, &extra.headers, extra.content_type.clone(), &content);
+        }
+        #[cfg(feature = "tracing")]
+        if let Some(logging) = &self.logging {
+            logging.response_body(extra.method, &url, extra.content_type.as_deref(), &content);
+        }
+        Ok(content)
+    }
 
-        if let Err(source) = resp.error_for_status_ref() {
-            let content = match resp.text().await {
-                Err(_) => serde_json::Value::String("<failed to retrieve content>".to_string()),
-                Ok(content) => {
-                    serde_json::from_str(&content).unwrap_or(serde_json::Value::String(content))
-                }
-            };
-            return Err(Error::Server {
+    /// Sends the request, and notifies the interceptors of the result.
+    async fn send<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<(reqwest::Response, Extra)>
+    where
+        P: Serialize,
+        B: Serialize,
+    {
+        let build = || self.build(method, endpoint, params, body);
+        let result = self
+            .guarded(
                 method,
-                endpoint: endpoint.to_string(),
-                status: format!("{status}"),
-                content,
-                source: source.into(),
-            });
+                endpoint,
+                Box::pin(self.execute(method, endpoint, build)),
+            )
+            .await;
+        for interceptor in &self.interceptors {
+            interceptor.after_receive(result.as_ref().map(|(_, extra)| extra));
         }
+        result
+    }
 
-        if content_type != Some("application/json".to_string()) {
-            return Err(Error::ContentType {
+    /// Sends the request once, after waiting for the rate limiter, and
+    /// records the corresponding metrics.
+    async fn attempt(
+        &self,
+        method: Method,
+        endpoint: &str,
+        request: reqwest::Request,
+    ) -> reqwest::Result<reqwest::Response> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        let request_size = request
+            .body()
+            .and_then(reqwest::Body::as_bytes)
+            .map(<[u8]>::len);
+        if let Some(metrics) = &self.metrics {
+            metrics.request_started(&RequestStarted {
                 method,
-                endpoint: endpoint.to_string(),
-                expected: vec!["application/json".to_string()],
-                received: content_type,
+                endpoint,
+                request_size,
             });
         }
+        #[cfg(feature = "tracing")]
+        let url = self.logging.as_ref().map(|logging| {
+            logging.request(method, &request);
+            request.url().clone()
+        });
+        let start = Instant::now();
+        let result = self.inner.execute(request).await;
+        #[cfg(feature = "tracing")]
+        if let Some(logging) = &self.logging
+            && let Some(url) = &url
+        {
+            logging.response(method, url, &result, start.elapsed());
+        }
+        if let Some(metrics) = &self.metrics {
+            let resp = result.as_ref().ok();
+            metrics.request_finished(&RequestFinished {
+                method,
+                endpoint,
+                status: resp.map(|resp| resp.status().as_u16()),
+                duration: start.elapsed(),
+                request_size,
+                response_size: resp.and_then(reqwest::Response::content_length),
+            });
+        }
+        result
+    }
 
-        let content = resp.text().await.map_err(|source| Error::ResponseBody {
-            method,
-            endpoint: endpoint.to_string(),
-            source: source.into(),
-        })?;
-
-        Ok(Response {
-            value: serde_json::from_str(&content).map_err(|source| Error::Deserializing {
+    /// Sends the multipart request once, since its body can't be rebuilt, and
+    /// notifies the interceptors of the result.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn send_multipart<P>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        form: Form,
+    ) -> Result<(reqwest::Response, Extra)>
+    where
+        P: Serialize,
+    {
+        let mut form = Some(form);
+        let build = || {
+            let form = form.take().ok_or_else(|| Error::RequestBuild {
+                method,
+                endpoint: endpoint.to_string(),
+                source: anyhow::anyhow!("a multipart request can't be sent twice"),
+            })?;
+            let form = translate_form(form).map_err(|source| Error::RequestBuild {
                 method,
                 endpoint: endpoint.to_string(),
-                typename: std::any::type_name::<R>(),
-                content,
                 source,
-            })?,
-            extra: Extra {
+            })?;
+            self.finish(
+                method,
+                endpoint,
+                self.prepare(method, endpoint, params).multipart(form),
+            )
+        };
+        let result = self
+            .guarded(
+                method,
+                endpoint,
+                Box::pin(self.execute(method, endpoint, build)),
+            )
+            .await;
+        for interceptor in &self.interceptors {
+            interceptor.after_receive(result.as_ref().map(|(_, extra)| extra));
+        }
+        result
+    }
+
+    /// Runs the given future unless the circuit breaker, if any, is open, and
+    /// records its outcome.
+    async fn guarded<T>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        future: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let Some(breaker) = &self.circuit_breaker else {
+            return future.await;
+        };
+        if let Err(open) = breaker.check() {
+            return Err(Error::RequestSend {
                 method,
                 endpoint: endpoint.to_string(),
-                status,
-                headers,
-                duration,
-                content_type,
-            },
-        })
+                request_id: None,
+                source: open.into(),
+            });
+        }
+        let result = future.await;
+        breaker.record(&result);
+        result
     }
 
-    async fn request_bytes<P, B>(
+    /// Runs the given future, unless the cancellation token, if any, is
+    /// cancelled first.
+    async fn cancellable<T>(
         &self,
         method: Method,
         endpoint: &str,
-        params: &P,
-        body: Option<&B>,
-    ) -> Result<Response<Bytes>>
-    where
-        P: Serialize + Sync,
-        B: Serialize + Sync,
-    {
-        let request = self.build(method, endpoint, params, body)?;
-        let start = SystemTime::now();
-        let resp = self
-            .inner
-            .execute(request)
-            .await
-            .map_err(|source| Error::RequestSend {
+        future: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let Some(token) = &self.cancellation else {
+            return future.await;
+        };
+        match future::select(pin!(token.cancelled()), pin!(future)).await {
+            Either::Left(((), _)) => Err(Error::Cancelled {
                 method,
                 endpoint: endpoint.to_string(),
-                source: source.into(),
-            })?;
+            }),
+            Either::Right((result, _)) => result,
+        }
+    }
+
+    /// Ends the stream with an error if the cancellation token, if any, is
+    /// cancelled before it completes.
+    fn interruptible(&self, method: Method, endpoint: &str, stream: ByteStream) -> ByteStream {
+        let Some(token) = self.cancellation.clone() else {
+            return stream;
+        };
+        let endpoint = endpoint.to_string();
+        let cancelled = token.cancelled();
+        let error = stream::once(async move {
+            token
+                .is_cancelled()
+                .then_some(Err(Error::Cancelled { method, endpoint }))
+        })
+        .filter_map(future::ready);
+        Box::pin(stream.take_until(cancelled).chain(error))
+    }
+
+    /// Waits for the concurrency limiter, if any; the request is in flight
+    /// until the returned permit is dropped.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn permit(&self) -> Option<tokio::sync::OwnedSemaphorePermit> {
+        match &self.concurrency_limiter {
+            Some(limiter) => limiter.acquire().await,
+            None => None,
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    #[allow(clippy::unused_async)]
+    async fn permit(&self) -> Option<()> {
+        None
+    }
+
+    /// Obtains the `Authorization` header from the provider of a dynamic
+    /// [`Auth`]; `None` for static credentials, already set by `prepare`.
+    async fn authorize(
+        &self,
+        method: Method,
+        endpoint: &str,
+        refresh: bool,
+    ) -> Result<Option<reqwest::header::HeaderValue>> {
+        let Auth::Dynamic(provider) = &self.auth else {
+            return Ok(None);
+        };
+        let error = |source| Error::RequestBuild {
+            method,
+            endpoint: endpoint.to_string(),
+            source,
+        };
+        let value = provider.authorization(refresh).await.map_err(error)?;
+        let mut value =
+            reqwest::header::HeaderValue::from_str(&value).map_err(|e| error(e.into()))?;
+        value.set_sensitive(true);
+        Ok(Some(value))
+    }
+
+    /// Adds the correlation id to the request, if enabled.
+    fn tag(&self, request: &mut reqwest::Request, request_id: Option<&str>) {
+        if let Some(header) = &self.request_id_header
+            && let Some(request_id) = request_id
+            && let Ok(value) = reqwest::header::HeaderValue::from_str(request_id)
+        {
+            request.headers_mut().insert(header, value);
+        }
+    }
+
+    /// Sends the request, and follows the redirects allowed by the redirect
+    /// policy, if any; each redirect is a separate attempt.
+    async fn follow(
+        &self,
+        method: Method,
+        endpoint: &str,
+        mut request: reqwest::Request,
+    ) -> reqwest::Result<reqwest::Response> {
+        let Some(policy) = &self.redirect_policy else {
+            return self.attempt(method, endpoint, request).await;
+        };
+        let mut redirects = 0;
+        loop {
+            let next = request.try_clone();
+            let resp = self.attempt(method, endpoint, request).await?;
+            let Some(mut next) =
+                next.and_then(|next| policy.follow(&self.auth, &resp, next, redirects))
+            else {
+                return Ok(resp);
+            };
+            if let Some(signer) = &self.signer
+                && sign(signer.as_ref(), method, &mut next).is_err()
+            {
+                return Ok(resp);
+            }
+            request = next;
+            redirects += 1;
+        }
+    }
+
+    /// Sends the request, retrying it according to the retry policy, and
+    /// returns the response if its status is a success. Requests whose body
+    /// is streamed are sent only once: `build` fails if called again.
+    async fn execute(
+        &self,
+        method: Method,
+        endpoint: &str,
+        mut build: impl FnMut() -> Result<reqwest::Request>,
+    ) -> Result<(reqwest::Response, Extra)> {
+        let start = SystemTime::now();
+        let request_id = self
+            .request_id_header
+            .as_ref()
+            .map(|_| generate_request_id());
+        let mut attempts = 0;
+        let mut waited = Duration::ZERO;
+        let mut authorization = self.authorize(method, endpoint, false).await?;
+        let mut refreshed = false;
+        let resp = loop {
+            let mut request = build()?;
+            if let Some(value) = &authorization {
+                request
+                    .headers_mut()
+                    .insert(reqwest::header::AUTHORIZATION, value.clone());
+            }
+            self.tag(&mut request, request_id.as_deref());
+            let retriable = request.body().is_none_or(|body| body.as_bytes().is_some());
+            let result = self.follow(method, endpoint, request).await;
+            if retriable
+                && !refreshed
+                && authorization.is_some()
+                && let Ok(resp) = &result
+                && resp.status() == reqwest::StatusCode::UNAUTHORIZED
+            {
+                refreshed = true;
+                authorization = self.authorize(method, endpoint, true).await?;
+                continue;
+            }
+            if retriable
+                && let Ok(resp) = &result
+                && let Some(policy) = &self.retry_after
+                && let Some(delay) = policy.delay(resp, waited)
+            {
+                waited += delay;
+                sleep(delay).await;
+                continue;
+            }
+            attempts += 1;
+            let retry = self
+                .retry_policy
+                .as_ref()
+                .filter(|policy| retriable && policy.allows(method, attempts));
+            match result {
+                Ok(resp) => match retry {
+                    Some(policy) if policy.retries_status(resp.status()) => {
+                        sleep(policy.delay(attempts)).await;
+                    }
+                    _ => break resp,
+                },
+                Err(source) => match retry {
+                    Some(policy) if policy.retries_error(&source) => {
+                        sleep(policy.delay(attempts)).await;
+                    }
+                    _ => {
+                        return Err(Error::RequestSend {
+                            method,
+                            endpoint: endpoint.to_string(),
+                            request_id: request_id.clone(),
+                            source: source.into(),
+                        });
+                    }
+                },
+            }
+        };
         let duration = start.elapsed().unwrap_or(Duration::from_secs(0));
+        let resp = check_status(method, endpoint, request_id.as_ref(), resp).await?;
         let status = resp.status();
         let headers = resp.headers().clone();
         let content_type = headers
@@ -240,37 +1181,63 @@ impl ClientTrait for Client {
             .and_then(|h| h.to_str().ok())
             .map(String::from);
 
-        if let Err(source) = resp.error_for_status_ref() {
-            let content = match resp.text().await {
-                Err(_) => serde_json::Value::String("<failed to retrieve content>".to_string()),
-                Ok(content) => {
-                    serde_json::from_str(&content).unwrap_or(serde_json::Value::String(content))
-                }
-            };
-            return Err(Error::Server {
-                method,
-                endpoint: endpoint.to_string(),
-                status: format!("{status}"),
-                content,
-                source: source.into(),
-            });
-        }
-
-        Ok(Response {
-            value: resp.bytes().await.map_err(|source| Error::ResponseBody {
-                method,
-                endpoint: endpoint.to_string(),
-                source: source.into(),
-            })?,
-            extra: Extra {
+        Ok((
+            resp,
+            Extra {
                 method,
                 endpoint: endpoint.to_string(),
+                request_id,
                 status,
                 headers,
                 duration,
                 content_type,
+                #[cfg(feature = "lru-cache")]
+                cache: None,
             },
-        })
+        ))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ClientTrait for Client {
+    type Extra = Extra;
+
+    async fn request_json<P, B, R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<R>>
+    where
+        B: Serialize + Sync,
+        P: Serialize + Sync,
+        R: for<'a> Deserialize<'a>,
+    {
+        let fetch = self.fetch(method, endpoint, params, body);
+        let (content, extra) = self.cancellable(method, endpoint, fetch).await?;
+        check_json(&extra)?;
+        self.parse(content, extra)
+    }
+
+    async fn request_bytes<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<Bytes>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let fetch = self.fetch(method, endpoint, params, body);
+        let (value, extra) = self.cancellable(method, endpoint, fetch).await?;
+        Ok(Response { value, extra })
     }
 
     async fn request_unit<P, B>(
@@ -284,51 +1251,73 @@ impl ClientTrait for Client {
         P: Serialize + Sync,
         B: Serialize + Sync,
     {
-        let request = self.build(method, endpoint, params, body)?;
-        let start = SystemTime::now();
-        let resp = self
-            .inner
-            .execute(request)
-            .await
-            .map_err(|source| Error::RequestSend {
-                method,
-                endpoint: endpoint.to_string(),
-                source: source.into(),
-            })?;
-        let duration = start.elapsed().unwrap_or(Duration::from_secs(0));
-        let status = resp.status();
-        let headers = resp.headers().clone();
-        let content_type = headers
-            .get(reqwest::header::CONTENT_TYPE)
-            .and_then(|h| h.to_str().ok())
-            .map(String::from);
+        let send = async {
+            let _permit = self.permit().await;
+            self.send(method, endpoint, params, body).await
+        };
+        let (_, extra) = self.cancellable(method, endpoint, send).await?;
+        Ok(Response { value: (), extra })
+    }
 
-        if let Err(source) = resp.error_for_status_ref() {
-            let content = match resp.text().await {
-                Err(_) => serde_json::Value::String("<failed to retrieve content>".to_string()),
-                Ok(content) => {
-                    serde_json::from_str(&content).unwrap_or(serde_json::Value::String(content))
-                }
-            };
-            return Err(Error::Server {
-                method,
-                endpoint: endpoint.to_string(),
-                status: format!("{status}"),
-                content,
-                source: source.into(),
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn request_multipart<P, R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        form: Form,
+    ) -> Result<Response<R>>
+    where
+        P: Serialize + Sync,
+        R: for<'a> Deserialize<'a>,
+    {
+        let send = async {
+            let _permit = self.permit().await;
+            let (resp, extra) = self.send_multipart(method, endpoint, params, form).await?;
+            self.decode(resp, extra).await
+        };
+        self.cancellable(method, endpoint, send).await
+    }
+
+    async fn request_stream<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<ByteStream>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let send = async {
+            let permit = self.permit().await;
+            let (resp, extra) = self.send(method, endpoint, params, body).await?;
+            Ok((permit, resp, extra))
+        };
+        let (permit, resp, mut extra) = self.cancellable(method, endpoint, send).await?;
+        if let Some(content) = self.cached(&resp, &mut extra) {
+            return Ok(Response {
+                value: Box::pin(stream::once(async { Ok(content) })),
+                extra,
             });
         }
-
-        Ok(Response {
-            value: (),
-            extra: Extra {
+        let name = endpoint.to_string();
+        let stream = resp.bytes_stream().map(move |chunk| {
+            let _permit = &permit;
+            chunk.map_err(|source| Error::ResponseBody {
                 method,
-                endpoint: endpoint.to_string(),
-                status,
-                headers,
-                duration,
-                content_type,
-            },
+                endpoint: name.clone(),
+                source: source.into(),
+            })
+        });
+        Ok(Response {
+            value: self.interruptible(method, endpoint, Box::pin(stream)),
+            extra,
         })
     }
+
+    fn server_version(&self) -> Option<ServerVersion> {
+        self.server_version
+    }
 }
@@ -0,0 +1,59 @@
+use std::num::NonZeroU32;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use web_time::Instant;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Token-bucket rate limiter, applied to every request sent by a client,
+/// including retries. The bucket is shared by all clones of the limiter, and
+/// therefore by all clones of a client.
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    tolerance: Duration,
+    next: Arc<Mutex<Instant>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl RateLimiter {
+    /// Allows `per_second` requests per second on average, and up to `burst`
+    /// requests at once after a period of inactivity.
+    #[must_use]
+    pub fn new(per_second: NonZeroU32, burst: NonZeroU32) -> Self {
+        let interval = Duration::from_secs(1) / per_second.get();
+        Self {
+            interval,
+            tolerance: interval * (burst.get() - 1),
+            next: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl RateLimiter {
+    /// Waits until a request can be sent.
+    ///
+    /// This uses the "generic cell rate algorithm": `next` is the time at which
+    /// the bucket would be full again; a request is allowed if that time is
+    /// less than `tolerance` in the future, and each request pushes it back by
+    /// `interval`. The slot is reserved before waiting, so that concurrent
+    /// requests are queued in order.
+    pub(crate) async fn acquire(&self) {
+        let delay = {
+            let mut next = self.next.lock().unwrap_or_else(PoisonError::into_inner);
+            let now = Instant::now();
+            let arrival = (*next).max(now);
+            *next = arrival + self.interval;
+            arrival.saturating_duration_since(now + self.tolerance)
+        };
+        if !delay.is_zero() {
+            crate::utils::sleep(delay).await;
+        }
+    }
+}
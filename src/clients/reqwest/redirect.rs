@@ -0,0 +1,214 @@
+use reqwest::StatusCode;
+use reqwest::header::{
+    AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, COOKIE, LOCATION, PROXY_AUTHORIZATION,
+};
+
+use crate::auth::Auth;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Describes which redirects are followed, and whether credentials are sent
+/// to the new location.
+///
+/// When a client is built with a redirect policy, redirects are followed by
+/// the client itself rather than by `reqwest`, which always drops the
+/// `Authorization` header when redirected to another origin. Redirects that
+/// aren't followed are returned as [`Error::Server`](crate::error::Error).
+///
+/// As with `reqwest`, `303 See Other` redirects, and `301` and `302` redirects
+/// of `POST` requests, are followed with a `GET` request without a body.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RedirectPolicy {
+    /// Maximum number of redirects followed for a single request.
+    pub max_redirects: usize,
+    /// Whether redirects to another origin (scheme, host, and port) are
+    /// followed.
+    pub cross_origin: bool,
+    /// Whether the credentials are kept when redirected to another origin,
+    /// for instance when a reverse proxy redirects to a canonical host: the
+    /// `Authorization`, `Proxy-Authorization` and `Cookie` headers, and the
+    /// headers of [`Auth::RemoteUser`]. Only enable this if all the origins
+    /// involved are trusted.
+    pub forward_auth: bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl Default for RedirectPolicy {
+    fn default() -> Self {
+        Self {
+            max_redirects: 10,
+            cross_origin: true,
+            forward_auth: false,
+        }
+    }
+}
+
+impl RedirectPolicy {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Doesn't follow any redirect.
+    #[must_use]
+    pub fn none() -> Self {
+        Self::default().max_redirects(0)
+    }
+
+    #[must_use]
+    pub fn max_redirects(mut self, value: usize) -> Self {
+        self.max_redirects = value;
+        self
+    }
+
+    #[must_use]
+    pub fn cross_origin(mut self, value: bool) -> Self {
+        self.cross_origin = value;
+        self
+    }
+
+    #[must_use]
+    pub fn forward_auth(mut self, value: bool) -> Self {
+        self.forward_auth = value;
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl RedirectPolicy {
+    /// Turns `request`, a copy of the request that got `resp`, into the
+    /// request to send next, if `resp` is a redirect that should be followed;
+    /// the credentials of `auth` are removed from requests to other origins.
+    pub(crate) fn follow(
+        &self,
+        auth: &Auth,
+        resp: &reqwest::Response,
+        mut request: reqwest::Request,
+        redirects: usize,
+    ) -> Option<reqwest::Request> {
+        let status = resp.status();
+        if !status.is_redirection()
+            || status == StatusCode::NOT_MODIFIED
+            || redirects >= self.max_redirects
+        {
+            return None;
+        }
+        let location = resp.headers().get(LOCATION)?.to_str().ok()?;
+        let url = resp.url().join(location).ok()?;
+        if url.origin() != resp.url().origin() {
+            if !self.cross_origin {
+                return None;
+            }
+            if !self.forward_auth {
+                let headers = request.headers_mut();
+                headers.remove(AUTHORIZATION);
+                headers.remove(PROXY_AUTHORIZATION);
+                headers.remove(COOKIE);
+                for (name, _) in auth.headers() {
+                    headers.remove(name.as_str());
+                }
+            }
+        }
+        let post = request.method() == reqwest::Method::POST;
+        if status == StatusCode::SEE_OTHER
+            || (post && matches!(status, StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND))
+        {
+            *request.method_mut() = reqwest::Method::GET;
+            *request.body_mut() = None;
+            request.headers_mut().remove(CONTENT_TYPE);
+            request.headers_mut().remove(CONTENT_LENGTH);
+        }
+        *request.url_mut() = url;
+        Some(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::RedirectPolicy;
+    use crate::auth::Auth;
+    use crate::clients::Client as _;
+    use crate::clients::reqwest::Client;
+    use crate::utils::{Method, body, params};
+
+    /// Serves a single request with the given response, and returns the URL
+    /// of the server and the head of the request it received.
+    async fn serve_once(response: String) -> (String, Arc<Mutex<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let received = Arc::new(Mutex::new(String::new()));
+        let head = received.clone();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buffer = vec![0; 8192];
+            let mut read = 0;
+            while !String::from_utf8_lossy(&buffer[..read]).contains("\r\n\r\n") {
+                read += socket.read(&mut buffer[read..]).await.unwrap();
+            }
+            *head.lock().unwrap() = String::from_utf8_lossy(&buffer[..read]).to_lowercase();
+            socket.write_all(response.as_bytes()).await.unwrap();
+        });
+        (format!("http://localhost:{port}"), received)
+    }
+
+    #[test]
+    fn cross_origin_redirects_drop_credentials() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let (target, received) = serve_once(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 2\r\n\r\n{}"
+                    .to_string(),
+            )
+            .await;
+            let (origin, _) = serve_once(format!(
+                "HTTP/1.1 302 Found\r\nlocation: {target}/api/tags/\r\ncontent-length: 0\r\n\r\n"
+            ))
+            .await;
+            let origin = origin.replace("localhost", "127.0.0.1");
+            let auth = Auth::RemoteUser {
+                header: "Remote-User".to_string(),
+                username: "alice".to_string(),
+                cookies: vec![("session".to_string(), "secret".into())],
+            };
+            let client = Client::builder(origin, auth)
+                .additional_headers(vec![(
+                    "Proxy-Authorization".to_string(),
+                    "Basic c2VjcmV0".to_string(),
+                )])
+                .redirect_policy(RedirectPolicy::default())
+                .build()
+                .unwrap();
+            client
+                .request_json::<_, _, serde_json::Value>(
+                    Method::GET,
+                    "/api/tags/",
+                    params::NONE,
+                    body::NONE,
+                )
+                .await
+                .unwrap();
+            let head = received.lock().unwrap().clone();
+            assert!(head.starts_with("get /api/tags/"), "{head}");
+            for header in [
+                "authorization:",
+                "proxy-authorization:",
+                "cookie:",
+                "remote-user:",
+            ] {
+                assert!(!head.contains(header), "{header} was forwarded:\n{head}");
+            }
+        });
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::time::Duration;
+use web_time::{SystemTime, UNIX_EPOCH};
+
+use crate::utils::Method;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Describes which failed requests are sent again, and how long to wait
+/// before each new attempt.
+///
+/// The delay before the n-th retry is `base_delay * 2^(n-1)`, capped at
+/// `max_delay`. With `jitter` enabled, a random amount of up to half of that
+/// delay is subtracted from it, so that clients that failed at the same time
+/// don't all retry in lockstep.
+///
+/// By default, `POST` and `PATCH` requests are never retried, since the server
+/// might have processed them before failing; they can be added to `methods`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one.
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub jitter: bool,
+    /// Response statuses that trigger a retry.
+    pub statuses: Vec<reqwest::StatusCode>,
+    /// Whether failing to connect to the server triggers a retry.
+    pub connection_errors: bool,
+    /// Whether a timeout triggers a retry.
+    pub timeouts: bool,
+    /// Methods of the requests that can be retried.
+    pub methods: Vec<Method>,
+}
+
+/// Describes how to react to `429 Too Many Requests` responses: instead of
+/// failing, the client waits for the duration given by the `Retry-After`
+/// header, and sends the request again. Such requests don't count as failed
+/// attempts for the [`RetryPolicy`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryAfter {
+    /// Upper bound on the total time spent waiting for a single request; once
+    /// it would be exceeded, the 429 response is returned as an error.
+    pub max_wait: Duration,
+    /// Delay used when the response has no valid `Retry-After` header.
+    pub default_delay: Duration,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            statuses: vec![
+                reqwest::StatusCode::BAD_GATEWAY,
+                reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                reqwest::StatusCode::GATEWAY_TIMEOUT,
+            ],
+            connection_errors: true,
+            timeouts: true,
+            methods: vec![Method::GET, Method::PUT, Method::DELETE],
+        }
+    }
+}
+
+impl RetryPolicy {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn max_attempts(mut self, value: u32) -> Self {
+        self.max_attempts = value;
+        self
+    }
+
+    #[must_use]
+    pub fn base_delay(mut self, value: Duration) -> Self {
+        self.base_delay = value;
+        self
+    }
+
+    #[must_use]
+    pub fn max_delay(mut self, value: Duration) -> Self {
+        self.max_delay = value;
+        self
+    }
+
+    #[must_use]
+    pub fn jitter(mut self, value: bool) -> Self {
+        self.jitter = value;
+        self
+    }
+
+    #[must_use]
+    pub fn statuses(mut self, value: Vec<reqwest::StatusCode>) -> Self {
+        self.statuses = value;
+        self
+    }
+
+    #[must_use]
+    pub fn connection_errors(mut self, value: bool) -> Self {
+        self.connection_errors = value;
+        self
+    }
+
+    #[must_use]
+    pub fn timeouts(mut self, value: bool) -> Self {
+        self.timeouts = value;
+        self
+    }
+
+    #[must_use]
+    pub fn methods(mut self, value: Vec<Method>) -> Self {
+        self.methods = value;
+        self
+    }
+}
+
+impl Default for RetryAfter {
+    fn default() -> Self {
+        Self {
+            max_wait: Duration::from_mins(1),
+            default_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+impl RetryAfter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn max_wait(mut self, value: Duration) -> Self {
+        self.max_wait = value;
+        self
+    }
+
+    #[must_use]
+    pub fn default_delay(mut self, value: Duration) -> Self {
+        self.default_delay = value;
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl RetryPolicy {
+    /// Whether another attempt can be made after `attempts` failed ones.
+    pub(crate) fn allows(&self, method: Method, attempts: u32) -> bool {
+        attempts < self.max_attempts && self.methods.contains(&method)
+    }
+
+    pub(crate) fn retries_status(&self, status: reqwest::StatusCode) -> bool {
+        self.statuses.contains(&status)
+    }
+
+    pub(crate) fn retries_error(&self, error: &reqwest::Error) -> bool {
+        // in the browser, failures of `fetch` are reported as request errors
+        #[cfg(not(target_arch = "wasm32"))]
+        let connection_error = error.is_connect();
+        #[cfg(target_arch = "wasm32")]
+        let connection_error = error.is_request();
+        (self.connection_errors && connection_error) || (self.timeouts && error.is_timeout())
+    }
+
+    /// Delay to wait for after `attempts` failed attempts.
+    pub(crate) fn delay(&self, attempts: u32) -> Duration {
+        let factor = 1u32 << attempts.saturating_sub(1).min(31);
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        if !self.jitter {
+            return delay;
+        }
+        let nanos = u64::try_from(delay.as_nanos()).unwrap_or(u64::MAX);
+        let jitter = RandomState::new().hash_one(attempts) % (nanos / 2 + 1);
+        delay.saturating_sub(Duration::from_nanos(jitter))
+    }
+}
+
+impl RetryAfter {
+    /// Delay to wait for before sending the request again, if the response
+    /// asks for it and the total wait stays within bounds.
+    pub(crate) fn delay(&self, resp: &reqwest::Response, waited: Duration) -> Option<Duration> {
+        if resp.status() != reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return None;
+        }
+        let delay = resp
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after)
+            .unwrap_or(self.default_delay);
+        waited
+            .checked_add(delay)
+            .is_some_and(|total| total <= self.max_wait)
+            .then_some(delay)
+    }
+}
+
+/// Parses the value of a `Retry-After` header, which is either a number of
+/// seconds, or a date in the IMF-fixdate format, such as
+/// `Sun, 06 Nov 1994 08:49:37 GMT`.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = parse_http_date(value)?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+fn parse_http_date(value: &str) -> Option<SystemTime> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let (_, date) = value.split_once(", ")?;
+    let mut parts = date.split(' ');
+    let day: u64 = parts.next()?.parse().ok()?;
+    let month = parts.next()?;
+    let month = MONTHS.iter().position(|m| *m == month)? + 1;
+    let year: u64 = parts.next()?.parse().ok()?;
+    let mut time = parts.next()?.split(':').map(str::parse::<u64>);
+    let (hours, minutes, seconds) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+    if parts.next()? != "GMT"
+        || !(1970..=9999).contains(&year)
+        || !(1..=31).contains(&day)
+        || hours > 23
+        || minutes > 59
+        || seconds > 59
+    {
+        return None;
+    }
+
+    // days since the epoch, from the proleptic gregorian calendar; years start
+    // in march so that leap days are at the end of the year
+    let (year, month) = if month > 2 {
+        (year, month as u64 - 3)
+    } else {
+        (year - 1, month as u64 + 9)
+    };
+    let days =
+        365 * year + year / 4 - year / 100 + year / 400 + (153 * month + 2) / 5 + day - 1 - 719_468;
+    let seconds = ((days * 24 + hours) * 60 + minutes) * 60 + seconds;
+    Some(UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use web_time::{SystemTime, UNIX_EPOCH};
+
+    use super::{parse_http_date, parse_retry_after};
+
+    #[test]
+    fn http_dates() {
+        let date = |seconds| Some(UNIX_EPOCH + Duration::from_secs(seconds));
+        assert_eq!(parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT"), date(0));
+        assert_eq!(
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            date(784_111_777)
+        );
+        assert_eq!(
+            parse_http_date("Tue, 29 Feb 2000 12:00:00 GMT"),
+            date(951_825_600)
+        );
+        assert_eq!(
+            parse_http_date("Wed, 01 Mar 2000 00:00:00 GMT"),
+            date(951_868_800)
+        );
+        for invalid in [
+            "",
+            "06 Nov 1994 08:49:37 GMT",
+            "Sun, 06 November 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1994 08:49:37 UTC",
+            "Sun, 06 Nov 1994 08:49 GMT",
+            "Wed, 31 Dec 1969 23:59:59 GMT",
+            "Sun, 00 Nov 1994 08:49:37 GMT",
+            "Sun, 32 Nov 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1994 24:49:37 GMT",
+            "Sun, 06 Nov 1994 08:60:37 GMT",
+            "Sun, 06 Nov 1994 08:49:60 GMT",
+            "Sun, 06 Nov 99999999999999 08:49:37 GMT",
+        ] {
+            assert_eq!(parse_http_date(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn retry_after() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after(" 5 "), Some(Duration::from_secs(5)));
+        assert_eq!(
+            parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(Duration::ZERO)
+        );
+        let delay = parse_retry_after("Fri, 01 Jan 2100 00:00:00 GMT").unwrap();
+        let expected = (UNIX_EPOCH + Duration::from_secs(4_102_444_800))
+            .duration_since(SystemTime::now())
+            .unwrap();
+        assert!(expected.abs_diff(delay) < Duration::from_secs(5));
+        for invalid in ["", "-5", "1.5", "soon"] {
+            assert_eq!(parse_retry_after(invalid), None, "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use futures_util::future;
+use std::collections::BTreeMap;
+
+use crate::error::{Error, Result};
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Clients of several servers, such as a home and a work instance, or staging
+/// and production, identified by name; for migration and comparison tools:
+///
+/// ```ignore
+/// let servers = Servers::new()
+///     .with("staging", reqwest::Client::new(staging_url, staging_auth))
+///     .with("prod", reqwest::Client::new(prod_url, prod_auth));
+/// let tags = servers.get("prod")?.tags().list(&params).await?;
+/// let counts = servers
+///     .broadcast(|client| async move { client.tags().list(&params).await })
+///     .await;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Servers<C> {
+    clients: BTreeMap<String, C>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl<C> Servers<C> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            clients: BTreeMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with(mut self, name: impl Into<String>, client: C) -> Self {
+        self.insert(name, client);
+        self
+    }
+
+    /// Adds a server, replacing and returning the client previously
+    /// registered under that name, if any.
+    pub fn insert(&mut self, name: impl Into<String>, client: C) -> Option<C> {
+        self.clients.insert(name.into(), client)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<C> {
+        self.clients.remove(name)
+    }
+
+    /// Returns the client of the given server; fails with
+    /// [`Error::UnknownServer`] if there is none.
+    pub fn get(&self, name: &str) -> Result<&C> {
+        self.clients.get(name).ok_or_else(|| Error::UnknownServer {
+            name: name.to_string(),
+        })
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.clients.keys().map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &C)> {
+        self.clients
+            .iter()
+            .map(|(name, client)| (name.as_str(), client))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Runs the query on all servers concurrently, and returns the result of
+    /// each, by name. Queries shouldn't modify anything: a failure on one
+    /// server doesn't cancel the query on the others.
+    pub async fn broadcast<'a, F, R>(&'a self, query: impl Fn(&'a C) -> F) -> Vec<(&'a str, R)>
+    where
+        F: Future<Output = R>,
+    {
+        let results = future::join_all(self.clients.values().map(query)).await;
+        self.names().zip(results).collect()
+    }
+}
+
+#[cfg(all(feature = "config", not(target_arch = "wasm32")))]
+impl Servers<crate::clients::reqwest::Client> {
+    /// Builds the clients of all the profiles of the configuration, named
+    /// after them.
+    pub fn from_config(config: &crate::config::Config) -> Result<Self> {
+        config
+            .profiles
+            .iter()
+            .map(|(name, profile)| Ok((name.clone(), profile.builder()?.build()?)))
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+impl<C> Default for Servers<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> FromIterator<(String, C)> for Servers<C> {
+    fn from_iter<I: IntoIterator<Item = (String, C)>>(iter: I) -> Self {
+        Self {
+            clients: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Servers;
+    use crate::error::Error;
+
+    #[test]
+    fn unknown_servers() {
+        let servers = Servers::new().with("prod", 1);
+        assert_eq!(servers.get("prod").ok(), Some(&1));
+        let error = servers.get("staging").unwrap_err();
+        assert!(matches!(error, Error::UnknownServer { name } if name == "staging"));
+    }
+}
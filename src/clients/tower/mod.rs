@@ -0,0 +1,365 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::marker::Sync;
+use std::time::{Duration, Instant};
+use tower_service::Service;
+
+use crate::auth::Auth;
+use crate::clients::{Client as ClientTrait, json};
+use crate::error::{Error, Result};
+use crate::response;
+use crate::utils::Method;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Error type accepted from the services used by [`Client`].
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Client that sends its requests through a `tower` service, so that
+/// existing middleware, such as timeouts, load shedding or retries, can be
+/// composed around Paperless calls:
+///
+/// ```ignore
+/// let service = ServiceBuilder::new()
+///     .load_shed()
+///     .timeout(Duration::from_secs(30))
+///     .service(ReqwestTransport::new(reqwest::Client::new()));
+/// let client = tower::Client::new(service, url, auth);
+/// ```
+///
+/// The service receives fully built requests, including the credentials,
+/// and returns responses whose bodies have been read; it is cloned for each
+/// request, as is customary with `tower`, so that it can be shared.
+#[derive(Debug, Clone)]
+pub struct Client<S> {
+    inner: S,
+    server_url: String,
+    auth: Auth,
+    additional_headers: Vec<(String, String)>,
+    max_error_content: Option<usize>,
+}
+
+/// Service sending requests with a `reqwest` client, to use as the innermost
+/// layer of the stack given to [`Client`].
+#[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    inner: reqwest::Client,
+}
+
+pub struct Extra {
+    pub method: Method,
+    pub endpoint: String,
+    pub status: http::StatusCode,
+    pub headers: http::HeaderMap,
+    pub duration: Duration,
+    pub content_type: Option<String>,
+}
+
+pub type Response<R> = response::Response<R, Extra>;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl Extra {
+    /// Length of the response body, as advertised by the server.
+    #[must_use]
+    pub fn content_length(&self) -> Option<u64> {
+        self.headers
+            .get(http::header::CONTENT_LENGTH)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|h| h.parse().ok())
+    }
+}
+
+impl<S> Client<S> {
+    #[must_use]
+    pub fn new(inner: S, server_url: String, auth: Auth) -> Self {
+        Self {
+            inner,
+            server_url,
+            auth,
+            additional_headers: vec![],
+            max_error_content: None,
+        }
+    }
+
+    #[must_use]
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    #[must_use]
+    pub fn auth(&self) -> &Auth {
+        &self.auth
+    }
+
+    pub fn set_auth(&mut self, auth: Auth) {
+        self.auth = auth;
+    }
+
+    #[must_use]
+    pub fn additional_headers(&self) -> &[(String, String)] {
+        &self.additional_headers
+    }
+
+    #[must_use]
+    pub fn additional_headers_mut(&mut self) -> &mut Vec<(String, String)> {
+        &mut self.additional_headers
+    }
+
+    /// Maximum number of bytes of a response body that are kept in
+    /// [`Error::Deserializing`] when decoding fails. Unbounded by default.
+    #[must_use]
+    pub fn max_error_content(&self) -> Option<usize> {
+        self.max_error_content
+    }
+
+    #[must_use]
+    pub fn max_error_content_mut(&mut self) -> &mut Option<usize> {
+        &mut self.max_error_content
+    }
+}
+
+#[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+impl ReqwestTransport {
+    #[must_use]
+    pub fn new(inner: reqwest::Client) -> Self {
+        Self { inner }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public helpers
+
+#[must_use]
+pub fn translate_method(method: Method) -> http::Method {
+    match method {
+        Method::GET => http::Method::GET,
+        Method::PUT => http::Method::PUT,
+        Method::POST => http::Method::POST,
+        Method::PATCH => http::Method::PATCH,
+        Method::DELETE => http::Method::DELETE,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl<S> Client<S>
+where
+    S: Service<http::Request<Bytes>, Response = http::Response<Bytes>> + Clone + Send + Sync,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+{
+    fn build<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> anyhow::Result<http::Request<Bytes>>
+    where
+        P: Serialize,
+        B: Serialize,
+    {
+        let query = serde_urlencoded::to_string(params)?;
+        let mut uri = format!("{}{endpoint}", self.server_url);
+        if !query.is_empty() {
+            uri.push(if uri.contains('?') { '&' } else { '?' });
+            uri.push_str(&query);
+        }
+        let mut request = http::Request::builder()
+            .method(translate_method(method))
+            .uri(uri)
+            .header(http::header::ACCEPT, "application/json; version=9");
+        let content = match body {
+            Some(body) => {
+                request = request.header(http::header::CONTENT_TYPE, "application/json");
+                serde_json::to_vec(body)?
+            }
+            None => vec![],
+        };
+        for (header_name, header_value) in self
+            .auth
+            .static_headers()?
+            .iter()
+            .chain(&self.additional_headers)
+        {
+            request = request.header(header_name, header_value);
+        }
+        Ok(request.body(content.into())?)
+    }
+
+    /// Sends the request through the service, and returns the response if its
+    /// status is a success.
+    async fn send<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<(Bytes, Extra)>
+    where
+        P: Serialize,
+        B: Serialize,
+    {
+        let request = self
+            .build(method, endpoint, params, body)
+            .map_err(|source| Error::RequestBuild {
+                method,
+                endpoint: endpoint.to_string(),
+                source,
+            })?;
+        let send_error = |source: S::Error| Error::RequestSend {
+            method,
+            endpoint: endpoint.to_string(),
+            request_id: None,
+            source: anyhow::Error::from_boxed(source.into()),
+        };
+        let start = Instant::now();
+        let mut service = self.inner.clone();
+        futures_util::future::poll_fn(|cx| service.poll_ready(cx))
+            .await
+            .map_err(send_error)?;
+        let resp = service.call(request).await.map_err(send_error)?;
+        let duration = start.elapsed();
+        let (parts, content) = resp.into_parts();
+        let content_type = parts
+            .headers
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|h| h.to_str().ok())
+            .map(String::from);
+
+        if parts.status.is_client_error() || parts.status.is_server_error() {
+            let status = parts.status;
+            return Err(Error::Server {
+                method,
+                endpoint: endpoint.to_string(),
+                request_id: None,
+                status: format!("{status}"),
+                content: serde_json::from_slice(&content).unwrap_or_else(|_| {
+                    serde_json::Value::String(String::from_utf8_lossy(&content).into_owned())
+                }),
+                source: anyhow::anyhow!("HTTP status {status}"),
+            });
+        }
+
+        Ok((
+            content,
+            Extra {
+                method,
+                endpoint: endpoint.to_string(),
+                status: parts.status,
+                headers: parts.headers,
+                duration,
+                content_type,
+            },
+        ))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+#[async_trait]
+impl<S> ClientTrait for Client<S>
+where
+    S: Service<http::Request<Bytes>, Response = http::Response<Bytes>> + Clone + Send + Sync,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+{
+    type Extra = Extra;
+
+    async fn request_json<P, B, R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<R>>
+    where
+        B: Serialize + Sync,
+        P: Serialize + Sync,
+        R: for<'a> Deserialize<'a>,
+    {
+        let (content, extra) = self.send(method, endpoint, params, body).await?;
+
+        if extra.content_type != Some("application/json".to_string()) {
+            return Err(Error::ContentType {
+                method,
+                endpoint: endpoint.to_string(),
+                expected: vec!["application/json".to_string()],
+                received: extra.content_type,
+            });
+        }
+
+        Ok(Response {
+            value: json::decode(method, endpoint, content.into(), self.max_error_content)?,
+            extra,
+        })
+    }
+
+    async fn request_bytes<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<Bytes>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let (value, extra) = self.send(method, endpoint, params, body).await?;
+        Ok(Response { value, extra })
+    }
+
+    async fn request_unit<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<()>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let (_, extra) = self.send(method, endpoint, params, body).await?;
+        Ok(Response { value: (), extra })
+    }
+}
+
+#[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+impl Service<http::Request<Bytes>> for ReqwestTransport {
+    type Response = http::Response<Bytes>;
+    type Error = reqwest::Error;
+    type Future = std::pin::Pin<
+        Box<dyn Future<Output = std::result::Result<Self::Response, Self::Error>> + Send>,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::result::Result<(), Self::Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<Bytes>) -> Self::Future {
+        let inner = self.inner.clone();
+        Box::pin(async move {
+            let resp = inner.execute(reqwest::Request::try_from(request)?).await?;
+            let status = resp.status();
+            let version = resp.version();
+            let headers = resp.headers().clone();
+            let mut result = http::Response::new(resp.bytes().await?);
+            *result.status_mut() = status;
+            *result.version_mut() = version;
+            *result.headers_mut() = headers;
+            Ok(result)
+        })
+    }
+}
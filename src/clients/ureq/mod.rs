@@ -0,0 +1,321 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::marker::Sync;
+use std::time::{Duration, Instant};
+
+use crate::auth::Auth;
+use crate::clients::{Client as ClientTrait, json};
+use crate::error::{Error, Result};
+use crate::response;
+use crate::utils::Method;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Client based on `ureq`, a minimal synchronous HTTP library.
+///
+/// Since `ureq` is blocking, the futures returned by this client block the
+/// current thread until the response has been received: they are meant to be
+/// driven by a simple executor, or by [`clients::blocking::Client`], rather
+/// than by a multi-task runtime.
+///
+/// [`clients::blocking::Client`]: crate::clients::blocking::Client
+#[derive(Debug, Clone)]
+pub struct Client {
+    inner: ureq::Agent,
+    server_url: String,
+    auth: Auth,
+    additional_headers: Vec<(String, String)>,
+    max_error_content: Option<usize>,
+}
+
+pub struct Extra {
+    pub method: Method,
+    pub endpoint: String,
+    pub status: ureq::http::StatusCode,
+    pub headers: ureq::http::HeaderMap,
+    pub duration: Duration,
+    pub content_type: Option<String>,
+}
+
+pub type Response<R> = response::Response<R, Extra>;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl Client {
+    #[must_use]
+    pub fn new(server_url: String, auth: Auth) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .build()
+            .new_agent();
+        Self::with_inner(agent, server_url, auth)
+    }
+
+    /// Uses an existing agent. The agent must be configured with
+    /// `http_status_as_error(false)`, for server errors to be reported as
+    /// [`Error::Server`].
+    #[must_use]
+    pub fn with_inner(inner: ureq::Agent, server_url: String, auth: Auth) -> Self {
+        Self {
+            inner,
+            server_url,
+            auth,
+            additional_headers: vec![],
+            max_error_content: None,
+        }
+    }
+
+    #[must_use]
+    pub fn inner(&self) -> &ureq::Agent {
+        &self.inner
+    }
+
+    #[must_use]
+    pub fn auth(&self) -> &Auth {
+        &self.auth
+    }
+
+    pub fn set_auth(&mut self, auth: Auth) {
+        self.auth = auth;
+    }
+
+    #[must_use]
+    pub fn additional_headers(&self) -> &[(String, String)] {
+        &self.additional_headers
+    }
+
+    #[must_use]
+    pub fn additional_headers_mut(&mut self) -> &mut Vec<(String, String)> {
+        &mut self.additional_headers
+    }
+
+    /// Maximum number of bytes of a response body that are kept in
+    /// [`Error::Deserializing`] when decoding fails. Unbounded by default.
+    #[must_use]
+    pub fn max_error_content(&self) -> Option<usize> {
+        self.max_error_content
+    }
+
+    #[must_use]
+    pub fn max_error_content_mut(&mut self) -> &mut Option<usize> {
+        &mut self.max_error_content
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public helpers
+
+#[must_use]
+pub fn translate_method(method: Method) -> ureq::http::Method {
+    match method {
+        Method::GET => ureq::http::Method::GET,
+        Method::PUT => ureq::http::Method::PUT,
+        Method::POST => ureq::http::Method::POST,
+        Method::PATCH => ureq::http::Method::PATCH,
+        Method::DELETE => ureq::http::Method::DELETE,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl Client {
+    fn build<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> anyhow::Result<ureq::http::Request<Vec<u8>>>
+    where
+        P: Serialize,
+        B: Serialize,
+    {
+        let query = serde_urlencoded::to_string(params)?;
+        let mut uri = format!("{}{endpoint}", self.server_url);
+        if !query.is_empty() {
+            uri.push(if uri.contains('?') { '&' } else { '?' });
+            uri.push_str(&query);
+        }
+        let mut request = ureq::http::Request::builder()
+            .method(translate_method(method))
+            .uri(uri)
+            .header(ureq::http::header::ACCEPT, "application/json; version=9");
+        let content = match body {
+            Some(body) => {
+                request = request.header(ureq::http::header::CONTENT_TYPE, "application/json");
+                serde_json::to_vec(body)?
+            }
+            None => vec![],
+        };
+        for (header_name, header_value) in self
+            .auth
+            .static_headers()?
+            .iter()
+            .chain(&self.additional_headers)
+        {
+            request = request.header(header_name, header_value);
+        }
+        Ok(request.body(content)?)
+    }
+
+    /// Sends the request, and returns the response if its status is a
+    /// success.
+    fn send<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<(ureq::http::Response<ureq::Body>, Extra)>
+    where
+        P: Serialize,
+        B: Serialize,
+    {
+        let request = self
+            .build(method, endpoint, params, body)
+            .map_err(|source| Error::RequestBuild {
+                method,
+                endpoint: endpoint.to_string(),
+                source,
+            })?;
+        let start = Instant::now();
+        let mut resp = self
+            .inner
+            .run(request)
+            .map_err(|source| Error::RequestSend {
+                method,
+                endpoint: endpoint.to_string(),
+                request_id: None,
+                source: source.into(),
+            })?;
+        let duration = start.elapsed();
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let content_type = headers
+            .get(ureq::http::header::CONTENT_TYPE)
+            .and_then(|h| h.to_str().ok())
+            .map(String::from);
+
+        if status.is_client_error() || status.is_server_error() {
+            let content = match resp.body_mut().read_to_string() {
+                Err(_) => serde_json::Value::String("<failed to retrieve content>".to_string()),
+                Ok(content) => {
+                    serde_json::from_str(&content).unwrap_or(serde_json::Value::String(content))
+                }
+            };
+            return Err(Error::Server {
+                method,
+                endpoint: endpoint.to_string(),
+                request_id: None,
+                status: format!("{status}"),
+                content,
+                source: anyhow::anyhow!("HTTP status {status}"),
+            });
+        }
+
+        Ok((
+            resp,
+            Extra {
+                method,
+                endpoint: endpoint.to_string(),
+                status,
+                headers,
+                duration,
+                content_type,
+            },
+        ))
+    }
+}
+
+/// Reads the whole body of a response; unlike `ureq`'s default, the size of
+/// the body isn't limited.
+fn read_body(
+    method: Method,
+    endpoint: &str,
+    mut resp: ureq::http::Response<ureq::Body>,
+) -> Result<Vec<u8>> {
+    resp.body_mut()
+        .with_config()
+        .limit(u64::MAX)
+        .read_to_vec()
+        .map_err(|source| Error::ResponseBody {
+            method,
+            endpoint: endpoint.to_string(),
+            source: source.into(),
+        })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+#[async_trait]
+impl ClientTrait for Client {
+    type Extra = Extra;
+
+    async fn request_json<P, B, R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<R>>
+    where
+        B: Serialize + Sync,
+        P: Serialize + Sync,
+        R: for<'a> Deserialize<'a>,
+    {
+        let (resp, extra) = self.send(method, endpoint, params, body)?;
+
+        if extra.content_type != Some("application/json".to_string()) {
+            return Err(Error::ContentType {
+                method,
+                endpoint: endpoint.to_string(),
+                expected: vec!["application/json".to_string()],
+                received: extra.content_type,
+            });
+        }
+
+        let content = read_body(method, endpoint, resp)?;
+        Ok(Response {
+            value: json::decode(method, endpoint, content, self.max_error_content)?,
+            extra,
+        })
+    }
+
+    async fn request_bytes<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<Bytes>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let (resp, extra) = self.send(method, endpoint, params, body)?;
+        Ok(Response {
+            value: read_body(method, endpoint, resp)?.into(),
+            extra,
+        })
+    }
+
+    async fn request_unit<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<()>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let (_, extra) = self.send(method, endpoint, params, body)?;
+        Ok(Response { value: (), extra })
+    }
+}
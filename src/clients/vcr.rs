@@ -0,0 +1,447 @@
+use async_trait::async_trait;
+use base64::engine::{Engine, general_purpose::STANDARD};
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::marker::Sync;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+use crate::clients::mock::{self, Request};
+use crate::clients::{Client as ClientTrait, json};
+use crate::error::{Error, Result};
+use crate::multipart::Form;
+use crate::response::Response;
+use crate::schema::model::ServerVersion;
+use crate::utils::{Method, REDACTED, Redaction};
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Client that records the interactions of another client with a server to
+/// a "cassette" file, and can later replay them without any server, for
+/// reproducible tests.
+///
+/// Interactions are recorded at the level of the [`Client`](ClientTrait)
+/// trait: the method, endpoint, parameters, and body of each request, and the
+/// decoded response or server error. Credentials and other headers are
+/// therefore never recorded; the parameters and JSON fields whose names
+/// contain `password`, `token` or `secret`, or any name added with
+/// [`Client::redact`], are recorded as `REDACTED`, in requests and responses
+/// alike.
+///
+/// When replaying, each request is answered with the first interaction with
+/// the same request that hasn't been replayed yet; requests without such an
+/// interaction fail with [`Error::RequestSend`]; requests are compared once
+/// redacted, so that they match regardless of their secrets. The `Extra` of
+/// the wrapped client is only available when recording.
+///
+/// ```ignore
+/// let client: vcr::Client<reqwest::Client> = if recording {
+///     vcr::Client::record(reqwest::Client::new(url, auth), "tests/cassettes/tags.json")
+/// } else {
+///     vcr::Client::replay("tests/cassettes/tags.json")?
+/// };
+/// run_tests(&client).await;
+/// if recording {
+///     client.save()?;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Client<C> {
+    inner: Option<C>,
+    path: PathBuf,
+    interactions: Mutex<Vec<Interaction>>,
+    redacted: Redaction,
+}
+
+/// A request and the corresponding outcome.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: Request,
+    pub response: Recorded,
+    #[serde(skip)]
+    replayed: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Recorded {
+    Json(serde_json::Value),
+    /// Base64-encoded body.
+    Bytes(String),
+    Unit,
+    ServerError {
+        status: String,
+        content: serde_json::Value,
+    },
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl<C> Client<C> {
+    /// Forwards all requests to `inner`, and records them; see
+    /// [`Client::save`].
+    pub fn record(inner: C, path: impl AsRef<Path>) -> Self {
+        Self {
+            inner: Some(inner),
+            path: path.as_ref().to_path_buf(),
+            interactions: Mutex::new(vec![]),
+            redacted: Redaction::default(),
+        }
+    }
+
+    /// Loads the interactions recorded in the given file.
+    pub fn replay(path: impl AsRef<Path>) -> io::Result<Self> {
+        let content = std::fs::read(path.as_ref())?;
+        Ok(Self {
+            inner: None,
+            path: path.as_ref().to_path_buf(),
+            interactions: Mutex::new(serde_json::from_slice(&content).map_err(io::Error::other)?),
+            redacted: Redaction::default(),
+        })
+    }
+
+    /// Also redacts the parameters and JSON fields whose names contain the
+    /// given string, regardless of case.
+    #[must_use]
+    pub fn redact(mut self, name: &str) -> Self {
+        self.redacted.add(name);
+        self
+    }
+
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    #[must_use]
+    pub fn interactions(&self) -> Vec<Interaction> {
+        lock(&self.interactions).clone()
+    }
+
+    /// Writes the recorded interactions to the cassette file, replacing it.
+    pub fn save(&self) -> io::Result<()> {
+        let content =
+            serde_json::to_vec_pretty(&*lock(&self.interactions)).map_err(io::Error::other)?;
+        std::fs::write(&self.path, content)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<C> Client<C> {
+    /// The request to record or to replay, with its secrets redacted.
+    fn request<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Request>
+    where
+        P: Serialize,
+        B: Serialize,
+    {
+        let mut request =
+            mock::record(method, endpoint, params, body).map_err(|source| Error::RequestBuild {
+                method,
+                endpoint: endpoint.to_string(),
+                source,
+            })?;
+        for (name, value) in &mut request.params {
+            if self.redacted.is_secret(name) {
+                *value = REDACTED.to_string();
+            }
+        }
+        if let Some(body) = &mut request.body {
+            self.redacted.json(body);
+        }
+        Ok(request)
+    }
+
+    /// Records the outcome of a request; errors that don't come from the
+    /// server aren't recorded.
+    fn store<T>(
+        &self,
+        request: Request,
+        result: &Result<T>,
+        recorded: impl FnOnce(&T) -> Recorded,
+    ) {
+        let response = match result {
+            Ok(value) => recorded(value),
+            Err(Error::Server {
+                status, content, ..
+            }) => {
+                let mut content = content.clone();
+                self.redacted.json(&mut content);
+                Recorded::ServerError {
+                    status: status.clone(),
+                    content,
+                }
+            }
+            Err(_) => return,
+        };
+        lock(&self.interactions).push(Interaction {
+            request,
+            response,
+            replayed: false,
+        });
+    }
+
+    /// Records the JSON response of the wrapped client, or replays it if
+    /// there is none, and decodes it.
+    fn json<E, R>(
+        &self,
+        request: &Request,
+        result: Option<Result<Response<serde_json::Value, E>>>,
+    ) -> Result<Response<R, Option<E>>>
+    where
+        R: for<'a> Deserialize<'a>,
+    {
+        let (value, extra) = match result {
+            Some(result) => {
+                self.store(request.clone(), &result, |resp| {
+                    let mut value = resp.value.clone();
+                    self.redacted.json(&mut value);
+                    Recorded::Json(value)
+                });
+                let resp = result?;
+                (resp.value, Some(resp.extra))
+            }
+            None => match self.replayed(request)? {
+                Recorded::Json(value) => (value, None),
+                _ => return Err(mismatch(request)),
+            },
+        };
+        let content = serde_json::to_vec(&value).unwrap_or_default();
+        Ok(Response {
+            value: json::decode(request.method, &request.endpoint, content, None)?,
+            extra,
+        })
+    }
+
+    fn replayed(&self, request: &Request) -> Result<Recorded> {
+        let mut interactions = lock(&self.interactions);
+        let interaction = interactions
+            .iter_mut()
+            .find(|interaction| !interaction.replayed && interaction.request == *request)
+            .ok_or_else(|| Error::RequestSend {
+                method: request.method,
+                endpoint: request.endpoint.clone(),
+                request_id: None,
+                source: anyhow::anyhow!("no recorded interaction for this request"),
+            })?;
+        interaction.replayed = true;
+        match &interaction.response {
+            Recorded::ServerError { status, content } => Err(Error::Server {
+                method: request.method,
+                endpoint: request.endpoint.clone(),
+                request_id: None,
+                status: status.clone(),
+                content: content.clone(),
+                source: anyhow::anyhow!("recorded server error"),
+            }),
+            response => Ok(response.clone()),
+        }
+    }
+}
+
+fn mismatch(request: &Request) -> Error {
+    Error::RequestSend {
+        method: request.method,
+        endpoint: request.endpoint.clone(),
+        request_id: None,
+        source: anyhow::anyhow!("the recorded response doesn't match the type of the request"),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<C: ClientTrait + Send> ClientTrait for Client<C>
+where
+    C::Extra: Send,
+{
+    type Extra = Option<C::Extra>;
+
+    async fn request_json<P, B, R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<R, Self::Extra>>
+    where
+        B: Serialize + Sync,
+        P: Serialize + Sync,
+        R: for<'a> Deserialize<'a>,
+    {
+        let request = self.request(method, endpoint, params, body)?;
+        let result = match &self.inner {
+            Some(inner) => Some(
+                inner
+                    .request_json::<P, B, serde_json::Value>(method, endpoint, params, body)
+                    .await,
+            ),
+            None => None,
+        };
+        self.json(&request, result)
+    }
+
+    async fn request_multipart<P, R>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        form: Form,
+    ) -> Result<Response<R, Self::Extra>>
+    where
+        P: Serialize + Sync,
+        R: for<'a> Deserialize<'a>,
+    {
+        let request = self.request(method, endpoint, params, Some(&form.summary()))?;
+        let result = match &self.inner {
+            Some(inner) => Some(
+                inner
+                    .request_multipart::<P, serde_json::Value>(method, endpoint, params, form)
+                    .await,
+            ),
+            None => None,
+        };
+        self.json(&request, result)
+    }
+
+    async fn request_bytes<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<Bytes, Self::Extra>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let request = self.request(method, endpoint, params, body)?;
+        match &self.inner {
+            Some(inner) => {
+                let result = inner.request_bytes(method, endpoint, params, body).await;
+                self.store(request, &result, |resp| {
+                    Recorded::Bytes(STANDARD.encode(&resp.value))
+                });
+                let resp = result?;
+                Ok(Response {
+                    value: resp.value,
+                    extra: Some(resp.extra),
+                })
+            }
+            None => match self.replayed(&request)? {
+                Recorded::Bytes(value) => Ok(Response {
+                    value: STANDARD
+                        .decode(value)
+                        .map_err(|_| mismatch(&request))?
+                        .into(),
+                    extra: None,
+                }),
+                _ => Err(mismatch(&request)),
+            },
+        }
+    }
+
+    async fn request_unit<P, B>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<(), Self::Extra>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        let request = self.request(method, endpoint, params, body)?;
+        match &self.inner {
+            Some(inner) => {
+                let result = inner.request_unit(method, endpoint, params, body).await;
+                self.store(request, &result, |_| Recorded::Unit);
+                Ok(Response {
+                    value: (),
+                    extra: Some(result?.extra),
+                })
+            }
+            None => match self.replayed(&request)? {
+                Recorded::Unit => Ok(Response {
+                    value: (),
+                    extra: None,
+                }),
+                _ => Err(mismatch(&request)),
+            },
+        }
+    }
+
+    /// Version of the inner client's server; unknown when replaying.
+    fn server_version(&self) -> Option<ServerVersion> {
+        self.inner.as_ref().and_then(ClientTrait::server_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+
+    use super::Client;
+    use crate::clients::Client as _;
+    use crate::clients::mock::{self, Reply};
+    use crate::utils::{Method, params};
+
+    #[test]
+    fn cassettes_dont_contain_secrets() {
+        let path =
+            std::env::temp_dir().join(format!("paper-plane-vcr-{}.json", std::process::id()));
+        let body = json!({"username": "alice", "password": "hunter2"});
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let inner = mock::Client::new();
+            inner.enqueue(
+                Method::POST,
+                "/api/token/",
+                Reply::json(&json!({"token": "abc123", "user": {"api_secret": "xyz"}})),
+            );
+            let client = Client::record(inner, &path);
+            let resp = client
+                .request_json::<_, _, Value>(Method::POST, "/api/token/", params::NONE, Some(&body))
+                .await
+                .unwrap();
+            assert_eq!(resp.value["token"], "abc123");
+            client.save().unwrap();
+
+            let content = std::fs::read_to_string(&path).unwrap();
+            for secret in ["hunter2", "abc123", "xyz"] {
+                assert!(
+                    !content.contains(secret),
+                    "{secret} was recorded:\n{content}"
+                );
+            }
+
+            let client = Client::<mock::Client>::replay(&path).unwrap();
+            let resp = client
+                .request_json::<_, _, Value>(Method::POST, "/api/token/", params::NONE, Some(&body))
+                .await
+                .unwrap();
+            assert_eq!(resp.value["token"], "REDACTED");
+        });
+        std::fs::remove_file(&path).unwrap();
+    }
+}
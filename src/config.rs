@@ -0,0 +1,147 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::auth::Auth;
+use crate::clients::reqwest::{Client, ClientBuilder};
+use crate::clients::servers::Servers;
+use crate::error::{Error, Result};
+use crate::schema::model::Secret;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Named connection profiles, read from a TOML file, so that command-line
+/// tools can offer a `--profile` option without reimplementing it:
+///
+/// ```toml
+/// default = "home"
+///
+/// [profiles.home]
+/// url = "https://paperless.example.com"
+/// token = "0123456789abcdef"
+///
+/// [profiles.work]
+/// url = "https://paperless.internal"
+/// username = "jdoe"
+/// password = "hunter2"
+/// headers = { "CF-Access-Client-Id" = "..." }
+/// root_certificate = "/etc/ssl/internal-ca.pem"
+/// timeout = 30
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Profile used when none is specified.
+    pub default: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// Connection settings of a server; the credentials are either a `token`, or
+/// a `username` and a `password`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub url: String,
+    pub token: Option<Secret>,
+    pub username: Option<String>,
+    pub password: Option<Secret>,
+    /// Headers added to each request, see [`Client::additional_headers`].
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// Timeout of each request, in seconds.
+    pub timeout: Option<u64>,
+    /// PEM file of an additional certificate authority to trust.
+    pub root_certificate: Option<PathBuf>,
+    /// Disables the validation of the server's certificate.
+    #[serde(default)]
+    pub accept_invalid_certs: bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl Config {
+    pub fn parse(content: &str) -> Result<Self> {
+        toml::from_str(content).map_err(|source| config_error(source, "invalid configuration"))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|source| config_error(source, format!("failed to read {}", path.display())))?;
+        Self::parse(&content)
+    }
+
+    /// Returns the given profile, or the default one if `None`.
+    pub fn profile(&self, name: Option<&str>) -> Result<&Profile> {
+        let name = name
+            .or(self.default.as_deref())
+            .ok_or_else(|| Error::ClientBuild {
+                source: anyhow::anyhow!("no profile specified, and no default profile"),
+            })?;
+        self.profiles.get(name).ok_or_else(|| Error::ClientBuild {
+            source: anyhow::anyhow!("unknown profile {name}"),
+        })
+    }
+
+    /// Builds a client for the given profile, or the default one if `None`.
+    pub fn client(&self, name: Option<&str>) -> Result<Client> {
+        self.profile(name)?.builder()?.build()
+    }
+
+    /// Builds the clients of all profiles, named after them; see
+    /// [`Servers::from_config`].
+    pub fn servers(&self) -> Result<Servers<Client>> {
+        Servers::from_config(self)
+    }
+}
+
+impl Profile {
+    pub fn auth(&self) -> Result<Auth> {
+        match (&self.token, &self.username, &self.password) {
+            (Some(token), None, None) => Ok(Auth::token(token.expose())),
+            (None, Some(username), Some(password)) => {
+                Ok(Auth::basic(username.as_str(), password.expose()))
+            }
+            _ => Err(Error::ClientBuild {
+                source: anyhow::anyhow!(
+                    "a profile needs either a token, or a username and a password"
+                ),
+            }),
+        }
+    }
+
+    /// Configures a client for this profile, which can then be further
+    /// customized.
+    pub fn builder(&self) -> Result<ClientBuilder> {
+        let url = self.url.trim_end_matches('/').to_string();
+        let mut builder = ClientBuilder::new(url, self.auth()?)
+            .additional_headers(self.headers.clone().into_iter().collect())
+            .danger_accept_invalid_certs(self.accept_invalid_certs);
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(Duration::from_secs(timeout));
+        }
+        if let Some(path) = &self.root_certificate {
+            let pem = std::fs::read(path).map_err(|source| {
+                config_error(source, format!("failed to read {}", path.display()))
+            })?;
+            builder = builder.add_root_certificate_pem(&pem)?;
+        }
+        Ok(builder)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+fn config_error<E>(source: E, context: impl Into<String>) -> Error
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    Error::ClientBuild {
+        source: anyhow::Error::new(source).context(context.into()),
+    }
+}
@@ -1,10 +1,18 @@
 // use std::backtrace::Backtrace;
 use thiserror::Error;
 
+use crate::schema::model::{ServerFeature, ServerVersion};
 use crate::utils::Method;
 
 #[derive(Error, Debug)]
 pub enum Error {
+    #[error("failed to build the client")]
+    ClientBuild {
+        // #[backtrace]
+        source: anyhow::Error,
+    },
+    #[error("unknown server {}", .name)]
+    UnknownServer { name: String },
     #[error("[{:?} {}] failed to build a request", .method, .endpoint)]
     RequestBuild {
         method: Method,
@@ -16,13 +24,27 @@ pub enum Error {
     RequestSend {
         method: Method,
         endpoint: String,
+        /// Correlation id sent with the request, if any.
+        request_id: Option<String>,
         // #[backtrace]
         source: anyhow::Error,
     },
+    #[error("[{:?} {}] request cancelled", .method, .endpoint)]
+    Cancelled { method: Method, endpoint: String },
+    #[error("[{:?} {}] {} require server version {}, found {}", .method, .endpoint, .feature, .feature.since(), .version)]
+    Unsupported {
+        method: Method,
+        endpoint: String,
+        feature: ServerFeature,
+        /// Version of the server, as known by the client.
+        version: ServerVersion,
+    },
     #[error("[{:?} {}] server error\nstatus: {}\nmessage: {}", .method, .endpoint, .status, .content)]
     Server {
         method: Method,
         endpoint: String,
+        /// Correlation id sent with the request, if any.
+        request_id: Option<String>,
         status: String,
         content: serde_json::Value,
         // #[backtrace]
@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+
+use crate::error::Result;
+use crate::schema::api::{correspondents, document_types, documents, tags};
+use crate::schema::model::DocumentId;
+use crate::services::{Correspondents, DocumentTypes, Documents, Tags};
+
+/// The kind of object whose documents are counted when verifying a migration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ObjectKind {
+    Correspondent,
+    DocumentType,
+    Tag,
+}
+
+/// A difference between the source and the destination of a migration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Discrepancy {
+    /// The servers do not hold the same number of documents.
+    DocumentCount { source: i32, destination: i32 },
+    /// No object of the same kind and name exists on the destination.
+    MissingObject { kind: ObjectKind, name: String },
+    /// The object exists on both servers, but isn't assigned to the same
+    /// number of documents.
+    ObjectDocumentCount {
+        kind: ObjectKind,
+        name: String,
+        source: i32,
+        destination: i32,
+    },
+    /// No document of the destination has the original checksum of the given
+    /// source document.
+    MissingChecksum {
+        document: DocumentId,
+        checksum: String,
+    },
+}
+
+/// The result of [`verify_migration`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// Number of source documents whose checksum was looked up on the
+    /// destination.
+    pub checked_documents: usize,
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+impl MigrationReport {
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// Compares the destination of a migration with its source.
+///
+/// The total number of documents is compared, as well as the number of
+/// documents assigned to each correspondent, document type, and tag; objects
+/// are matched by name, since ids differ between servers. Objects that only
+/// exist on the destination are ignored. Then, up to `sample_size` documents,
+/// evenly spread across the source, are looked up on the destination by
+/// original checksum.
+///
+/// With the `tracing` feature, the whole verification is recorded as a
+/// `verify_migration` span, with a summary event once it is complete.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "info", skip_all, fields(sample_size = sample_size))
+)]
+pub async fn verify_migration<E1, E2, S, D>(
+    source: &S,
+    destination: &D,
+    sample_size: usize,
+) -> Result<MigrationReport>
+where
+    S: Correspondents<E1> + DocumentTypes<E1> + Documents<E1> + Tags<E1> + ?Sized,
+    D: Correspondents<E2> + DocumentTypes<E2> + Documents<E2> + Tags<E2> + ?Sized,
+{
+    let mut report = MigrationReport::default();
+
+    let params = documents::list().page_size(1);
+    let source_documents = Documents::list(source, &params).await?.value;
+    let destination_documents = Documents::list(destination, &params).await?.value;
+    if source_documents.count != destination_documents.count {
+        report.discrepancies.push(Discrepancy::DocumentCount {
+            source: source_documents.count,
+            destination: destination_documents.count,
+        });
+    }
+
+    compare_counts(
+        &mut report,
+        ObjectKind::Correspondent,
+        document_counts!(Correspondents, source, correspondents::list()),
+        &document_counts!(Correspondents, destination, correspondents::list()),
+    );
+    compare_counts(
+        &mut report,
+        ObjectKind::DocumentType,
+        document_counts!(DocumentTypes, source, document_types::list()),
+        &document_counts!(DocumentTypes, destination, document_types::list()),
+    );
+    compare_counts(
+        &mut report,
+        ObjectKind::Tag,
+        document_counts!(Tags, source, tags::list()),
+        &document_counts!(Tags, destination, tags::list()),
+    );
+
+    let ids = source_documents.document_ids();
+    let step = (ids.len() / sample_size.max(1)).max(1);
+    for &document in ids.iter().step_by(step).take(sample_size) {
+        let checksum = Documents::metadata(source, document)
+            .await?
+            .value
+            .original_checksum;
+        let params = documents::list()
+            .checksum_iexact(checksum.clone())
+            .page_size(1);
+        if Documents::list(destination, &params).await?.value.count == 0 {
+            report
+                .discrepancies
+                .push(Discrepancy::MissingChecksum { document, checksum });
+        }
+        report.checked_documents += 1;
+    }
+
+    #[cfg(feature = "tracing")]
+    tracing::info!(
+        checked_documents = report.checked_documents,
+        discrepancies = report.discrepancies.len(),
+        "migration verified",
+    );
+    Ok(report)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+/// Walks all pages of the given service, and maps each object's name to its
+/// document count.
+macro_rules! document_counts {
+    ($service:ident, $client:expr, $params:expr) => {{
+        let mut counts = BTreeMap::<String, i32>::new();
+        let mut page = $service::list($client, &$params).await?.value;
+        loop {
+            for item in std::mem::take(&mut page.results) {
+                let count = item.document_count;
+                counts.insert(item.name, count);
+            }
+            match $service::next_page($client, &page).await? {
+                Some(next) => page = next.value,
+                None => break,
+            }
+        }
+        counts
+    }};
+}
+use document_counts;
+
+fn compare_counts(
+    report: &mut MigrationReport,
+    kind: ObjectKind,
+    source: BTreeMap<String, i32>,
+    destination: &BTreeMap<String, i32>,
+) {
+    for (name, source) in source {
+        match destination.get(&name) {
+            None => report
+                .discrepancies
+                .push(Discrepancy::MissingObject { kind, name }),
+            Some(&destination) if destination != source => {
+                report.discrepancies.push(Discrepancy::ObjectDocumentCount {
+                    kind,
+                    name,
+                    source,
+                    destination,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+}
@@ -0,0 +1,4 @@
+mod migration;
+pub use migration::*;
+mod sharing;
+pub use sharing::*;
@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+
+use crate::error::Result;
+use crate::pagination::Snapshot;
+use crate::schema::api::share_links;
+use crate::schema::model::{Document, DocumentId, ShareLink};
+use crate::services::{Documents, ShareLinks};
+
+/// A document exposed through at least one share link.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SharedDocument {
+    pub document: Document,
+    pub share_links: Vec<ShareLink>,
+}
+
+/// Lists all documents that are exposed through a share link, alongside the
+/// corresponding links.
+///
+/// All share links matching the given parameters are fetched, and the
+/// corresponding documents are then retrieved by chunks. Links are returned
+/// regardless of their expiration date: to only consider active links, filter
+/// them using the `expiration__*` parameters, or the `expiration` field of each
+/// link. Documents are sorted by id.
+///
+/// With the `tracing` feature, the whole operation is recorded as a
+/// `shared_documents` span, with a summary event once it is complete.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "info", skip_all))]
+pub async fn shared_documents<E, S>(
+    service: &S,
+    params: &share_links::List,
+) -> Result<Vec<SharedDocument>>
+where
+    S: ShareLinks<E> + Documents<E> + ?Sized,
+{
+    let mut links_by_document = BTreeMap::<DocumentId, Vec<ShareLink>>::new();
+    let mut page = ShareLinks::list(service, params).await?.value;
+    loop {
+        for link in std::mem::take(&mut page.results) {
+            if let Some(document) = link.document {
+                links_by_document.entry(document).or_default().push(link);
+            }
+        }
+        match ShareLinks::next_page(service, &page).await? {
+            Some(next) => page = next.value,
+            None => break,
+        }
+    }
+
+    let mut result = Vec::with_capacity(links_by_document.len());
+    let mut snapshot = Snapshot::new(links_by_document.keys().copied().collect());
+    while let Some(chunk) = snapshot.next_chunk(service).await? {
+        for document in chunk.value {
+            if let Some(share_links) = links_by_document.remove(&document.id) {
+                result.push(SharedDocument {
+                    document,
+                    share_links,
+                });
+            }
+        }
+    }
+    #[cfg(feature = "tracing")]
+    tracing::info!(
+        documents = result.len(),
+        missing = snapshot.missing(),
+        "shared documents listed",
+    );
+    Ok(result)
+}
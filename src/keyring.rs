@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use keyring::Entry;
+use std::sync::{Mutex, PoisonError};
+
+use crate::auth::CredentialProvider;
+
+/// Service under which the tokens are stored in the keychain; the server URL
+/// is used as the user name.
+const SERVICE: &str = "paper-plane";
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Credentials provider reading the API token of a server from the
+/// platform's keychain, the first time it is needed; see [`Auth::keyring`].
+///
+/// The token is read again if the server rejects it, so that a token rotated
+/// with [`store_token`] is picked up without restarting the application.
+///
+/// [`Auth::keyring`]: crate::auth::Auth::keyring
+#[derive(Debug)]
+pub struct KeyringToken {
+    server_url: String,
+    token: Mutex<Option<String>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl KeyringToken {
+    #[must_use]
+    pub fn new(server_url: String) -> Self {
+        Self {
+            server_url,
+            token: Mutex::new(None),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public helpers
+
+/// Stores the API token of the given server in the platform's keychain,
+/// replacing the previous one, if any.
+pub fn store_token(server_url: &str, token: &str) -> keyring::Result<()> {
+    Entry::new(SERVICE, server_url)?.set_password(token)
+}
+
+/// Reads the API token of the given server from the platform's keychain.
+pub fn load_token(server_url: &str) -> keyring::Result<Option<String>> {
+    match Entry::new(SERVICE, server_url)?.get_password() {
+        Ok(token) => Ok(Some(token)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Removes the API token of the given server from the platform's keychain;
+/// does nothing if there is none.
+pub fn delete_token(server_url: &str) -> keyring::Result<()> {
+    match Entry::new(SERVICE, server_url)?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+#[async_trait]
+impl CredentialProvider for KeyringToken {
+    async fn authorization(&self, refresh: bool) -> anyhow::Result<String> {
+        let mut token = self.token.lock().unwrap_or_else(PoisonError::into_inner);
+        let token = match token.as_mut() {
+            Some(token) if !refresh => token,
+            _ => token.insert(
+                load_token(&self.server_url)?
+                    .ok_or_else(|| anyhow::anyhow!("no token stored for {}", self.server_url))?,
+            ),
+        };
+        Ok(format!("Token {token}"))
+    }
+}
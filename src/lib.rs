@@ -2,8 +2,20 @@ pub mod re;
 
 pub mod auth;
 pub mod clients;
+#[cfg(all(feature = "config", not(target_arch = "wasm32")))]
+pub mod config;
 pub mod error;
+pub mod helpers;
+#[cfg(all(feature = "keyring", not(target_arch = "wasm32")))]
+pub mod keyring;
+pub mod metrics;
+pub mod multipart;
+#[cfg(feature = "oauth2")]
+pub mod oauth2;
+pub mod pagination;
+pub mod progress;
 pub mod response;
 pub mod schema;
 pub mod services;
+pub mod signing;
 pub mod utils;
@@ -0,0 +1,42 @@
+use std::fmt;
+use std::time::Duration;
+
+use crate::utils::Method;
+
+/// Sent when a request is about to be sent; see [`Metrics`].
+#[derive(Clone, Copy, Debug)]
+pub struct RequestStarted<'a> {
+    pub method: Method,
+    pub endpoint: &'a str,
+    /// Size of the request body, if it isn't streamed.
+    pub request_size: Option<usize>,
+}
+
+/// Sent when the response to a request has been received, or when sending
+/// it failed; see [`Metrics`].
+#[derive(Clone, Copy, Debug)]
+pub struct RequestFinished<'a> {
+    pub method: Method,
+    pub endpoint: &'a str,
+    /// Status of the response; `None` if no response was received.
+    pub status: Option<u16>,
+    /// Time between sending the request and receiving the response headers.
+    pub duration: Duration,
+    /// Size of the request body, if it isn't streamed.
+    pub request_size: Option<usize>,
+    /// Size of the response body, as advertised by the server.
+    pub response_size: Option<u64>,
+}
+
+/// Receives events about each request sent by a client, to export metrics.
+/// Retried requests are reported once per attempt.
+pub trait Metrics: Send + Sync {
+    fn request_started(&self, _event: &RequestStarted<'_>) {}
+    fn request_finished(&self, _event: &RequestFinished<'_>) {}
+}
+
+impl fmt::Debug for dyn Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Metrics")
+    }
+}
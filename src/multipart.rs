@@ -0,0 +1,235 @@
+use bytes::Bytes;
+use futures_util::io::{AsyncRead, AsyncReadExt};
+use futures_util::{Stream, stream};
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+
+use crate::progress::{Progress, track};
+
+/// Size of the chunks read from the readers given to [`Part::reader`].
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// A `multipart/form-data` body, as sent by
+/// [`Client::request_multipart`](crate::clients::Client::request_multipart).
+///
+/// Files can be given as streams or readers, in which case they are sent as
+/// they are read, without ever being held in memory as a whole. The fields of
+/// the form are public, so that other implementations of the client can send
+/// it.
+#[derive(Debug, Default)]
+pub struct Form {
+    pub parts: Vec<(String, Part)>,
+}
+
+/// A single field of a [`Form`].
+#[derive(Debug)]
+pub struct Part {
+    pub content: Content,
+    pub file_name: Option<String>,
+    pub mime_type: Option<String>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub type PartStream = Pin<Box<dyn Stream<Item = io::Result<Bytes>> + Send>>;
+
+#[cfg(target_arch = "wasm32")]
+pub type PartStream = Pin<Box<dyn Stream<Item = io::Result<Bytes>>>>;
+
+pub enum Content {
+    Text(String),
+    Bytes(Bytes),
+    Stream {
+        stream: PartStream,
+        length: Option<u64>,
+    },
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl Form {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn text(self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.part(name, Part::text(value))
+    }
+
+    #[must_use]
+    pub fn part(mut self, name: impl Into<String>, part: Part) -> Self {
+        self.parts.push((name.into(), part));
+        self
+    }
+
+    /// Describes the content of the form, without its files, for clients
+    /// that record requests.
+    #[cfg(feature = "mock")]
+    pub(crate) fn summary(&self) -> serde_json::Value {
+        let parts = self
+            .parts
+            .iter()
+            .map(|(name, part)| {
+                let value = match &part.content {
+                    Content::Text(text) => serde_json::Value::String(text.clone()),
+                    _ => serde_json::json!({
+                        "file_name": part.file_name,
+                        "mime_type": part.mime_type,
+                    }),
+                };
+                serde_json::json!([name, value])
+            })
+            .collect();
+        serde_json::Value::Array(parts)
+    }
+}
+
+impl Part {
+    #[must_use]
+    pub fn text(value: impl Into<String>) -> Self {
+        Self::new(Content::Text(value.into()))
+    }
+
+    #[must_use]
+    pub fn bytes(value: impl Into<Bytes>) -> Self {
+        Self::new(Content::Bytes(value.into()))
+    }
+
+    /// Sends the chunks of the given stream as they are produced; `length`,
+    /// if known, is advertised to the server.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn stream<S>(stream: S, length: Option<u64>) -> Self
+    where
+        S: Stream<Item = io::Result<Bytes>> + Send + 'static,
+    {
+        Self::new(Content::Stream {
+            stream: Box::pin(stream),
+            length,
+        })
+    }
+
+    /// Sends the chunks of the given stream as they are produced; `length`,
+    /// if known, is advertised to the server.
+    #[cfg(target_arch = "wasm32")]
+    #[must_use]
+    pub fn stream<S>(stream: S, length: Option<u64>) -> Self
+    where
+        S: Stream<Item = io::Result<Bytes>> + 'static,
+    {
+        Self::new(Content::Stream {
+            stream: Box::pin(stream),
+            length,
+        })
+    }
+
+    /// Sends the content of the given reader as it is read, for instance a
+    /// file; `tokio` files can be adapted with `tokio_util::compat`.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn reader<R>(reader: R, length: Option<u64>) -> Self
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        Self::stream(read_chunks(reader), length)
+    }
+
+    /// Sends the content of the given reader as it is read.
+    #[cfg(target_arch = "wasm32")]
+    #[must_use]
+    pub fn reader<R>(reader: R, length: Option<u64>) -> Self
+    where
+        R: AsyncRead + Unpin + 'static,
+    {
+        Self::stream(read_chunks(reader), length)
+    }
+
+    /// Reports the upload of this part to `progress`; the total is the
+    /// length of the part, if known. Text parts aren't reported.
+    #[must_use]
+    pub fn progress(self, progress: impl Progress + 'static) -> Self {
+        let content = match self.content {
+            Content::Text(_) => return self,
+            Content::Bytes(bytes) => {
+                let length = bytes.len() as u64;
+                Content::Stream {
+                    stream: Box::pin(track(
+                        stream::once(async { Ok(bytes) }),
+                        Some(length),
+                        progress,
+                    )),
+                    length: Some(length),
+                }
+            }
+            Content::Stream { stream, length } => Content::Stream {
+                stream: Box::pin(track(stream, length, progress)),
+                length,
+            },
+        };
+        Self { content, ..self }
+    }
+
+    #[must_use]
+    pub fn file_name(mut self, value: impl Into<String>) -> Self {
+        self.file_name = Some(value.into());
+        self
+    }
+
+    #[must_use]
+    pub fn mime_type(mut self, value: impl Into<String>) -> Self {
+        self.mime_type = Some(value.into());
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+impl Part {
+    fn new(content: Content) -> Self {
+        Self {
+            content,
+            file_name: None,
+            mime_type: None,
+        }
+    }
+}
+
+fn read_chunks<R>(reader: R) -> impl Stream<Item = io::Result<Bytes>>
+where
+    R: AsyncRead + Unpin,
+{
+    stream::unfold(Some(reader), |reader| async move {
+        let mut reader = reader?;
+        let mut buffer = vec![0; READ_CHUNK_SIZE];
+        match reader.read(&mut buffer).await {
+            Ok(0) => None,
+            Ok(n) => {
+                buffer.truncate(n);
+                Some((Ok(Bytes::from(buffer)), Some(reader)))
+            }
+            Err(e) => Some((Err(e), None)),
+        }
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+impl fmt::Debug for Content {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => f.debug_tuple("Text").field(text).finish(),
+            Self::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            Self::Stream { length, .. } => {
+                f.debug_struct("Stream").field("length", length).finish()
+            }
+        }
+    }
+}
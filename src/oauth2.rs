@@ -0,0 +1,486 @@
+use async_trait::async_trait;
+use secure_string::SecureString;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::time::Duration;
+use web_time::Instant;
+
+use crate::auth::CredentialProvider;
+use crate::error::{Error, Result};
+use crate::utils::{Method, sleep};
+
+/// Grant type of the device authorization flow, RFC 8628.
+const DEVICE_CODE_GRANT: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+////////////////////////////////////////////////////////////////////////////////
+// Public types
+
+/// Endpoints of an OAuth 2 authorization server, as advertised by its
+/// discovery document.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Issuer {
+    pub token_endpoint: String,
+    pub device_authorization_endpoint: Option<String>,
+}
+
+/// Obtains bearer tokens from an OAuth 2 / OIDC issuer, for servers behind an
+/// authenticating proxy such as `oauth2-proxy`, and feeds them to a client
+/// through [`Auth::dynamic`](crate::auth::Auth::dynamic):
+///
+/// ```ignore
+/// let issuer = Issuer::discover("https://sso.example.com/realms/home").await?;
+/// let oauth = OAuth2::client_credentials(&issuer, "paper-plane", secret).scope("openid");
+/// let client = Client::new(url, Auth::dynamic(oauth));
+/// ```
+///
+/// Two flows are supported: the client credentials flow, for automation, and
+/// the device authorization flow, for command-line tools acting on behalf of a
+/// user, see [`OAuth2::authorize_device`]. Tokens are renewed shortly before
+/// they expire, with the refresh token if the issuer provided one, and by
+/// running the client credentials flow again otherwise, or when the issuer
+/// rejects the refresh token.
+pub struct OAuth2 {
+    http: reqwest::Client,
+    issuer: Issuer,
+    client_id: String,
+    client_secret: Option<SecureString>,
+    scopes: Vec<String>,
+    refresh_margin: Duration,
+    tokens: tokio::sync::Mutex<Option<Tokens>>,
+}
+
+/// Instructions to show to the user during the device authorization flow.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct DeviceAuthorization {
+    pub user_code: String,
+    pub verification_uri: String,
+    /// Verification URI that includes the user code, if supported.
+    pub verification_uri_complete: Option<String>,
+    /// Lifetime of the codes, in seconds.
+    pub expires_in: u64,
+}
+
+struct Tokens {
+    access_token: SecureString,
+    refresh_token: Option<SecureString>,
+    expires_at: Option<Instant>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct DeviceResponse {
+    device_code: String,
+    #[serde(default = "default_interval")]
+    interval: u64,
+    #[serde(flatten)]
+    authorization: DeviceAuthorization,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Public implementation
+
+impl Issuer {
+    /// Fetches the discovery document of the issuer, at
+    /// `{issuer_url}/.well-known/openid-configuration`.
+    pub async fn discover(issuer_url: &str) -> Result<Self> {
+        let url = format!(
+            "{}/.well-known/openid-configuration",
+            issuer_url.trim_end_matches('/')
+        );
+        let resp = reqwest::Client::new()
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| send_error(Method::GET, &url, e))?;
+        decode(Method::GET, &url, resp).await
+    }
+}
+
+impl OAuth2 {
+    /// Client credentials flow: the client authenticates as itself.
+    #[must_use]
+    pub fn client_credentials(
+        issuer: &Issuer,
+        client_id: impl Into<String>,
+        client_secret: impl Into<SecureString>,
+    ) -> Self {
+        Self::new(issuer, client_id.into(), Some(client_secret.into()))
+    }
+
+    /// Device authorization flow: the client acts on behalf of a user, who
+    /// must first approve it, see [`OAuth2::authorize_device`]. Public clients
+    /// have no secret.
+    #[must_use]
+    pub fn device(issuer: &Issuer, client_id: impl Into<String>) -> Self {
+        Self::new(issuer, client_id.into(), None)
+    }
+
+    #[must_use]
+    pub fn client_secret(mut self, value: impl Into<SecureString>) -> Self {
+        self.client_secret = Some(value.into());
+        self
+    }
+
+    /// Adds a scope to request.
+    #[must_use]
+    pub fn scope(mut self, value: impl Into<String>) -> Self {
+        self.scopes.push(value.into());
+        self
+    }
+
+    /// Client used to reach the issuer.
+    #[must_use]
+    pub fn http_client(mut self, value: reqwest::Client) -> Self {
+        self.http = value;
+        self
+    }
+
+    /// How long before their expiry tokens are renewed; one minute by default.
+    #[must_use]
+    pub fn refresh_margin(mut self, value: Duration) -> Self {
+        self.refresh_margin = value;
+        self
+    }
+
+    /// Runs the device authorization flow: `prompt` is called with the code
+    /// the user must enter, and the issuer is then polled until the user
+    /// approves or denies the request, or the code expires.
+    pub async fn authorize_device(&self, prompt: impl FnOnce(&DeviceAuthorization)) -> Result<()> {
+        let endpoint = self
+            .issuer
+            .device_authorization_endpoint
+            .as_deref()
+            .ok_or_else(|| Error::RequestBuild {
+                method: Method::POST,
+                endpoint: self.issuer.token_endpoint.clone(),
+                source: anyhow::anyhow!("the issuer doesn't support the device flow"),
+            })?;
+        let scope = self.scopes.join(" ");
+        let mut params = vec![("client_id", self.client_id.as_str())];
+        if !scope.is_empty() {
+            params.push(("scope", &scope));
+        }
+        let device: DeviceResponse = self.post(endpoint, &params).await?;
+        prompt(&device.authorization);
+
+        let deadline = Instant::now() + Duration::from_secs(device.authorization.expires_in);
+        let mut interval = Duration::from_secs(device.interval);
+        let params = [
+            ("grant_type", DEVICE_CODE_GRANT),
+            ("device_code", &device.device_code),
+            ("client_id", &self.client_id),
+        ];
+        loop {
+            sleep(interval).await;
+            match self
+                .post::<TokenResponse>(&self.issuer.token_endpoint, &params)
+                .await
+            {
+                Ok(tokens) => {
+                    *self.tokens.lock().await = Some(Tokens::from(tokens));
+                    return Ok(());
+                }
+                Err(error) => match oauth_error(&error) {
+                    Some("authorization_pending") if Instant::now() < deadline => {}
+                    Some("slow_down") if Instant::now() < deadline => {
+                        interval += Duration::from_secs(5);
+                    }
+                    _ => return Err(error),
+                },
+            }
+        }
+    }
+
+    /// Returns a valid access token, renewing it if needed.
+    pub async fn access_token(&self) -> Result<SecureString> {
+        self.token(false).await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Internal helpers
+
+fn default_interval() -> u64 {
+    5
+}
+
+fn send_error(method: Method, endpoint: &str, source: reqwest::Error) -> Error {
+    Error::RequestSend {
+        method,
+        endpoint: endpoint.to_string(),
+        request_id: None,
+        source: source.into(),
+    }
+}
+
+/// Reads and decodes the JSON body of a response from the issuer.
+async fn decode<R>(method: Method, endpoint: &str, resp: reqwest::Response) -> Result<R>
+where
+    R: DeserializeOwned,
+{
+    let status = resp.status();
+    let content = resp.bytes().await.map_err(|source| Error::ResponseBody {
+        method,
+        endpoint: endpoint.to_string(),
+        source: source.into(),
+    })?;
+    if !status.is_success() {
+        return Err(Error::Server {
+            method,
+            endpoint: endpoint.to_string(),
+            request_id: None,
+            status: format!("{status}"),
+            content: serde_json::from_slice(&content).unwrap_or_else(|_| {
+                serde_json::Value::String(String::from_utf8_lossy(&content).into_owned())
+            }),
+            source: anyhow::anyhow!("HTTP status {status}"),
+        });
+    }
+    serde_json::from_slice(&content).map_err(|source| Error::Deserializing {
+        method,
+        endpoint: endpoint.to_string(),
+        typename: std::any::type_name::<R>(),
+        content: String::from_utf8_lossy(&content).into_owned(),
+        source,
+    })
+}
+
+/// The OAuth error code of a failed token request, such as
+/// `authorization_pending`.
+fn oauth_error(error: &Error) -> Option<&str> {
+    match error {
+        Error::Server { content, .. } => content.get("error")?.as_str(),
+        _ => None,
+    }
+}
+
+impl OAuth2 {
+    fn new(issuer: &Issuer, client_id: String, client_secret: Option<SecureString>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            issuer: issuer.clone(),
+            client_id,
+            client_secret,
+            scopes: vec![],
+            refresh_margin: Duration::from_mins(1),
+            tokens: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Sends a form to the issuer, authenticating the client with its secret,
+    /// if any.
+    async fn post<R>(&self, endpoint: &str, params: &[(&str, &str)]) -> Result<R>
+    where
+        R: DeserializeOwned,
+    {
+        let mut request = self.http.post(endpoint).form(params);
+        if let Some(secret) = &self.client_secret {
+            request = request.basic_auth(&self.client_id, Some(secret.unsecure()));
+        }
+        let resp = request
+            .send()
+            .await
+            .map_err(|e| send_error(Method::POST, endpoint, e))?;
+        decode(Method::POST, endpoint, resp).await
+    }
+
+    /// Requests new tokens, with the given refresh token, or with the client
+    /// credentials.
+    async fn grant(&self, refresh_token: Option<&SecureString>) -> Result<TokenResponse> {
+        let endpoint = self.issuer.token_endpoint.as_str();
+        let scope = self.scopes.join(" ");
+        let mut params = match refresh_token {
+            Some(refresh_token) => vec![
+                ("grant_type", "refresh_token"),
+                ("refresh_token", refresh_token.unsecure()),
+            ],
+            None if self.client_secret.is_some() => vec![("grant_type", "client_credentials")],
+            None => {
+                return Err(Error::RequestBuild {
+                    method: Method::POST,
+                    endpoint: endpoint.to_string(),
+                    source: anyhow::anyhow!("the device hasn't been authorized"),
+                });
+            }
+        };
+        if self.client_secret.is_none() {
+            params.push(("client_id", &self.client_id));
+        }
+        if !scope.is_empty() {
+            params.push(("scope", &scope));
+        }
+        self.post(endpoint, &params).await
+    }
+
+    /// Returns the current access token, unless it is about to expire or
+    /// `renew` is set, in which case a new one is obtained first.
+    async fn token(&self, renew: bool) -> Result<SecureString> {
+        let mut tokens = self.tokens.lock().await;
+        if let Some(current) = tokens.as_ref()
+            && !renew
+            && current
+                .expires_at
+                .is_none_or(|at| Instant::now() + self.refresh_margin < at)
+        {
+            return Ok(current.access_token.clone());
+        }
+        let refresh_token = tokens
+            .as_ref()
+            .and_then(|current| current.refresh_token.clone());
+        let resp = match self.grant(refresh_token.as_ref()).await {
+            // The refresh token expired or was revoked: start over.
+            Err(error)
+                if refresh_token.is_some()
+                    && self.client_secret.is_some()
+                    && oauth_error(&error) == Some("invalid_grant") =>
+            {
+                *tokens = None;
+                self.grant(None).await?
+            }
+            result => result?,
+        };
+        let mut renewed = Tokens::from(resp);
+        // Issuers may keep the same refresh token without sending it again.
+        if renewed.refresh_token.is_none() {
+            renewed.refresh_token = tokens.take().and_then(|current| current.refresh_token);
+        }
+        let access_token = renewed.access_token.clone();
+        *tokens = Some(renewed);
+        Ok(access_token)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Traits
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl CredentialProvider for OAuth2 {
+    async fn authorization(&self, refresh: bool) -> anyhow::Result<String> {
+        let token = self.token(refresh).await?;
+        Ok(format!("Bearer {}", token.unsecure()))
+    }
+}
+
+impl From<TokenResponse> for Tokens {
+    fn from(resp: TokenResponse) -> Self {
+        Self {
+            access_token: resp.access_token.into(),
+            refresh_token: resp.refresh_token.map(SecureString::from),
+            expires_at: resp
+                .expires_in
+                .map(|seconds| Instant::now() + Duration::from_secs(seconds)),
+        }
+    }
+}
+
+impl fmt::Debug for OAuth2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuth2")
+            .field("issuer", &self.issuer)
+            .field("client_id", &self.client_id)
+            .field("scopes", &self.scopes)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::{Issuer, OAuth2};
+
+    /// Serves the given responses in order, one per connection, and returns
+    /// the URL of the server and the bodies of the requests it received.
+    async fn serve(responses: Vec<String>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let received = Arc::new(Mutex::new(vec![]));
+        let bodies = received.clone();
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = vec![];
+                let mut buffer = [0; 4096];
+                let body = loop {
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request).to_lowercase();
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length = head
+                            .lines()
+                            .find_map(|line| line.strip_prefix("content-length:"))
+                            .map_or(0, |value| value.trim().parse().unwrap());
+                        if body.len() >= length {
+                            break body.to_string();
+                        }
+                    }
+                };
+                bodies.lock().unwrap().push(body);
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        (format!("http://127.0.0.1:{port}"), received)
+    }
+
+    fn json_response(status: &str, body: &str) -> String {
+        format!(
+            "HTTP/1.1 {status}\r\ncontent-type: application/json\r\nconnection: close\r\ncontent-length: {}\r\n\r\n{body}",
+            body.len()
+        )
+    }
+
+    #[test]
+    fn rejected_refresh_tokens_fall_back_to_client_credentials() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let (url, received) = serve(vec![
+                json_response(
+                    "200 OK",
+                    r#"{"access_token":"first","refresh_token":"stale","expires_in":0}"#,
+                ),
+                json_response("400 Bad Request", r#"{"error":"invalid_grant"}"#),
+                json_response("200 OK", r#"{"access_token":"second"}"#),
+            ])
+            .await;
+            let issuer = Issuer {
+                token_endpoint: format!("{url}/token"),
+                device_authorization_endpoint: None,
+            };
+            let oauth = OAuth2::client_credentials(&issuer, "paper-plane", "secret");
+            assert_eq!(oauth.access_token().await.unwrap().unsecure(), "first");
+            assert_eq!(oauth.access_token().await.unwrap().unsecure(), "second");
+            let bodies = received.lock().unwrap().clone();
+            assert_eq!(
+                bodies,
+                [
+                    "grant_type=client_credentials",
+                    "grant_type=refresh_token&refresh_token=stale",
+                    "grant_type=client_credentials",
+                ]
+            );
+            assert!(
+                oauth
+                    .tokens
+                    .lock()
+                    .await
+                    .as_ref()
+                    .unwrap()
+                    .refresh_token
+                    .is_none()
+            );
+        });
+    }
+}
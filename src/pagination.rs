@@ -0,0 +1,579 @@
+use futures_util::{Stream, StreamExt, future, stream};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::time::Duration;
+use url::Url;
+use web_time::Instant;
+
+use crate::clients::Client;
+use crate::error::{Error, Result};
+use crate::response::Response;
+use crate::schema::api::Ordering;
+use crate::schema::api::documents::{List, OrderBy};
+use crate::schema::model::{Document, DocumentId, Paginated};
+use crate::services::Documents;
+
+////////////////////////////////////////////////////////////////////////////////
+// Pages
+
+/// Returns the pages of a listing, starting with the given call, and then
+/// following the `next` links; works with the `list` function of any service:
+///
+/// ```ignore
+/// let mut pages = pin!(pagination::pages(&client, client.tags().list(&params)));
+/// while let Some(page) = pages.next().await {
+///     for tag in page?.value.results {
+///         println!("{tag:?}");
+///     }
+/// }
+/// ```
+///
+/// Pages are only fetched when polled. The stream ends after the last page,
+/// or after the first error.
+pub fn pages<'a, C, T, F>(
+    client: &'a C,
+    first: F,
+) -> impl Stream<Item = Result<Response<Paginated<T>, C::Extra>>> + 'a
+where
+    C: Client,
+    T: for<'de> Deserialize<'de> + 'a,
+    F: Future<Output = Result<Response<Paginated<T>, C::Extra>>> + 'a,
+{
+    stream::unfold(PageState::First(first), move |state| async move {
+        let result = match state {
+            PageState::First(first) => first.await,
+            PageState::Next(url) => client.follow_page_url(&url).await,
+            PageState::Done => return None,
+        };
+        let next = match &result {
+            Ok(page) => page
+                .value
+                .raw_next_url()
+                .cloned()
+                .map_or(PageState::Done, PageState::Next),
+            Err(_) => PageState::Done,
+        };
+        Some((result, next))
+    })
+}
+
+/// Returns the pages of a listing, like [`pages`], but fetches up to
+/// `concurrency` pages at once, for large listings: once the first page
+/// reveals the number of items, the URLs of all the other pages are derived
+/// from its `next` link, by changing the `page` parameter.
+///
+/// Pages are still returned in order. Items added or deleted while the pages
+/// are fetched can shift the pages, and be skipped or returned twice; a page
+/// that doesn't exist anymore results in an error.
+pub fn pages_concurrent<'a, C, T, F>(
+    client: &'a C,
+    first: F,
+    concurrency: usize,
+) -> impl Stream<Item = Result<Response<Paginated<T>, C::Extra>>> + 'a
+where
+    C: Client,
+    T: for<'de> Deserialize<'de> + 'a,
+    F: Future<Output = Result<Response<Paginated<T>, C::Extra>>> + 'a,
+{
+    stream::once(first).flat_map(move |result| {
+        let urls = match &result {
+            Ok(page) => page_urls(&page.value),
+            Err(_) => vec![],
+        };
+        let rest = stream::iter(urls)
+            .map(move |url| async move { client.follow_page_url(&url).await })
+            .buffered(concurrency.max(1));
+        stream::once(future::ready(result)).chain(rest)
+    })
+}
+
+/// Returns the items of a listing, starting with those of the given page, and
+/// then following the `next` links, hiding page boundaries entirely:
+///
+/// ```ignore
+/// let page = client.documents().list(&params).await?.value;
+/// let mut documents = pin!(pagination::items(&client, page));
+/// while let Some(document) = documents.try_next().await? {
+///     println!("{}", document.title);
+/// }
+/// ```
+///
+/// Pages are only fetched once all the items of the previous one have been
+/// consumed. The stream ends after the last item, or after the first error.
+pub fn items<'a, C, T>(client: &'a C, first: Paginated<T>) -> impl Stream<Item = Result<T>> + 'a
+where
+    C: Client,
+    T: for<'de> Deserialize<'de> + 'a,
+{
+    let state = Some((first.results.into_iter(), first.next));
+    stream::unfold(state, move |state| async move {
+        let (mut items, mut next) = state?;
+        loop {
+            if let Some(item) = items.next() {
+                return Some((Ok(item), Some((items, next))));
+            }
+            match client.follow_page_url::<T>(&next?).await {
+                Ok(page) => {
+                    items = page.value.results.into_iter();
+                    next = page.value.next;
+                }
+                Err(error) => return Some((Err(error), None)),
+            }
+        }
+    })
+}
+
+/// Fetches the pages of a listing, starting with the given call, and returns
+/// all their items, for scripts that don't care about pages:
+///
+/// ```ignore
+/// let tags = pagination::list_all(&client, client.tags().list(&params), 10_000).await?;
+/// ```
+///
+/// As a safety net against unexpectedly large listings, at most `max_items`
+/// items are returned: no more pages are fetched once they are reached.
+///
+/// With the `tracing` feature, the whole listing is recorded as a `list_all`
+/// span, with a summary event once it is complete.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "info", skip_all, fields(max_items = max_items))
+)]
+pub async fn list_all<C, T, F>(client: &C, first: F, max_items: usize) -> Result<Vec<T>>
+where
+    C: Client,
+    T: for<'de> Deserialize<'de>,
+    F: Future<Output = Result<Response<Paginated<T>, C::Extra>>>,
+{
+    let mut items = Vec::new();
+    let mut pages = std::pin::pin!(pages(client, first));
+    while items.len() < max_items
+        && let Some(page) = pages.next().await
+    {
+        items.extend(page?.value.results);
+    }
+    items.truncate(max_items);
+    #[cfg(feature = "tracing")]
+    tracing::info!(items = items.len(), "listing complete");
+    Ok(items)
+}
+
+/// URLs of all the pages after the given first page.
+fn page_urls<T>(first: &Paginated<T>) -> Vec<Url> {
+    (2..=first.page_count())
+        .filter_map(|page| first.page_url(page))
+        .collect()
+}
+
+enum PageState<F> {
+    First(F),
+    Next(Url),
+    Done,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Crawl
+
+/// Function waiting for the given duration, with the timer of the runtime of
+/// the application; see [`Crawl::interval_with`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type Sleep = fn(Duration) -> Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Function waiting for the given duration, with the timer of the runtime of
+/// the application; see [`Crawl::interval_with`].
+#[cfg(target_arch = "wasm32")]
+pub type Sleep = fn(Duration) -> Pin<Box<dyn Future<Output = ()>>>;
+
+/// Position of a [`Crawl`], which can be persisted to resume it later.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Cursor {
+    /// The first page hasn't been fetched yet.
+    #[default]
+    Start,
+    /// URL of the next page to fetch.
+    Next(Url),
+    /// All pages have been fetched.
+    Done,
+}
+
+/// A resumable, throttled walk through the pages of a listing, for long
+/// crawls, such as nightly full-index crawls against production instances:
+///
+/// ```ignore
+/// let cursor = load_cursor()?.unwrap_or_default();
+/// let mut crawl = Crawl::resume(cursor).interval(Duration::from_secs(1));
+/// while let Some(page) = crawl.next_page(&client, client.documents().list(&params)).await? {
+///     index(page.value.results)?;
+///     save_cursor(crawl.cursor())?;
+/// }
+/// ```
+///
+/// The cursor only moves forward once a page has been fetched successfully:
+/// after a failure, the same page is requested again by the next call. Like
+/// other page-based walks, a crawl is subject to results shifting if items are
+/// added or deleted in the meantime.
+#[derive(Clone, Debug, Default)]
+pub struct Crawl {
+    cursor: Cursor,
+    interval: Duration,
+    sleep: Option<Sleep>,
+    last_request: Option<Instant>,
+}
+
+impl Crawl {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resumes a crawl from a cursor saved by a previous one.
+    #[must_use]
+    pub fn resume(cursor: Cursor) -> Self {
+        Self {
+            cursor,
+            ..Self::default()
+        }
+    }
+
+    /// Minimum time between two requests; none by default. The wait uses the
+    /// timer of the `reqwest` client; see [`Crawl::interval_with`] for other
+    /// runtimes.
+    #[cfg(feature = "reqwest")]
+    #[must_use]
+    pub fn interval(self, value: Duration) -> Self {
+        self.interval_with(value, |duration| Box::pin(crate::utils::sleep(duration)))
+    }
+
+    /// Minimum time between two requests, waited for with the given function,
+    /// such as `|duration| Box::pin(tokio::time::sleep(duration))`.
+    #[must_use]
+    pub fn interval_with(mut self, value: Duration, sleep: Sleep) -> Self {
+        self.interval = value;
+        self.sleep = Some(sleep);
+        self
+    }
+
+    #[must_use]
+    pub fn cursor(&self) -> &Cursor {
+        &self.cursor
+    }
+
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.cursor == Cursor::Done
+    }
+
+    /// Fetches the next page, waiting for the interval since the previous
+    /// request first, or returns `None` once all pages have been fetched.
+    /// `first` is the call returning the first page: it is only awaited when
+    /// the crawl starts, and ignored otherwise.
+    pub async fn next_page<C, T, F>(
+        &mut self,
+        client: &C,
+        first: F,
+    ) -> Result<Option<Response<Paginated<T>, C::Extra>>>
+    where
+        C: Client,
+        T: for<'de> Deserialize<'de>,
+        F: Future<Output = Result<Response<Paginated<T>, C::Extra>>>,
+    {
+        if self.is_done() {
+            return Ok(None);
+        }
+        if let (Some(last), Some(sleep)) = (self.last_request, self.sleep) {
+            let delay = (last + self.interval).saturating_duration_since(Instant::now());
+            if !delay.is_zero() {
+                sleep(delay).await;
+            }
+        }
+        self.last_request = Some(Instant::now());
+        let page = match &self.cursor {
+            Cursor::Next(url) => client.follow_page_url(url).await?,
+            _ => first.await?,
+        };
+        self.cursor = page
+            .value
+            .raw_next_url()
+            .cloned()
+            .map_or(Cursor::Done, Cursor::Next);
+        Ok(Some(page))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Snapshot
+
+/// A stable iteration over the documents matching a query.
+///
+/// Page-based iteration is subject to results shifting while walking through
+/// the pages: if documents are added or deleted between two requests, items
+/// can be skipped or returned twice. A `Snapshot` instead records the full list
+/// of matching ids upfront, using the `all` field of the listing, and then
+/// fetches the documents by chunks of ids: each document of the snapshot is
+/// returned exactly once, in the order of the original query. Documents added
+/// after the snapshot was taken are not included, and documents deleted in the
+/// meantime are skipped.
+///
+/// With the `tracing` feature, the whole iteration is recorded as a single
+/// `documents_snapshot` span, with one child span per request, and a summary
+/// event once all chunks have been delivered.
+#[derive(Clone, Debug)]
+pub struct Snapshot {
+    ids: Vec<DocumentId>,
+    position: usize,
+    chunk_size: usize,
+    delivered: usize,
+    failures: usize,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+impl Snapshot {
+    pub const DEFAULT_CHUNK_SIZE: usize = 100;
+
+    #[must_use]
+    pub fn new(ids: Vec<DocumentId>) -> Self {
+        Self::with_span(
+            #[cfg(feature = "tracing")]
+            tracing::info_span!("documents_snapshot", total = ids.len()),
+            ids,
+        )
+    }
+
+    /// Records the ids of all the documents matching the given parameters.
+    /// Only the first document is fetched alongside the list of ids; the
+    /// `page` and `page_size` parameters are ignored.
+    pub async fn take<E, D>(service: &D, params: &List) -> Result<Response<Self, E>>
+    where
+        D: Documents<E> + ?Sized,
+    {
+        let params = params.clone().page(1).page_size(1);
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!("documents_snapshot", total = tracing::field::Empty);
+        let call = service.list(&params);
+        #[cfg(feature = "tracing")]
+        let call = tracing::Instrument::instrument(
+            call,
+            tracing::debug_span!(parent: &span, "snapshot_ids"),
+        );
+        let Response { value, extra } = call.await?;
+        #[cfg(feature = "tracing")]
+        span.record("total", value.all.len());
+        Ok(Response {
+            value: Self::with_span(
+                #[cfg(feature = "tracing")]
+                span,
+                value.document_ids(),
+            ),
+            extra,
+        })
+    }
+
+    #[must_use]
+    pub fn chunk_size(mut self, value: usize) -> Self {
+        self.chunk_size = value.max(1);
+        self
+    }
+
+    #[must_use]
+    pub fn ids(&self) -> &[DocumentId] {
+        &self.ids
+    }
+
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.ids.len() - self.position
+    }
+
+    /// Number of documents returned so far.
+    #[must_use]
+    pub fn delivered(&self) -> usize {
+        self.delivered
+    }
+
+    /// Number of documents of the snapshot that could not be found anymore.
+    #[must_use]
+    pub fn missing(&self) -> usize {
+        self.position - self.delivered
+    }
+
+    /// Number of failed requests so far.
+    #[must_use]
+    pub fn failures(&self) -> usize {
+        self.failures
+    }
+
+    /// Fetches the next chunk of documents, or returns `None` once all of them
+    /// have been delivered. On failure, the snapshot is left untouched, and the
+    /// same chunk can be requested again.
+    pub async fn next_chunk<E, D>(
+        &mut self,
+        service: &D,
+    ) -> Result<Option<Response<Vec<Document>, E>>>
+    where
+        D: Documents<E> + ?Sized,
+    {
+        if self.remaining() == 0 {
+            return Ok(None);
+        }
+        let end = self.ids.len().min(self.position + self.chunk_size);
+        let chunk = self.ids[self.position..end].to_vec();
+        let params = List::new()
+            .id_in(chunk.clone())
+            .page_size(i32::try_from(chunk.len()).unwrap_or(i32::MAX));
+        let call = service.list(&params);
+        #[cfg(feature = "tracing")]
+        let call = tracing::Instrument::instrument(
+            call,
+            tracing::debug_span!(parent: &self.span, "chunk", offset = self.position, size = chunk.len()),
+        );
+        let result = call.await;
+        if result.is_err() {
+            self.failures += 1;
+        }
+        #[cfg(feature = "tracing")]
+        if let Err(error) = &result {
+            tracing::warn!(parent: &self.span, %error, "failed to fetch chunk");
+        }
+        let Response { value, extra } = result?;
+        self.position = end;
+        let mut by_id = value
+            .results
+            .into_iter()
+            .map(|document| (document.id, document))
+            .collect::<HashMap<_, _>>();
+        let documents = chunk
+            .iter()
+            .filter_map(|id| by_id.remove(id))
+            .collect::<Vec<_>>();
+        self.delivered += documents.len();
+        #[cfg(feature = "tracing")]
+        if self.remaining() == 0 {
+            tracing::info!(
+                parent: &self.span,
+                delivered = self.delivered,
+                missing = self.missing(),
+                failures = self.failures,
+                "snapshot fully delivered",
+            );
+        }
+        Ok(Some(Response {
+            value: documents,
+            extra,
+        }))
+    }
+}
+
+impl Snapshot {
+    /// A snapshot of the given ids, whose iteration is recorded in the given
+    /// span.
+    fn with_span(#[cfg(feature = "tracing")] span: tracing::Span, ids: Vec<DocumentId>) -> Self {
+        Self {
+            ids,
+            position: 0,
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            delivered: 0,
+            failures: 0,
+            #[cfg(feature = "tracing")]
+            span,
+        }
+    }
+}
+
+impl<T> From<&Paginated<T>> for Snapshot {
+    fn from(page: &Paginated<T>) -> Self {
+        Self::new(page.document_ids())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Id cursor
+
+/// A deterministic walk through the documents matching a query, by increasing
+/// id, for long crawls.
+///
+/// Instead of numbered pages, each request asks for the documents whose id is
+/// greater than the last one returned, ordered by id: documents added or
+/// deleted mid-crawl can't shift the following pages, so that no document is
+/// skipped or returned twice. Unlike with a [`Snapshot`], documents added
+/// during the crawl are included if their id comes after the cursor. The last
+/// id can be persisted to resume an interrupted crawl.
+#[derive(Clone, Debug)]
+pub struct IdCursor {
+    params: List,
+    last_id: Option<DocumentId>,
+    done: bool,
+}
+
+impl IdCursor {
+    /// Starts a walk through the documents matching the given parameters; the
+    /// `page`, `ordering` and `id__gt` parameters are ignored, and
+    /// `page_size` is the size of each chunk.
+    #[must_use]
+    pub fn new(params: &List) -> Self {
+        Self {
+            params: params.clone().ordering(Ordering::Asc(OrderBy::Id)),
+            last_id: None,
+            done: false,
+        }
+    }
+
+    /// Resumes a walk after the given document id.
+    #[must_use]
+    pub fn resume(params: &List, last_id: DocumentId) -> Self {
+        Self {
+            last_id: Some(last_id),
+            ..Self::new(params)
+        }
+    }
+
+    /// Id of the last document returned so far.
+    #[must_use]
+    pub fn last_id(&self) -> Option<DocumentId> {
+        self.last_id
+    }
+
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Fetches the next chunk of documents, or returns `None` once all of them
+    /// have been delivered. On failure, the cursor is left untouched, and the
+    /// same chunk can be requested again.
+    pub async fn next_chunk<E, D>(
+        &mut self,
+        service: &D,
+    ) -> Result<Option<Response<Vec<Document>, E>>>
+    where
+        D: Documents<E> + ?Sized,
+    {
+        if self.done {
+            return Ok(None);
+        }
+        let mut params = self.params.clone();
+        params.page = None;
+        params.id_gt = self.last_id;
+        let Response { value, extra } = service.list(&params).await?;
+        if let Some(last_id) = self.last_id
+            && value.results.iter().any(|document| document.id <= last_id)
+        {
+            return Err(Error::Internal {
+                source: anyhow::anyhow!("the server ignored the id__gt filter"),
+            });
+        }
+        self.done = value.raw_next_url().is_none();
+        if let Some(document) = value.results.last() {
+            self.last_id = Some(document.id);
+        }
+        if value.results.is_empty() {
+            self.done = true;
+            return Ok(None);
+        }
+        Ok(Some(Response {
+            value: value.results,
+            extra,
+        }))
+    }
+}
@@ -0,0 +1,54 @@
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
+use std::fmt;
+
+/// Receives the progress of a transfer: the number of bytes transferred so
+/// far, and the total number of bytes, if known. It is called after each
+/// chunk of the body.
+///
+/// Any closure with the same signature as `update` implements this trait.
+pub trait Progress: Send + Sync {
+    fn update(&self, transferred: u64, total: Option<u64>);
+}
+
+impl<F> Progress for F
+where
+    F: Fn(u64, Option<u64>) + Send + Sync,
+{
+    fn update(&self, transferred: u64, total: Option<u64>) {
+        self(transferred, total);
+    }
+}
+
+impl fmt::Debug for dyn Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Progress")
+    }
+}
+
+/// Reports the chunks of the given stream to `progress` as they go through;
+/// for downloads, see for instance
+/// [`Documents::download_stream`](crate::services::Documents::download_stream):
+///
+/// ```ignore
+/// let resp = client.documents().download_stream(id, None).await?;
+/// let total = resp.extra.content_length();
+/// let body = progress::track(resp.value, total, |done, total| bar.update(done, total));
+/// ```
+pub fn track<S, E, P>(
+    stream: S,
+    total: Option<u64>,
+    progress: P,
+) -> impl Stream<Item = Result<Bytes, E>>
+where
+    S: Stream<Item = Result<Bytes, E>>,
+    P: Progress,
+{
+    let mut transferred = 0;
+    stream.inspect(move |chunk| {
+        if let Ok(chunk) = chunk {
+            transferred += chunk.len() as u64;
+            progress.update(transferred, total);
+        }
+    })
+}
@@ -1,4 +1,21 @@
 pub mod bytes;
 
+#[cfg(feature = "hyper")]
+pub mod hyper;
+
+#[cfg(feature = "isahc")]
+pub mod isahc;
+
+#[cfg(all(feature = "keyring", not(target_arch = "wasm32")))]
+pub mod keyring;
+
 #[cfg(feature = "reqwest")]
 pub mod reqwest;
+
+#[cfg(feature = "rgb")]
+pub mod rgb;
+
+pub mod rust_decimal;
+
+#[cfg(feature = "ureq")]
+pub mod ureq;
@@ -0,0 +1 @@
+pub use rust_decimal::*;
@@ -6,8 +6,8 @@ use crate::schema::model;
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
-    pub user_args: serde_json::Value,
-    pub barcode_tag_mapping: serde_json::Value,
+    pub user_args: Option<serde_json::Value>,
+    pub barcode_tag_mapping: Option<serde_json::Value>,
     pub output_type: Option<model::OutputType>,
     pub pages: Option<i64>,
     pub language: Option<String>,
@@ -42,8 +42,8 @@ pub fn create() -> Create {
 impl From<&model::ApplicationConfiguration> for Create {
     fn from(item: &model::ApplicationConfiguration) -> Self {
         Self {
-            user_args: item.user_args.clone(),
-            barcode_tag_mapping: item.barcode_tag_mapping.clone(),
+            user_args: Some(item.user_args.clone()),
+            barcode_tag_mapping: Some(item.barcode_tag_mapping.clone()),
             output_type: item.output_type,
             pages: item.pages,
             language: item.language.clone(),
@@ -80,13 +80,13 @@ impl Create {
 
     #[must_use]
     pub fn user_args(mut self, value: serde_json::Value) -> Self {
-        self.user_args = value;
+        self.user_args = Some(value);
         self
     }
 
     #[must_use]
     pub fn barcode_tag_mapping(mut self, value: serde_json::Value) -> Self {
-        self.barcode_tag_mapping = value;
+        self.barcode_tag_mapping = Some(value);
         self
     }
 
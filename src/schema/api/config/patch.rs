@@ -6,32 +6,56 @@ use crate::schema::model;
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Patch {
-    pub user_args: serde_json::Value,
-    pub barcode_tag_mapping: serde_json::Value,
-    pub output_type: Option<model::OutputType>,
-    pub pages: Option<i64>,
-    pub language: Option<String>,
-    pub mode: Option<model::OcrMode>,
-    pub skip_archive_file: Option<model::SkipArchiveFile>,
-    pub image_dpi: Option<i64>,
-    pub unpaper_clean: Option<model::UnpaperClean>,
-    pub deskew: Option<bool>,
-    pub rotate_pages: Option<bool>,
-    pub rotate_pages_threshold: Option<f64>,
-    pub max_image_pixels: Option<f64>,
-    pub color_conversion_strategy: Option<model::ColorConversionStrategy>,
-    pub app_title: Option<String>,
-    pub app_logo: Option<String>,
-    pub barcodes_enabled: Option<bool>,
-    pub barcode_enable_tiff_support: Option<bool>,
-    pub barcode_string: Option<String>,
-    pub barcode_retain_split_pages: Option<bool>,
-    pub barcode_enable_asn: Option<bool>,
-    pub barcode_asn_prefix: Option<String>,
-    pub barcode_upscale: Option<f64>,
-    pub barcode_dpi: Option<i64>,
-    pub barcode_max_pages: Option<i64>,
-    pub barcode_enable_tag: Option<bool>,
+    pub user_args: Option<serde_json::Value>,
+    pub barcode_tag_mapping: Option<serde_json::Value>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub output_type: Option<Option<model::OutputType>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub pages: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub language: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub mode: Option<Option<model::OcrMode>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub skip_archive_file: Option<Option<model::SkipArchiveFile>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub image_dpi: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub unpaper_clean: Option<Option<model::UnpaperClean>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub deskew: Option<Option<bool>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub rotate_pages: Option<Option<bool>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub rotate_pages_threshold: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub max_image_pixels: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub color_conversion_strategy: Option<Option<model::ColorConversionStrategy>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub app_title: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub app_logo: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub barcodes_enabled: Option<Option<bool>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub barcode_enable_tiff_support: Option<Option<bool>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub barcode_string: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub barcode_retain_split_pages: Option<Option<bool>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub barcode_enable_asn: Option<Option<bool>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub barcode_asn_prefix: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub barcode_upscale: Option<Option<f64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub barcode_dpi: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub barcode_max_pages: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub barcode_enable_tag: Option<Option<bool>>,
 }
 
 #[must_use]
@@ -45,159 +69,250 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(
+        original: &model::ApplicationConfiguration,
+        edited: &model::ApplicationConfiguration,
+    ) -> Self {
+        let mut patch = Self::new();
+        if original.user_args != edited.user_args {
+            patch.user_args = Some(edited.user_args.clone());
+        }
+        if original.barcode_tag_mapping != edited.barcode_tag_mapping {
+            patch.barcode_tag_mapping = Some(edited.barcode_tag_mapping.clone());
+        }
+        if original.output_type != edited.output_type {
+            patch.output_type = Some(edited.output_type);
+        }
+        if original.pages != edited.pages {
+            patch.pages = Some(edited.pages);
+        }
+        if original.language != edited.language {
+            patch.language = Some(edited.language.clone());
+        }
+        if original.mode != edited.mode {
+            patch.mode = Some(edited.mode);
+        }
+        if original.skip_archive_file != edited.skip_archive_file {
+            patch.skip_archive_file = Some(edited.skip_archive_file);
+        }
+        if original.image_dpi != edited.image_dpi {
+            patch.image_dpi = Some(edited.image_dpi);
+        }
+        if original.unpaper_clean != edited.unpaper_clean {
+            patch.unpaper_clean = Some(edited.unpaper_clean);
+        }
+        if original.deskew != edited.deskew {
+            patch.deskew = Some(edited.deskew);
+        }
+        if original.rotate_pages != edited.rotate_pages {
+            patch.rotate_pages = Some(edited.rotate_pages);
+        }
+        if original.rotate_pages_threshold != edited.rotate_pages_threshold {
+            patch.rotate_pages_threshold = Some(edited.rotate_pages_threshold);
+        }
+        if original.max_image_pixels != edited.max_image_pixels {
+            patch.max_image_pixels = Some(edited.max_image_pixels);
+        }
+        if original.color_conversion_strategy != edited.color_conversion_strategy {
+            patch.color_conversion_strategy = Some(edited.color_conversion_strategy);
+        }
+        if original.app_title != edited.app_title {
+            patch.app_title = Some(edited.app_title.clone());
+        }
+        if original.app_logo != edited.app_logo {
+            patch.app_logo = Some(edited.app_logo.clone());
+        }
+        if original.barcodes_enabled != edited.barcodes_enabled {
+            patch.barcodes_enabled = Some(edited.barcodes_enabled);
+        }
+        if original.barcode_enable_tiff_support != edited.barcode_enable_tiff_support {
+            patch.barcode_enable_tiff_support = Some(edited.barcode_enable_tiff_support);
+        }
+        if original.barcode_string != edited.barcode_string {
+            patch.barcode_string = Some(edited.barcode_string.clone());
+        }
+        if original.barcode_retain_split_pages != edited.barcode_retain_split_pages {
+            patch.barcode_retain_split_pages = Some(edited.barcode_retain_split_pages);
+        }
+        if original.barcode_enable_asn != edited.barcode_enable_asn {
+            patch.barcode_enable_asn = Some(edited.barcode_enable_asn);
+        }
+        if original.barcode_asn_prefix != edited.barcode_asn_prefix {
+            patch.barcode_asn_prefix = Some(edited.barcode_asn_prefix.clone());
+        }
+        if original.barcode_upscale != edited.barcode_upscale {
+            patch.barcode_upscale = Some(edited.barcode_upscale);
+        }
+        if original.barcode_dpi != edited.barcode_dpi {
+            patch.barcode_dpi = Some(edited.barcode_dpi);
+        }
+        if original.barcode_max_pages != edited.barcode_max_pages {
+            patch.barcode_max_pages = Some(edited.barcode_max_pages);
+        }
+        if original.barcode_enable_tag != edited.barcode_enable_tag {
+            patch.barcode_enable_tag = Some(edited.barcode_enable_tag);
+        }
+        patch
+    }
+
     #[must_use]
     pub fn user_args(mut self, value: serde_json::Value) -> Self {
-        self.user_args = value;
+        self.user_args = Some(value);
         self
     }
 
     #[must_use]
     pub fn barcode_tag_mapping(mut self, value: serde_json::Value) -> Self {
-        self.barcode_tag_mapping = value;
+        self.barcode_tag_mapping = Some(value);
         self
     }
 
     #[must_use]
-    pub fn output_type(mut self, value: model::OutputType) -> Self {
-        self.output_type = Some(value);
+    pub fn output_type(mut self, value: impl Into<Option<model::OutputType>>) -> Self {
+        self.output_type = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn pages(mut self, value: i64) -> Self {
-        self.pages = Some(value);
+    pub fn pages(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.pages = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn language(mut self, value: String) -> Self {
-        self.language = Some(value);
+    pub fn language(mut self, value: impl Into<Option<String>>) -> Self {
+        self.language = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn mode(mut self, value: model::OcrMode) -> Self {
-        self.mode = Some(value);
+    pub fn mode(mut self, value: impl Into<Option<model::OcrMode>>) -> Self {
+        self.mode = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn skip_archive_file(mut self, value: model::SkipArchiveFile) -> Self {
-        self.skip_archive_file = Some(value);
+    pub fn skip_archive_file(mut self, value: impl Into<Option<model::SkipArchiveFile>>) -> Self {
+        self.skip_archive_file = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn image_dpi(mut self, value: i64) -> Self {
-        self.image_dpi = Some(value);
+    pub fn image_dpi(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.image_dpi = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn unpaper_clean(mut self, value: model::UnpaperClean) -> Self {
-        self.unpaper_clean = Some(value);
+    pub fn unpaper_clean(mut self, value: impl Into<Option<model::UnpaperClean>>) -> Self {
+        self.unpaper_clean = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn deskew(mut self, value: bool) -> Self {
-        self.deskew = Some(value);
+    pub fn deskew(mut self, value: impl Into<Option<bool>>) -> Self {
+        self.deskew = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn rotate_pages(mut self, value: bool) -> Self {
-        self.rotate_pages = Some(value);
+    pub fn rotate_pages(mut self, value: impl Into<Option<bool>>) -> Self {
+        self.rotate_pages = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn rotate_pages_threshold(mut self, value: f64) -> Self {
-        self.rotate_pages_threshold = Some(value);
+    pub fn rotate_pages_threshold(mut self, value: impl Into<Option<f64>>) -> Self {
+        self.rotate_pages_threshold = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn max_image_pixels(mut self, value: f64) -> Self {
-        self.max_image_pixels = Some(value);
+    pub fn max_image_pixels(mut self, value: impl Into<Option<f64>>) -> Self {
+        self.max_image_pixels = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn color_conversion_strategy(mut self, value: model::ColorConversionStrategy) -> Self {
-        self.color_conversion_strategy = Some(value);
+    pub fn color_conversion_strategy(
+        mut self,
+        value: impl Into<Option<model::ColorConversionStrategy>>,
+    ) -> Self {
+        self.color_conversion_strategy = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn app_title(mut self, value: String) -> Self {
-        self.app_title = Some(value);
+    pub fn app_title(mut self, value: impl Into<Option<String>>) -> Self {
+        self.app_title = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn app_logo(mut self, value: String) -> Self {
-        self.app_logo = Some(value);
+    pub fn app_logo(mut self, value: impl Into<Option<String>>) -> Self {
+        self.app_logo = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn barcodes_enabled(mut self, value: bool) -> Self {
-        self.barcodes_enabled = Some(value);
+    pub fn barcodes_enabled(mut self, value: impl Into<Option<bool>>) -> Self {
+        self.barcodes_enabled = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn barcode_enable_tiff_support(mut self, value: bool) -> Self {
-        self.barcode_enable_tiff_support = Some(value);
+    pub fn barcode_enable_tiff_support(mut self, value: impl Into<Option<bool>>) -> Self {
+        self.barcode_enable_tiff_support = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn barcode_string(mut self, value: String) -> Self {
-        self.barcode_string = Some(value);
+    pub fn barcode_string(mut self, value: impl Into<Option<String>>) -> Self {
+        self.barcode_string = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn barcode_retain_split_pages(mut self, value: bool) -> Self {
-        self.barcode_retain_split_pages = Some(value);
+    pub fn barcode_retain_split_pages(mut self, value: impl Into<Option<bool>>) -> Self {
+        self.barcode_retain_split_pages = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn barcode_enable_asn(mut self, value: bool) -> Self {
-        self.barcode_enable_asn = Some(value);
+    pub fn barcode_enable_asn(mut self, value: impl Into<Option<bool>>) -> Self {
+        self.barcode_enable_asn = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn barcode_asn_prefix(mut self, value: String) -> Self {
-        self.barcode_asn_prefix = Some(value);
+    pub fn barcode_asn_prefix(mut self, value: impl Into<Option<String>>) -> Self {
+        self.barcode_asn_prefix = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn barcode_upscale(mut self, value: f64) -> Self {
-        self.barcode_upscale = Some(value);
+    pub fn barcode_upscale(mut self, value: impl Into<Option<f64>>) -> Self {
+        self.barcode_upscale = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn barcode_dpi(mut self, value: i64) -> Self {
-        self.barcode_dpi = Some(value);
+    pub fn barcode_dpi(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.barcode_dpi = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn barcode_max_pages(mut self, value: i64) -> Self {
-        self.barcode_max_pages = Some(value);
+    pub fn barcode_max_pages(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.barcode_max_pages = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn barcode_enable_tag(mut self, value: bool) -> Self {
-        self.barcode_enable_tag = Some(value);
+    pub fn barcode_enable_tag(mut self, value: impl Into<Option<bool>>) -> Self {
+        self.barcode_enable_tag = Some(value.into());
         self
     }
 }
@@ -12,7 +12,7 @@ pub struct Create {
 }
 
 #[must_use]
-pub fn create(name: String, data_type: model::DataType) -> Create {
+pub fn create(name: impl Into<String>, data_type: model::DataType) -> Create {
     Create::new(name, data_type)
 }
 
@@ -26,11 +26,19 @@ impl From<&model::CustomField> for Create {
     }
 }
 
+/// A text field without a name, which must be set before the field is
+/// created.
+impl Default for Create {
+    fn default() -> Self {
+        Self::new(String::new(), model::DataType::String)
+    }
+}
+
 impl Create {
     #[must_use]
-    pub fn new(name: String, data_type: model::DataType) -> Self {
+    pub fn new(name: impl Into<String>, data_type: model::DataType) -> Self {
         Self {
-            name,
+            name: name.into(),
             data_type,
             extra_data: None,
         }
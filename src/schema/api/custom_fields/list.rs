@@ -1,14 +1,25 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::schema::utils;
+use crate::schema::api::Ordering;
+use crate::schema::model;
+use crate::schema::utils::{self, order_by};
+
+order_by! {
+    /// Field by which custom fields can be ordered.
+    pub enum OrderBy {
+        Id => "id",
+        Name => "name",
+        DataType => "data_type",
+    }
+}
 
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct List {
-    pub id: Option<i32>,
+    pub id: Option<model::CustomFieldId>,
     #[serde(rename = "id__in", with = "utils::comma_list")]
-    pub id_in: Option<Vec<i32>>,
+    pub id_in: Option<Vec<model::CustomFieldId>>,
     #[serde(rename = "name__icontains")]
     pub name_icontains: Option<String>,
     #[serde(rename = "name__iendswith")]
@@ -17,7 +28,7 @@ pub struct List {
     pub name_iexact: Option<String>,
     #[serde(rename = "name__istartswith")]
     pub name_istartswith: Option<String>,
-    pub ordering: Option<String>,
+    pub ordering: Option<Ordering<OrderBy>>,
     pub page: Option<i32>,
     pub page_size: Option<i32>,
 }
@@ -34,13 +45,13 @@ impl List {
     }
 
     #[must_use]
-    pub fn id(mut self, value: i32) -> Self {
+    pub fn id(mut self, value: model::CustomFieldId) -> Self {
         self.id = Some(value);
         self
     }
 
     #[must_use]
-    pub fn id_in(mut self, value: Vec<i32>) -> Self {
+    pub fn id_in(mut self, value: Vec<model::CustomFieldId>) -> Self {
         self.id_in = Some(value);
         self
     }
@@ -70,7 +81,7 @@ impl List {
     }
 
     #[must_use]
-    pub fn ordering(mut self, value: String) -> Self {
+    pub fn ordering(mut self, value: Ordering<OrderBy>) -> Self {
         self.ordering = Some(value);
         self
     }
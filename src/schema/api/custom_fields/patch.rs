@@ -8,7 +8,8 @@ use crate::schema::model;
 pub struct Patch {
     pub name: Option<String>,
     pub data_type: Option<model::DataType>,
-    pub extra_data: Option<serde_json::Value>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub extra_data: Option<Option<serde_json::Value>>,
 }
 
 #[must_use]
@@ -22,6 +23,22 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(original: &model::CustomField, edited: &model::CustomField) -> Self {
+        let mut patch = Self::new();
+        if original.name != edited.name {
+            patch.name = Some(edited.name.clone());
+        }
+        if original.data_type != edited.data_type {
+            patch.data_type = Some(edited.data_type);
+        }
+        if original.extra_data != edited.extra_data {
+            patch.extra_data = Some(edited.extra_data.clone());
+        }
+        patch
+    }
+
     #[must_use]
     pub fn name(mut self, value: String) -> Self {
         self.name = Some(value);
@@ -35,8 +52,8 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn extra_data(mut self, value: serde_json::Value) -> Self {
-        self.extra_data = Some(value);
+    pub fn extra_data(mut self, value: impl Into<Option<serde_json::Value>>) -> Self {
+        self.extra_data = Some(value.into());
         self
     }
 }
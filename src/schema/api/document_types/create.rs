@@ -4,19 +4,19 @@ use serde_with::skip_serializing_none;
 use crate::schema::model;
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
     pub name: String,
     #[serde(rename = "match")]
     pub matches: Option<String>,
     pub matching_algorithm: Option<model::MatchingAlgorithm>,
     pub is_insensitive: Option<bool>,
-    pub owner: Option<i32>,
-    pub set_permissions: Option<model::Permissions>,
+    pub owner: Option<model::UserId>,
+    pub set_permissions: Option<model::SetPermissions>,
 }
 
 #[must_use]
-pub fn create(name: String) -> Create {
+pub fn create(name: impl Into<String>) -> Create {
     Create::new(name)
 }
 
@@ -28,16 +28,16 @@ impl From<&model::DocumentType> for Create {
             matching_algorithm: item.matching_algorithm,
             is_insensitive: item.is_insensitive,
             owner: item.owner,
-            set_permissions: Some(item.permissions.clone()),
+            set_permissions: Some((&item.permissions).into()),
         }
     }
 }
 
 impl Create {
     #[must_use]
-    pub fn new(name: String) -> Self {
+    pub fn new(name: impl Into<String>) -> Self {
         Self {
-            name,
+            name: name.into(),
             matches: None,
             matching_algorithm: None,
             is_insensitive: None,
@@ -65,14 +65,31 @@ impl Create {
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
+    pub fn owner(mut self, value: model::UserId) -> Self {
         self.owner = Some(value);
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::Permissions) -> Self {
+    pub fn set_permissions(mut self, value: model::SetPermissions) -> Self {
         self.set_permissions = Some(value);
         self
     }
+
+    /// Sets both the owner and the permissions.
+    #[must_use]
+    pub fn permissions(mut self, value: model::PermissionsForm) -> Self {
+        self.owner = value.owner;
+        self.set_permissions = Some(value.set_permissions);
+        self
+    }
+
+    /// Checks that the match is consistent with the matching algorithm, if
+    /// one is set, before sending the request.
+    pub fn validate_match(&self) -> Result<(), model::MatchError> {
+        match self.matching_algorithm {
+            Some(algorithm) => algorithm.validate(self.matches.as_deref().unwrap_or("")),
+            None => Ok(()),
+        }
+    }
 }
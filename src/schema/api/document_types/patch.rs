@@ -11,8 +11,9 @@ pub struct Patch {
     pub matches: Option<String>,
     pub matching_algorithm: Option<model::MatchingAlgorithm>,
     pub is_insensitive: Option<bool>,
-    pub owner: Option<i32>,
-    pub set_permissions: Option<model::Permissions>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub owner: Option<Option<model::UserId>>,
+    pub set_permissions: Option<model::SetPermissions>,
 }
 
 #[must_use]
@@ -26,6 +27,31 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(original: &model::DocumentType, edited: &model::DocumentType) -> Self {
+        let mut patch = Self::new();
+        if original.name != edited.name {
+            patch.name = Some(edited.name.clone());
+        }
+        if original.matches != edited.matches {
+            patch.matches = Some(edited.matches.clone().unwrap_or_default());
+        }
+        if original.matching_algorithm != edited.matching_algorithm {
+            patch.matching_algorithm = edited.matching_algorithm;
+        }
+        if original.is_insensitive != edited.is_insensitive {
+            patch.is_insensitive = edited.is_insensitive;
+        }
+        if original.owner != edited.owner {
+            patch.owner = Some(edited.owner);
+        }
+        if original.permissions != edited.permissions {
+            patch.set_permissions = Some((&edited.permissions).into());
+        }
+        patch
+    }
+
     #[must_use]
     pub fn name(mut self, value: String) -> Self {
         self.name = Some(value);
@@ -51,14 +77,22 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
-        self.owner = Some(value);
+    pub fn owner(mut self, value: impl Into<Option<model::UserId>>) -> Self {
+        self.owner = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::Permissions) -> Self {
+    pub fn set_permissions(mut self, value: model::SetPermissions) -> Self {
         self.set_permissions = Some(value);
         self
     }
+
+    /// Sets both the owner and the permissions.
+    #[must_use]
+    pub fn permissions(mut self, value: model::PermissionsForm) -> Self {
+        self.owner = Some(value.owner);
+        self.set_permissions = Some(value.set_permissions);
+        self
+    }
 }
@@ -6,29 +6,29 @@ use crate::schema::model;
 use crate::schema::utils;
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
     #[serialize_always]
-    pub correspondent: Option<i32>,
+    pub correspondent: Option<model::CorrespondentId>,
     #[serialize_always]
-    pub document_type: Option<i32>,
+    pub document_type: Option<model::DocumentTypeId>,
     #[serialize_always]
-    pub storage_path: Option<i32>,
+    pub storage_path: Option<model::StoragePathId>,
     pub title: Option<String>,
     pub content: Option<String>,
-    pub tags: Vec<i32>,
-    pub created: Option<String>,
-    pub deleted_at: Option<String>,
-    pub archive_serial_number: Option<i64>,
-    pub owner: Option<i32>,
-    pub set_permissions: Option<model::Permissions>,
+    pub tags: Vec<model::TagId>,
+    pub created: Option<model::Date>,
+    pub deleted_at: Option<model::DateTime>,
+    pub archive_serial_number: Option<model::Asn>,
+    pub owner: Option<model::UserId>,
+    pub set_permissions: Option<model::SetPermissions>,
     #[serde(with = "utils::custom_fields::map_option")]
-    pub custom_fields: Option<HashMap<i32, serde_json::Value>>,
+    pub custom_fields: Option<HashMap<model::CustomFieldId, serde_json::Value>>,
     pub remove_inbox_tags: Option<bool>,
 }
 
 #[must_use]
-pub fn create(tags: Vec<i32>) -> Create {
+pub fn create(tags: Vec<model::TagId>) -> Create {
     Create::new(tags)
 }
 
@@ -45,7 +45,7 @@ impl From<&model::Document> for Create {
             deleted_at: item.deleted_at.clone(),
             archive_serial_number: item.archive_serial_number,
             owner: item.owner,
-            set_permissions: Some(item.permissions.clone()),
+            set_permissions: Some((&item.permissions).into()),
             custom_fields: Some(item.custom_fields.clone()),
             remove_inbox_tags: None,
         }
@@ -54,7 +54,7 @@ impl From<&model::Document> for Create {
 
 impl Create {
     #[must_use]
-    pub fn new(tags: Vec<i32>) -> Self {
+    pub fn new(tags: Vec<model::TagId>) -> Self {
         Self {
             correspondent: None,
             document_type: None,
@@ -73,19 +73,19 @@ impl Create {
     }
 
     #[must_use]
-    pub fn correspondent(mut self, value: i32) -> Self {
+    pub fn correspondent(mut self, value: model::CorrespondentId) -> Self {
         self.correspondent = Some(value);
         self
     }
 
     #[must_use]
-    pub fn document_type(mut self, value: i32) -> Self {
+    pub fn document_type(mut self, value: model::DocumentTypeId) -> Self {
         self.document_type = Some(value);
         self
     }
 
     #[must_use]
-    pub fn storage_path(mut self, value: i32) -> Self {
+    pub fn storage_path(mut self, value: model::StoragePathId) -> Self {
         self.storage_path = Some(value);
         self
     }
@@ -103,43 +103,54 @@ impl Create {
     }
 
     #[must_use]
-    pub fn tags(mut self, value: Vec<i32>) -> Self {
+    pub fn tags(mut self, value: Vec<model::TagId>) -> Self {
         self.tags = value;
         self
     }
 
     #[must_use]
-    pub fn created(mut self, value: String) -> Self {
+    pub fn created(mut self, value: model::Date) -> Self {
         self.created = Some(value);
         self
     }
 
     #[must_use]
-    pub fn deleted_at(mut self, value: String) -> Self {
+    pub fn deleted_at(mut self, value: model::DateTime) -> Self {
         self.deleted_at = Some(value);
         self
     }
 
     #[must_use]
-    pub fn archive_serial_number(mut self, value: i64) -> Self {
+    pub fn archive_serial_number(mut self, value: model::Asn) -> Self {
         self.archive_serial_number = Some(value);
         self
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
+    pub fn owner(mut self, value: model::UserId) -> Self {
         self.owner = Some(value);
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::Permissions) -> Self {
+    pub fn set_permissions(mut self, value: model::SetPermissions) -> Self {
         self.set_permissions = Some(value);
         self
     }
 
+    /// Sets both the owner and the permissions.
     #[must_use]
-    pub fn custom_fields(mut self, value: HashMap<i32, serde_json::Value>) -> Self {
+    pub fn permissions(mut self, value: model::PermissionsForm) -> Self {
+        self.owner = value.owner;
+        self.set_permissions = Some(value.set_permissions);
+        self
+    }
+
+    #[must_use]
+    pub fn custom_fields(
+        mut self,
+        value: HashMap<model::CustomFieldId, serde_json::Value>,
+    ) -> Self {
         self.custom_fields = Some(value);
         self
     }
@@ -0,0 +1,603 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::schema::model::{self, FilterRule};
+
+/// Criteria selecting documents, which can be applied to the parameters of a
+/// listing with [`List::filter`](super::List::filter). Unlike the raw filters
+/// of `List`, each criterion is named after what it selects, and dates are
+/// [`model::Date`] values; all criteria must match.
+#[skip_serializing_none]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Filter {
+    /// Text contained in the title or the content.
+    pub title_content: Option<String>,
+    /// Text contained in the title.
+    pub title: Option<String>,
+    /// Text contained in the content.
+    pub content: Option<String>,
+    /// Full-text search, with the query language of the server's index.
+    pub query: Option<String>,
+    /// Id of a document to which the results must be similar.
+    pub more_like: Option<model::DocumentId>,
+    pub asn: Option<model::Asn>,
+    pub asn_gt: Option<model::Asn>,
+    pub asn_lt: Option<model::Asn>,
+    pub has_asn: Option<bool>,
+    pub correspondent: Option<model::CorrespondentId>,
+    pub correspondent_in: Option<Vec<model::CorrespondentId>>,
+    pub correspondent_not_in: Option<Vec<model::CorrespondentId>>,
+    pub has_correspondent: Option<bool>,
+    pub document_type: Option<model::DocumentTypeId>,
+    pub document_type_in: Option<Vec<model::DocumentTypeId>>,
+    pub document_type_not_in: Option<Vec<model::DocumentTypeId>>,
+    pub has_document_type: Option<bool>,
+    pub storage_path: Option<model::StoragePathId>,
+    pub storage_path_in: Option<Vec<model::StoragePathId>>,
+    pub storage_path_not_in: Option<Vec<model::StoragePathId>>,
+    pub has_storage_path: Option<bool>,
+    /// Tags that the documents must all have.
+    pub tags_all: Option<Vec<model::TagId>>,
+    /// Tags among which the documents must have at least one.
+    pub tags_any: Option<Vec<model::TagId>>,
+    /// Tags that the documents must not have.
+    pub tags_none: Option<Vec<model::TagId>>,
+    pub is_tagged: Option<bool>,
+    pub is_in_inbox: Option<bool>,
+    pub created_before: Option<model::Date>,
+    pub created_after: Option<model::Date>,
+    pub created_from: Option<model::Date>,
+    pub created_to: Option<model::Date>,
+    pub added_before: Option<model::Date>,
+    pub added_after: Option<model::Date>,
+    pub added_from: Option<model::Date>,
+    pub added_to: Option<model::Date>,
+    pub modified_before: Option<model::Date>,
+    pub modified_after: Option<model::Date>,
+    pub owner: Option<model::UserId>,
+    pub owner_in: Option<Vec<model::UserId>>,
+    pub owner_not_in: Option<Vec<model::UserId>>,
+    pub has_owner: Option<bool>,
+    /// Id of the user who shared the documents with others.
+    pub shared_by: Option<model::UserId>,
+    pub has_custom_fields: Option<bool>,
+    /// Custom fields that the documents must all have.
+    pub custom_fields_all: Option<Vec<model::CustomFieldId>>,
+    /// Custom fields among which the documents must have at least one.
+    pub custom_fields_in: Option<Vec<model::CustomFieldId>>,
+    pub custom_fields_not_in: Option<Vec<model::CustomFieldId>>,
+    /// Text contained in the value of any custom field.
+    pub custom_fields_text: Option<String>,
+    /// Query on the values of custom fields, in the JSON syntax of the server,
+    /// such as `["AND", [[1, "exists", true]]]`.
+    pub custom_field_query: Option<String>,
+    pub mime_type: Option<String>,
+}
+
+#[must_use]
+pub fn filter() -> Filter {
+    Filter::new()
+}
+
+impl Filter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn title_content(mut self, value: String) -> Self {
+        self.title_content = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn title(mut self, value: String) -> Self {
+        self.title = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn content(mut self, value: String) -> Self {
+        self.content = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn query(mut self, value: String) -> Self {
+        self.query = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn more_like(mut self, value: model::DocumentId) -> Self {
+        self.more_like = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn asn(mut self, value: model::Asn) -> Self {
+        self.asn = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn asn_gt(mut self, value: model::Asn) -> Self {
+        self.asn_gt = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn asn_lt(mut self, value: model::Asn) -> Self {
+        self.asn_lt = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn has_asn(mut self, value: bool) -> Self {
+        self.has_asn = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn correspondent(mut self, value: model::CorrespondentId) -> Self {
+        self.correspondent = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn correspondent_in(mut self, value: Vec<model::CorrespondentId>) -> Self {
+        self.correspondent_in = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn correspondent_not_in(mut self, value: Vec<model::CorrespondentId>) -> Self {
+        self.correspondent_not_in = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn has_correspondent(mut self, value: bool) -> Self {
+        self.has_correspondent = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn document_type(mut self, value: model::DocumentTypeId) -> Self {
+        self.document_type = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn document_type_in(mut self, value: Vec<model::DocumentTypeId>) -> Self {
+        self.document_type_in = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn document_type_not_in(mut self, value: Vec<model::DocumentTypeId>) -> Self {
+        self.document_type_not_in = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn has_document_type(mut self, value: bool) -> Self {
+        self.has_document_type = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn storage_path(mut self, value: model::StoragePathId) -> Self {
+        self.storage_path = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn storage_path_in(mut self, value: Vec<model::StoragePathId>) -> Self {
+        self.storage_path_in = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn storage_path_not_in(mut self, value: Vec<model::StoragePathId>) -> Self {
+        self.storage_path_not_in = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn has_storage_path(mut self, value: bool) -> Self {
+        self.has_storage_path = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn tags_all(mut self, value: Vec<model::TagId>) -> Self {
+        self.tags_all = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn tags_any(mut self, value: Vec<model::TagId>) -> Self {
+        self.tags_any = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn tags_none(mut self, value: Vec<model::TagId>) -> Self {
+        self.tags_none = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn is_tagged(mut self, value: bool) -> Self {
+        self.is_tagged = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn is_in_inbox(mut self, value: bool) -> Self {
+        self.is_in_inbox = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn created_before(mut self, value: model::Date) -> Self {
+        self.created_before = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn created_after(mut self, value: model::Date) -> Self {
+        self.created_after = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn created_from(mut self, value: model::Date) -> Self {
+        self.created_from = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn created_to(mut self, value: model::Date) -> Self {
+        self.created_to = Some(value);
+        self
+    }
+
+    /// Documents created between the two dates, both included.
+    #[must_use]
+    pub fn created_between(self, from: model::Date, to: model::Date) -> Self {
+        self.created_from(from).created_to(to)
+    }
+
+    #[must_use]
+    pub fn added_before(mut self, value: model::Date) -> Self {
+        self.added_before = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn added_after(mut self, value: model::Date) -> Self {
+        self.added_after = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn added_from(mut self, value: model::Date) -> Self {
+        self.added_from = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn added_to(mut self, value: model::Date) -> Self {
+        self.added_to = Some(value);
+        self
+    }
+
+    /// Documents added between the two dates, both included.
+    #[must_use]
+    pub fn added_between(self, from: model::Date, to: model::Date) -> Self {
+        self.added_from(from).added_to(to)
+    }
+
+    #[must_use]
+    pub fn modified_before(mut self, value: model::Date) -> Self {
+        self.modified_before = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn modified_after(mut self, value: model::Date) -> Self {
+        self.modified_after = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn owner(mut self, value: model::UserId) -> Self {
+        self.owner = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn owner_in(mut self, value: Vec<model::UserId>) -> Self {
+        self.owner_in = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn owner_not_in(mut self, value: Vec<model::UserId>) -> Self {
+        self.owner_not_in = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn has_owner(mut self, value: bool) -> Self {
+        self.has_owner = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn shared_by(mut self, value: model::UserId) -> Self {
+        self.shared_by = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn has_custom_fields(mut self, value: bool) -> Self {
+        self.has_custom_fields = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn custom_fields_all(mut self, value: Vec<model::CustomFieldId>) -> Self {
+        self.custom_fields_all = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn custom_fields_in(mut self, value: Vec<model::CustomFieldId>) -> Self {
+        self.custom_fields_in = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn custom_fields_not_in(mut self, value: Vec<model::CustomFieldId>) -> Self {
+        self.custom_fields_not_in = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn custom_fields_text(mut self, value: String) -> Self {
+        self.custom_fields_text = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn custom_field_query(mut self, value: String) -> Self {
+        self.custom_field_query = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn mime_type(mut self, value: String) -> Self {
+        self.mime_type = Some(value);
+        self
+    }
+
+    /// Rules of a saved view selecting the same documents. Only the absence
+    /// of a correspondent, document type or storage path can be expressed
+    /// with rules: `has_correspondent(true)` and the like are left out.
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn to_rules(&self) -> Vec<FilterRule> {
+        fn ids<T: Copy>(
+            rules: &mut Vec<FilterRule>,
+            ids: Option<&Vec<T>>,
+            rule: fn(T) -> FilterRule,
+        ) {
+            rules.extend(ids.into_iter().flatten().copied().map(rule));
+        }
+        let mut rules = Vec::new();
+        let mut push = |rule: Option<FilterRule>| rules.extend(rule);
+        push(
+            self.title_content
+                .clone()
+                .map(FilterRule::TitleOrContentContains),
+        );
+        push(self.title.clone().map(FilterRule::TitleContains));
+        push(self.content.clone().map(FilterRule::ContentContains));
+        push(self.query.clone().map(FilterRule::FulltextQuery));
+        push(self.more_like.map(FilterRule::MoreLikeThis));
+        push(self.asn.map(FilterRule::AsnIs));
+        push(self.asn_gt.map(FilterRule::AsnGreaterThan));
+        push(self.asn_lt.map(FilterRule::AsnLesserThan));
+        push(self.has_asn.map(|has| FilterRule::DoesNotHaveAsn(!has)));
+        push(
+            self.correspondent
+                .map(|id| FilterRule::CorrespondentIs(Some(id))),
+        );
+        push(none(self.has_correspondent, FilterRule::CorrespondentIs));
+        push(
+            self.document_type
+                .map(|id| FilterRule::DocumentTypeIs(Some(id))),
+        );
+        push(none(self.has_document_type, FilterRule::DocumentTypeIs));
+        push(
+            self.storage_path
+                .map(|id| FilterRule::StoragePathIs(Some(id))),
+        );
+        push(none(self.has_storage_path, FilterRule::StoragePathIs));
+        push(self.is_tagged.map(FilterRule::HasAnyTag));
+        push(self.is_in_inbox.map(FilterRule::IsInInbox));
+        push(self.created_before.clone().map(FilterRule::CreatedBefore));
+        push(self.created_after.clone().map(FilterRule::CreatedAfter));
+        push(self.created_from.clone().map(FilterRule::CreatedFrom));
+        push(self.created_to.clone().map(FilterRule::CreatedTo));
+        push(self.added_before.clone().map(FilterRule::AddedBefore));
+        push(self.added_after.clone().map(FilterRule::AddedAfter));
+        push(self.added_from.clone().map(FilterRule::AddedFrom));
+        push(self.added_to.clone().map(FilterRule::AddedTo));
+        push(self.modified_before.clone().map(FilterRule::ModifiedBefore));
+        push(self.modified_after.clone().map(FilterRule::ModifiedAfter));
+        push(self.owner.map(|id| FilterRule::OwnerIs(Some(id))));
+        push(self.has_owner.map(|has| FilterRule::DoesNotHaveOwner(!has)));
+        push(self.shared_by.map(FilterRule::IsSharedByMe));
+        push(
+            self.has_custom_fields
+                .map(FilterRule::DoesNotHaveCustomFields),
+        );
+        push(
+            self.custom_fields_text
+                .clone()
+                .map(FilterRule::HasCustomFieldValue),
+        );
+        push(
+            self.custom_field_query
+                .clone()
+                .map(FilterRule::CustomFieldsQuery),
+        );
+        push(self.mime_type.clone().map(FilterRule::MimeTypeIs));
+        ids(
+            &mut rules,
+            self.correspondent_in.as_ref(),
+            FilterRule::HasCorrespondentIn,
+        );
+        ids(
+            &mut rules,
+            self.correspondent_not_in.as_ref(),
+            FilterRule::DoesNotHaveCorrespondentIn,
+        );
+        ids(
+            &mut rules,
+            self.document_type_in.as_ref(),
+            FilterRule::HasDocumentTypeIn,
+        );
+        ids(
+            &mut rules,
+            self.document_type_not_in.as_ref(),
+            FilterRule::DoesNotHaveDocumentTypeIn,
+        );
+        ids(
+            &mut rules,
+            self.storage_path_in.as_ref(),
+            FilterRule::HasStoragePathIn,
+        );
+        ids(
+            &mut rules,
+            self.storage_path_not_in.as_ref(),
+            FilterRule::DoesNotHaveStoragePathIn,
+        );
+        ids(&mut rules, self.tags_all.as_ref(), FilterRule::HasTag);
+        ids(&mut rules, self.tags_any.as_ref(), FilterRule::HasTagsIn);
+        ids(
+            &mut rules,
+            self.tags_none.as_ref(),
+            FilterRule::DoesNotHaveTag,
+        );
+        ids(&mut rules, self.owner_in.as_ref(), FilterRule::HasOwnerIn);
+        ids(
+            &mut rules,
+            self.owner_not_in.as_ref(),
+            FilterRule::DoesNotHaveOwnerIn,
+        );
+        ids(
+            &mut rules,
+            self.custom_fields_all.as_ref(),
+            FilterRule::HasCustomFields,
+        );
+        ids(
+            &mut rules,
+            self.custom_fields_in.as_ref(),
+            FilterRule::HasCustomFieldsIn,
+        );
+        ids(
+            &mut rules,
+            self.custom_fields_not_in.as_ref(),
+            FilterRule::DoesNotHaveCustomFieldsIn,
+        );
+        rules
+    }
+
+    /// Filter selecting the same documents as the rules of a saved view.
+    /// Rules without a corresponding criterion, such as `CreationYearIs`, and
+    /// rules that couldn't be decoded, are ignored.
+    #[must_use]
+    pub fn from_rules(rules: &[FilterRule]) -> Self {
+        fn push<T>(ids: &mut Option<Vec<T>>, id: T) {
+            ids.get_or_insert_with(Vec::new).push(id);
+        }
+        let mut filter = Self::new();
+        for rule in rules.iter().cloned() {
+            match rule {
+                FilterRule::TitleOrContentContains(value) => filter.title_content = Some(value),
+                FilterRule::TitleContains(value) => filter.title = Some(value),
+                FilterRule::ContentContains(value) => filter.content = Some(value),
+                FilterRule::FulltextQuery(value) => filter.query = Some(value),
+                FilterRule::MoreLikeThis(id) => filter.more_like = Some(id),
+                FilterRule::AsnIs(asn) => filter.asn = Some(asn),
+                FilterRule::AsnGreaterThan(asn) => filter.asn_gt = Some(asn),
+                FilterRule::AsnLesserThan(asn) => filter.asn_lt = Some(asn),
+                FilterRule::DoesNotHaveAsn(flag) => filter.has_asn = Some(!flag),
+                FilterRule::CorrespondentIs(Some(id)) => filter.correspondent = Some(id),
+                FilterRule::CorrespondentIs(None) => filter.has_correspondent = Some(false),
+                FilterRule::DocumentTypeIs(Some(id)) => filter.document_type = Some(id),
+                FilterRule::DocumentTypeIs(None) => filter.has_document_type = Some(false),
+                FilterRule::StoragePathIs(Some(id)) => filter.storage_path = Some(id),
+                FilterRule::StoragePathIs(None) => filter.has_storage_path = Some(false),
+                FilterRule::HasCorrespondentIn(id) => push(&mut filter.correspondent_in, id),
+                FilterRule::DoesNotHaveCorrespondentIn(id) => {
+                    push(&mut filter.correspondent_not_in, id);
+                }
+                FilterRule::HasDocumentTypeIn(id) => push(&mut filter.document_type_in, id),
+                FilterRule::DoesNotHaveDocumentTypeIn(id) => {
+                    push(&mut filter.document_type_not_in, id);
+                }
+                FilterRule::HasStoragePathIn(id) => push(&mut filter.storage_path_in, id),
+                FilterRule::DoesNotHaveStoragePathIn(id) => {
+                    push(&mut filter.storage_path_not_in, id);
+                }
+                FilterRule::HasTag(id) => push(&mut filter.tags_all, id),
+                FilterRule::HasTagsIn(id) => push(&mut filter.tags_any, id),
+                FilterRule::DoesNotHaveTag(id) => push(&mut filter.tags_none, id),
+                FilterRule::HasAnyTag(flag) => filter.is_tagged = Some(flag),
+                FilterRule::IsInInbox(flag) => filter.is_in_inbox = Some(flag),
+                FilterRule::CreatedBefore(date) => filter.created_before = Some(date),
+                FilterRule::CreatedAfter(date) => filter.created_after = Some(date),
+                FilterRule::CreatedFrom(date) => filter.created_from = Some(date),
+                FilterRule::CreatedTo(date) => filter.created_to = Some(date),
+                FilterRule::AddedBefore(date) => filter.added_before = Some(date),
+                FilterRule::AddedAfter(date) => filter.added_after = Some(date),
+                FilterRule::AddedFrom(date) => filter.added_from = Some(date),
+                FilterRule::AddedTo(date) => filter.added_to = Some(date),
+                FilterRule::ModifiedBefore(date) => filter.modified_before = Some(date),
+                FilterRule::ModifiedAfter(date) => filter.modified_after = Some(date),
+                FilterRule::OwnerIs(Some(id)) => filter.owner = Some(id),
+                FilterRule::OwnerIs(None) => filter.has_owner = Some(false),
+                FilterRule::HasOwnerIn(id) => push(&mut filter.owner_in, id),
+                FilterRule::DoesNotHaveOwnerIn(id) => push(&mut filter.owner_not_in, id),
+                FilterRule::DoesNotHaveOwner(flag) => filter.has_owner = Some(!flag),
+                FilterRule::IsSharedByMe(id) => filter.shared_by = Some(id),
+                FilterRule::DoesNotHaveCustomFields(flag) => filter.has_custom_fields = Some(flag),
+                FilterRule::HasCustomFields(id) => push(&mut filter.custom_fields_all, id),
+                FilterRule::HasCustomFieldsIn(id) => push(&mut filter.custom_fields_in, id),
+                FilterRule::DoesNotHaveCustomFieldsIn(id) => {
+                    push(&mut filter.custom_fields_not_in, id);
+                }
+                FilterRule::HasCustomFieldValue(value) => filter.custom_fields_text = Some(value),
+                FilterRule::CustomFieldsQuery(value) => filter.custom_field_query = Some(value),
+                FilterRule::MimeTypeIs(value) => filter.mime_type = Some(value),
+                FilterRule::CreationYearIs(_)
+                | FilterRule::CreationMonthIs(_)
+                | FilterRule::CreationDayIs(_)
+                | FilterRule::Other(_) => {}
+            }
+        }
+        filter
+    }
+}
+
+/// Rule selecting the documents without an object, when they must have none.
+fn none<T>(has: Option<bool>, rule: fn(Option<T>) -> FilterRule) -> Option<FilterRule> {
+    (has == Some(false)).then(|| rule(None))
+}
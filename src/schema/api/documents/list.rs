@@ -1,7 +1,74 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::fmt;
+use std::str::FromStr;
+
+use super::Filter;
+use crate::schema::api::{Ordering, OrderingParseError};
+use crate::schema::{model, utils};
+
+/// Field by which documents can be ordered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OrderBy {
+    Id,
+    Title,
+    CorrespondentName,
+    DocumentTypeName,
+    Created,
+    Modified,
+    Added,
+    ArchiveSerialNumber,
+    NumNotes,
+    Owner,
+    PageCount,
+    /// Value of the custom field with the given id.
+    CustomField(model::CustomFieldId),
+}
+
+impl fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Id => "id",
+            Self::Title => "title",
+            Self::CorrespondentName => "correspondent__name",
+            Self::DocumentTypeName => "document_type__name",
+            Self::Created => "created",
+            Self::Modified => "modified",
+            Self::Added => "added",
+            Self::ArchiveSerialNumber => "archive_serial_number",
+            Self::NumNotes => "num_notes",
+            Self::Owner => "owner",
+            Self::PageCount => "page_count",
+            Self::CustomField(id) => return write!(f, "custom_field_{id}"),
+        };
+        f.write_str(name)
+    }
+}
 
-use crate::schema::utils;
+impl FromStr for OrderBy {
+    type Err = OrderingParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "id" => Self::Id,
+            "title" => Self::Title,
+            "correspondent__name" => Self::CorrespondentName,
+            "document_type__name" => Self::DocumentTypeName,
+            "created" => Self::Created,
+            "modified" => Self::Modified,
+            "added" => Self::Added,
+            "archive_serial_number" => Self::ArchiveSerialNumber,
+            "num_notes" => Self::NumNotes,
+            "owner" => Self::Owner,
+            "page_count" => Self::PageCount,
+            _ => s
+                .strip_prefix("custom_field_")
+                .and_then(|id| id.parse().ok())
+                .map(Self::CustomField)
+                .ok_or_else(|| OrderingParseError(s.to_string()))?,
+        })
+    }
+}
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -30,17 +97,17 @@ pub struct List {
     #[serde(rename = "added__year")]
     pub added_year: Option<f64>,
     #[serde(rename = "archive_serial_number")]
-    pub archive_serial_number: Option<i32>,
+    pub archive_serial_number: Option<model::Asn>,
     #[serde(rename = "archive_serial_number__gt")]
-    pub archive_serial_number_gt: Option<i32>,
+    pub archive_serial_number_gt: Option<model::Asn>,
     #[serde(rename = "archive_serial_number__gte")]
-    pub archive_serial_number_gte: Option<i32>,
+    pub archive_serial_number_gte: Option<model::Asn>,
     #[serde(rename = "archive_serial_number__isnull")]
     pub archive_serial_number_isnull: Option<bool>,
     #[serde(rename = "archive_serial_number__lt")]
-    pub archive_serial_number_lt: Option<i32>,
+    pub archive_serial_number_lt: Option<model::Asn>,
     #[serde(rename = "archive_serial_number__lte")]
-    pub archive_serial_number_lte: Option<i32>,
+    pub archive_serial_number_lte: Option<model::Asn>,
     #[serde(rename = "checksum__icontains")]
     pub checksum_icontains: Option<String>,
     #[serde(rename = "checksum__iendswith")]
@@ -58,11 +125,11 @@ pub struct List {
     #[serde(rename = "content__istartswith")]
     pub content_istartswith: Option<String>,
     #[serde(rename = "correspondent__id")]
-    pub correspondent_id: Option<i32>,
+    pub correspondent_id: Option<model::CorrespondentId>,
     #[serde(rename = "correspondent__id__in", with = "utils::comma_list")]
-    pub correspondent_id_in: Option<Vec<i32>>,
-    #[serde(rename = "correspondent__id__none")]
-    pub correspondent_id_none: Option<i32>,
+    pub correspondent_id_in: Option<Vec<model::CorrespondentId>>,
+    #[serde(rename = "correspondent__id__none", with = "utils::comma_list")]
+    pub correspondent_id_none: Option<Vec<model::CorrespondentId>>,
     #[serde(rename = "correspondent__isnull")]
     pub correspondent_isnull: Option<bool>,
     #[serde(rename = "correspondent__name__icontains")]
@@ -99,18 +166,18 @@ pub struct List {
     pub custom_field_query: Option<String>,
     #[serde(rename = "custom_fields__icontains")]
     pub custom_fields_icontains: Option<String>,
-    #[serde(rename = "custom_fields__id__all")]
-    pub custom_fields_id_all: Option<i32>,
-    #[serde(rename = "custom_fields__id__in")]
-    pub custom_fields_id_in: Option<i32>,
-    #[serde(rename = "custom_fields__id__none")]
-    pub custom_fields_id_none: Option<i32>,
+    #[serde(rename = "custom_fields__id__all", with = "utils::comma_list")]
+    pub custom_fields_id_all: Option<Vec<model::CustomFieldId>>,
+    #[serde(rename = "custom_fields__id__in", with = "utils::comma_list")]
+    pub custom_fields_id_in: Option<Vec<model::CustomFieldId>>,
+    #[serde(rename = "custom_fields__id__none", with = "utils::comma_list")]
+    pub custom_fields_id_none: Option<Vec<model::CustomFieldId>>,
     #[serde(rename = "document_type__id")]
-    pub document_type_id: Option<i32>,
+    pub document_type_id: Option<model::DocumentTypeId>,
     #[serde(rename = "document_type__id__in", with = "utils::comma_list")]
-    pub document_type_id_in: Option<Vec<i32>>,
-    #[serde(rename = "document_type__id__none")]
-    pub document_type_id_none: Option<i32>,
+    pub document_type_id_in: Option<Vec<model::DocumentTypeId>>,
+    #[serde(rename = "document_type__id__none", with = "utils::comma_list")]
+    pub document_type_id_none: Option<Vec<model::DocumentTypeId>>,
     #[serde(rename = "document_type__isnull")]
     pub document_type_isnull: Option<bool>,
     #[serde(rename = "document_type__name__icontains")]
@@ -124,9 +191,17 @@ pub struct List {
     #[serde(with = "utils::comma_list")]
     pub fields: Option<Vec<String>>,
     pub has_custom_fields: Option<bool>,
-    pub id: Option<i32>,
+    pub id: Option<model::DocumentId>,
+    #[serde(rename = "id__gt")]
+    pub id_gt: Option<model::DocumentId>,
+    #[serde(rename = "id__gte")]
+    pub id_gte: Option<model::DocumentId>,
     #[serde(rename = "id__in", with = "utils::comma_list")]
-    pub id_in: Option<Vec<i32>>,
+    pub id_in: Option<Vec<model::DocumentId>>,
+    #[serde(rename = "id__lt")]
+    pub id_lt: Option<model::DocumentId>,
+    #[serde(rename = "id__lte")]
+    pub id_lte: Option<model::DocumentId>,
     #[serde(rename = "is_in_inbox")]
     pub is_in_inbox: Option<bool>,
     #[serde(rename = "is_tagged")]
@@ -155,8 +230,10 @@ pub struct List {
     pub modified_month: Option<f64>,
     #[serde(rename = "modified__year")]
     pub modified_year: Option<f64>,
+    #[serde(rename = "more_like_id")]
+    pub more_like_id: Option<model::DocumentId>,
     #[serde(rename = "ordering")]
-    pub ordering: Option<String>,
+    pub ordering: Option<Ordering<OrderBy>>,
     #[serde(rename = "original_filename__icontains")]
     pub original_filename_icontains: Option<String>,
     #[serde(rename = "original_filename__iendswith")]
@@ -166,27 +243,29 @@ pub struct List {
     #[serde(rename = "original_filename__istartswith")]
     pub original_filename_istartswith: Option<String>,
     #[serde(rename = "owner__id")]
-    pub owner_id: Option<i32>,
+    pub owner_id: Option<model::UserId>,
     #[serde(rename = "owner__id__in", with = "utils::comma_list")]
-    pub owner_id_in: Option<Vec<i32>>,
-    #[serde(rename = "owner__id__none")]
-    pub owner_id_none: Option<i32>,
+    pub owner_id_in: Option<Vec<model::UserId>>,
+    #[serde(rename = "owner__id__none", with = "utils::comma_list")]
+    pub owner_id_none: Option<Vec<model::UserId>>,
     #[serde(rename = "owner__isnull")]
     pub owner_isnull: Option<bool>,
     #[serde(rename = "page")]
     pub page: Option<i32>,
     #[serde(rename = "page_size")]
     pub page_size: Option<i32>,
+    #[serde(rename = "query")]
+    pub query: Option<String>,
     #[serde(rename = "search")]
     pub search: Option<String>,
     #[serde(rename = "shared_by__id")]
-    pub shared_by_id: Option<bool>,
+    pub shared_by_id: Option<model::UserId>,
     #[serde(rename = "storage_path__id")]
-    pub storage_path_id: Option<i32>,
+    pub storage_path_id: Option<model::StoragePathId>,
     #[serde(rename = "storage_path__id__in", with = "utils::comma_list")]
-    pub storage_path_id_in: Option<Vec<i32>>,
-    #[serde(rename = "storage_path__id__none")]
-    pub storage_path_id_none: Option<i32>,
+    pub storage_path_id_in: Option<Vec<model::StoragePathId>>,
+    #[serde(rename = "storage_path__id__none", with = "utils::comma_list")]
+    pub storage_path_id_none: Option<Vec<model::StoragePathId>>,
     #[serde(rename = "storage_path__isnull")]
     pub storage_path_isnull: Option<bool>,
     #[serde(rename = "storage_path__name__icontains")]
@@ -198,13 +277,13 @@ pub struct List {
     #[serde(rename = "storage_path__name__istartswith")]
     pub storage_path_name_istartswith: Option<String>,
     #[serde(rename = "tags__id")]
-    pub tags_id: Option<i32>,
-    #[serde(rename = "tags__id__all")]
-    pub tags_id_all: Option<i32>,
-    #[serde(rename = "tags__id__in")]
-    pub tags_id_in: Option<i32>,
-    #[serde(rename = "tags__id__none")]
-    pub tags_id_none: Option<i32>,
+    pub tags_id: Option<model::TagId>,
+    #[serde(rename = "tags__id__all", with = "utils::comma_list")]
+    pub tags_id_all: Option<Vec<model::TagId>>,
+    #[serde(rename = "tags__id__in", with = "utils::comma_list")]
+    pub tags_id_in: Option<Vec<model::TagId>>,
+    #[serde(rename = "tags__id__none", with = "utils::comma_list")]
+    pub tags_id_none: Option<Vec<model::TagId>>,
     #[serde(rename = "tags__name__icontains")]
     pub tags_name_icontains: Option<String>,
     #[serde(rename = "tags__name__iendswith")]
@@ -223,6 +302,8 @@ pub struct List {
     pub title_istartswith: Option<String>,
     #[serde(rename = "title_content")]
     pub title_content: Option<String>,
+    /// Truncates the content of the documents to their first 300 characters.
+    pub truncate_content: Option<bool>,
 }
 
 #[must_use]
@@ -295,7 +376,11 @@ impl Default for List {
             fields: None,
             has_custom_fields: None,
             id: None,
+            id_gt: None,
+            id_gte: None,
             id_in: None,
+            id_lt: None,
+            id_lte: None,
             is_in_inbox: None,
             is_tagged: None,
             mime_type: None,
@@ -310,6 +395,7 @@ impl Default for List {
             modified_lte: None,
             modified_month: None,
             modified_year: None,
+            more_like_id: None,
             ordering: None,
             original_filename_icontains: None,
             original_filename_iendswith: None,
@@ -321,6 +407,7 @@ impl Default for List {
             owner_isnull: None,
             page: None,
             page_size: None,
+            query: None,
             search: None,
             shared_by_id: None,
             storage_path_id: None,
@@ -344,6 +431,7 @@ impl Default for List {
             title_iexact: None,
             title_istartswith: None,
             title_content: None,
+            truncate_content: None,
         }
     }
 }
@@ -354,6 +442,105 @@ impl List {
         Self::default()
     }
 
+    /// Applies the criteria of the given filter, replacing the corresponding
+    /// parameters; the other parameters are left untouched.
+    #[must_use]
+    pub fn filter(mut self, filter: &Filter) -> Self {
+        fn set<T>(param: &mut Option<T>, value: Option<T>) {
+            if value.is_some() {
+                *param = value;
+            }
+        }
+        let date = |value: &Option<model::Date>| value.as_ref().map(ToString::to_string);
+        let not = |value: Option<bool>| value.map(|value| !value);
+
+        set(&mut self.title_content, filter.title_content.clone());
+        set(&mut self.title_icontains, filter.title.clone());
+        set(&mut self.content_icontains, filter.content.clone());
+        set(&mut self.query, filter.query.clone());
+        set(&mut self.more_like_id, filter.more_like);
+        set(&mut self.archive_serial_number, filter.asn);
+        set(&mut self.archive_serial_number_gt, filter.asn_gt);
+        set(&mut self.archive_serial_number_lt, filter.asn_lt);
+        set(&mut self.archive_serial_number_isnull, not(filter.has_asn));
+        set(&mut self.correspondent_id, filter.correspondent);
+        set(
+            &mut self.correspondent_id_in,
+            filter.correspondent_in.clone(),
+        );
+        set(
+            &mut self.correspondent_id_none,
+            filter.correspondent_not_in.clone(),
+        );
+        set(
+            &mut self.correspondent_isnull,
+            not(filter.has_correspondent),
+        );
+        set(&mut self.document_type_id, filter.document_type);
+        set(
+            &mut self.document_type_id_in,
+            filter.document_type_in.clone(),
+        );
+        set(
+            &mut self.document_type_id_none,
+            filter.document_type_not_in.clone(),
+        );
+        set(
+            &mut self.document_type_isnull,
+            not(filter.has_document_type),
+        );
+        set(&mut self.storage_path_id, filter.storage_path);
+        set(&mut self.storage_path_id_in, filter.storage_path_in.clone());
+        set(
+            &mut self.storage_path_id_none,
+            filter.storage_path_not_in.clone(),
+        );
+        set(&mut self.storage_path_isnull, not(filter.has_storage_path));
+        set(&mut self.tags_id_all, filter.tags_all.clone());
+        set(&mut self.tags_id_in, filter.tags_any.clone());
+        set(&mut self.tags_id_none, filter.tags_none.clone());
+        set(&mut self.is_tagged, filter.is_tagged);
+        set(&mut self.is_in_inbox, filter.is_in_inbox);
+        set(&mut self.created_date_lt, date(&filter.created_before));
+        set(&mut self.created_date_gt, date(&filter.created_after));
+        set(&mut self.created_date_gte, date(&filter.created_from));
+        set(&mut self.created_date_lte, date(&filter.created_to));
+        set(&mut self.added_date_lt, date(&filter.added_before));
+        set(&mut self.added_date_gt, date(&filter.added_after));
+        set(&mut self.added_date_gte, date(&filter.added_from));
+        set(&mut self.added_date_lte, date(&filter.added_to));
+        set(&mut self.modified_date_lt, date(&filter.modified_before));
+        set(&mut self.modified_date_gt, date(&filter.modified_after));
+        set(&mut self.owner_id, filter.owner);
+        set(&mut self.owner_id_in, filter.owner_in.clone());
+        set(&mut self.owner_id_none, filter.owner_not_in.clone());
+        set(&mut self.owner_isnull, not(filter.has_owner));
+        set(&mut self.shared_by_id, filter.shared_by);
+        set(&mut self.has_custom_fields, filter.has_custom_fields);
+        set(
+            &mut self.custom_fields_id_all,
+            filter.custom_fields_all.clone(),
+        );
+        set(
+            &mut self.custom_fields_id_in,
+            filter.custom_fields_in.clone(),
+        );
+        set(
+            &mut self.custom_fields_id_none,
+            filter.custom_fields_not_in.clone(),
+        );
+        set(
+            &mut self.custom_fields_icontains,
+            filter.custom_fields_text.clone(),
+        );
+        set(
+            &mut self.custom_field_query,
+            filter.custom_field_query.clone(),
+        );
+        set(&mut self.mime_type, filter.mime_type.clone());
+        self
+    }
+
     #[must_use]
     pub fn added_date_gt(mut self, value: String) -> Self {
         self.added_date_gt = Some(value);
@@ -421,19 +608,19 @@ impl List {
     }
 
     #[must_use]
-    pub fn archive_serial_number(mut self, value: i32) -> Self {
+    pub fn archive_serial_number(mut self, value: model::Asn) -> Self {
         self.archive_serial_number = Some(value);
         self
     }
 
     #[must_use]
-    pub fn archive_serial_number_gt(mut self, value: i32) -> Self {
+    pub fn archive_serial_number_gt(mut self, value: model::Asn) -> Self {
         self.archive_serial_number_gt = Some(value);
         self
     }
 
     #[must_use]
-    pub fn archive_serial_number_gte(mut self, value: i32) -> Self {
+    pub fn archive_serial_number_gte(mut self, value: model::Asn) -> Self {
         self.archive_serial_number_gte = Some(value);
         self
     }
@@ -445,13 +632,13 @@ impl List {
     }
 
     #[must_use]
-    pub fn archive_serial_number_lt(mut self, value: i32) -> Self {
+    pub fn archive_serial_number_lt(mut self, value: model::Asn) -> Self {
         self.archive_serial_number_lt = Some(value);
         self
     }
 
     #[must_use]
-    pub fn archive_serial_number_lte(mut self, value: i32) -> Self {
+    pub fn archive_serial_number_lte(mut self, value: model::Asn) -> Self {
         self.archive_serial_number_lte = Some(value);
         self
     }
@@ -505,19 +692,19 @@ impl List {
     }
 
     #[must_use]
-    pub fn correspondent_id(mut self, value: i32) -> Self {
+    pub fn correspondent_id(mut self, value: model::CorrespondentId) -> Self {
         self.correspondent_id = Some(value);
         self
     }
 
     #[must_use]
-    pub fn correspondent_id_in(mut self, value: Vec<i32>) -> Self {
+    pub fn correspondent_id_in(mut self, value: Vec<model::CorrespondentId>) -> Self {
         self.correspondent_id_in = Some(value);
         self
     }
 
     #[must_use]
-    pub fn correspondent_id_none(mut self, value: i32) -> Self {
+    pub fn correspondent_id_none(mut self, value: Vec<model::CorrespondentId>) -> Self {
         self.correspondent_id_none = Some(value);
         self
     }
@@ -631,37 +818,37 @@ impl List {
     }
 
     #[must_use]
-    pub fn custom_fields_id_all(mut self, value: i32) -> Self {
+    pub fn custom_fields_id_all(mut self, value: Vec<model::CustomFieldId>) -> Self {
         self.custom_fields_id_all = Some(value);
         self
     }
 
     #[must_use]
-    pub fn custom_fields_id_in(mut self, value: i32) -> Self {
+    pub fn custom_fields_id_in(mut self, value: Vec<model::CustomFieldId>) -> Self {
         self.custom_fields_id_in = Some(value);
         self
     }
 
     #[must_use]
-    pub fn custom_fields_id_none(mut self, value: i32) -> Self {
+    pub fn custom_fields_id_none(mut self, value: Vec<model::CustomFieldId>) -> Self {
         self.custom_fields_id_none = Some(value);
         self
     }
 
     #[must_use]
-    pub fn document_type_id(mut self, value: i32) -> Self {
+    pub fn document_type_id(mut self, value: model::DocumentTypeId) -> Self {
         self.document_type_id = Some(value);
         self
     }
 
     #[must_use]
-    pub fn document_type_id_in(mut self, value: Vec<i32>) -> Self {
+    pub fn document_type_id_in(mut self, value: Vec<model::DocumentTypeId>) -> Self {
         self.document_type_id_in = Some(value);
         self
     }
 
     #[must_use]
-    pub fn document_type_id_none(mut self, value: i32) -> Self {
+    pub fn document_type_id_none(mut self, value: Vec<model::DocumentTypeId>) -> Self {
         self.document_type_id_none = Some(value);
         self
     }
@@ -709,17 +896,41 @@ impl List {
     }
 
     #[must_use]
-    pub fn id(mut self, value: i32) -> Self {
+    pub fn id(mut self, value: model::DocumentId) -> Self {
         self.id = Some(value);
         self
     }
 
     #[must_use]
-    pub fn id_in(mut self, value: Vec<i32>) -> Self {
+    pub fn id_gt(mut self, value: model::DocumentId) -> Self {
+        self.id_gt = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn id_gte(mut self, value: model::DocumentId) -> Self {
+        self.id_gte = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn id_in(mut self, value: Vec<model::DocumentId>) -> Self {
         self.id_in = Some(value);
         self
     }
 
+    #[must_use]
+    pub fn id_lt(mut self, value: model::DocumentId) -> Self {
+        self.id_lt = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn id_lte(mut self, value: model::DocumentId) -> Self {
+        self.id_lte = Some(value);
+        self
+    }
+
     #[must_use]
     pub fn is_in_inbox(mut self, value: bool) -> Self {
         self.is_in_inbox = Some(value);
@@ -804,8 +1015,16 @@ impl List {
         self
     }
 
+    /// Only lists documents similar to the given one, ordered by similarity;
+    /// each result then carries a [`SearchHit`](crate::schema::model::SearchHit).
     #[must_use]
-    pub fn ordering(mut self, value: String) -> Self {
+    pub fn more_like_id(mut self, value: model::DocumentId) -> Self {
+        self.more_like_id = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn ordering(mut self, value: Ordering<OrderBy>) -> Self {
         self.ordering = Some(value);
         self
     }
@@ -835,19 +1054,19 @@ impl List {
     }
 
     #[must_use]
-    pub fn owner_id(mut self, value: i32) -> Self {
+    pub fn owner_id(mut self, value: model::UserId) -> Self {
         self.owner_id = Some(value);
         self
     }
 
     #[must_use]
-    pub fn owner_id_in(mut self, value: Vec<i32>) -> Self {
+    pub fn owner_id_in(mut self, value: Vec<model::UserId>) -> Self {
         self.owner_id_in = Some(value);
         self
     }
 
     #[must_use]
-    pub fn owner_id_none(mut self, value: i32) -> Self {
+    pub fn owner_id_none(mut self, value: Vec<model::UserId>) -> Self {
         self.owner_id_none = Some(value);
         self
     }
@@ -870,6 +1089,13 @@ impl List {
         self
     }
 
+    /// Full-text search, with the query language of the server's index.
+    #[must_use]
+    pub fn query(mut self, value: String) -> Self {
+        self.query = Some(value);
+        self
+    }
+
     #[must_use]
     pub fn search(mut self, value: String) -> Self {
         self.search = Some(value);
@@ -877,25 +1103,25 @@ impl List {
     }
 
     #[must_use]
-    pub fn shared_by_id(mut self, value: bool) -> Self {
+    pub fn shared_by_id(mut self, value: model::UserId) -> Self {
         self.shared_by_id = Some(value);
         self
     }
 
     #[must_use]
-    pub fn storage_path_id(mut self, value: i32) -> Self {
+    pub fn storage_path_id(mut self, value: model::StoragePathId) -> Self {
         self.storage_path_id = Some(value);
         self
     }
 
     #[must_use]
-    pub fn storage_path_id_in(mut self, value: Vec<i32>) -> Self {
+    pub fn storage_path_id_in(mut self, value: Vec<model::StoragePathId>) -> Self {
         self.storage_path_id_in = Some(value);
         self
     }
 
     #[must_use]
-    pub fn storage_path_id_none(mut self, value: i32) -> Self {
+    pub fn storage_path_id_none(mut self, value: Vec<model::StoragePathId>) -> Self {
         self.storage_path_id_none = Some(value);
         self
     }
@@ -931,25 +1157,25 @@ impl List {
     }
 
     #[must_use]
-    pub fn tags_id(mut self, value: i32) -> Self {
+    pub fn tags_id(mut self, value: model::TagId) -> Self {
         self.tags_id = Some(value);
         self
     }
 
     #[must_use]
-    pub fn tags_id_all(mut self, value: i32) -> Self {
+    pub fn tags_id_all(mut self, value: Vec<model::TagId>) -> Self {
         self.tags_id_all = Some(value);
         self
     }
 
     #[must_use]
-    pub fn tags_id_in(mut self, value: i32) -> Self {
+    pub fn tags_id_in(mut self, value: Vec<model::TagId>) -> Self {
         self.tags_id_in = Some(value);
         self
     }
 
     #[must_use]
-    pub fn tags_id_none(mut self, value: i32) -> Self {
+    pub fn tags_id_none(mut self, value: Vec<model::TagId>) -> Self {
         self.tags_id_none = Some(value);
         self
     }
@@ -1007,4 +1233,39 @@ impl List {
         self.title_content = Some(value);
         self
     }
+
+    #[must_use]
+    pub fn truncate_content(mut self, value: bool) -> Self {
+        self.truncate_content = Some(value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Filter, List, model};
+
+    #[test]
+    fn filter_parameters() {
+        let filter = Filter::new()
+            .title("invoice".to_string())
+            .has_correspondent(false)
+            .document_type(model::DocumentTypeId::new(3))
+            .tags_all(vec![model::TagId::new(4), model::TagId::new(5)])
+            .is_in_inbox(true)
+            .created_after("2024-03-05".parse().unwrap())
+            .has_owner(true);
+        let query = serde_urlencoded::to_string(List::new().filter(&filter)).unwrap();
+        assert_eq!(
+            query,
+            "full_perms=true\
+             &correspondent__isnull=true\
+             &created__date__gt=2024-03-05\
+             &document_type__id=3\
+             &is_in_inbox=true\
+             &owner__isnull=false\
+             &tags__id__all=4%2C5\
+             &title__icontains=invoice"
+        );
+    }
 }
@@ -1,8 +1,12 @@
 mod create;
 pub use create::*;
+mod filter;
+pub use filter::*;
 mod history;
 pub use history::*;
 mod list;
 pub use list::*;
 mod patch;
 pub use patch::*;
+mod upload;
+pub use upload::*;
@@ -8,19 +8,24 @@ use crate::schema::utils;
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Patch {
-    pub correspondent: Option<i32>,
-    pub document_type: Option<i32>,
-    pub storage_path: Option<i32>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub correspondent: Option<Option<model::CorrespondentId>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub document_type: Option<Option<model::DocumentTypeId>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub storage_path: Option<Option<model::StoragePathId>>,
     pub title: Option<String>,
     pub content: Option<String>,
-    pub tags: Option<Vec<i32>>,
-    pub created: Option<String>,
-    pub deleted_at: Option<String>,
-    pub archive_serial_number: Option<i64>,
-    pub owner: Option<i32>,
-    pub set_permissions: Option<model::Permissions>,
-    #[serde(with = "utils::custom_fields::map_option")]
-    pub custom_fields: Option<HashMap<i32, serde_json::Value>>,
+    pub tags: Option<Vec<model::TagId>>,
+    pub created: Option<model::Date>,
+    pub deleted_at: Option<model::DateTime>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub archive_serial_number: Option<Option<model::Asn>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub owner: Option<Option<model::UserId>>,
+    pub set_permissions: Option<model::SetPermissions>,
+    #[serde(default, with = "utils::custom_fields::map_option")]
+    pub custom_fields: Option<HashMap<model::CustomFieldId, serde_json::Value>>,
     pub remove_inbox_tags: Option<bool>,
 }
 
@@ -35,21 +40,64 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
     #[must_use]
-    pub fn correspondent(mut self, value: i32) -> Self {
-        self.correspondent = Some(value);
+    pub fn diff(original: &model::Document, edited: &model::Document) -> Self {
+        let mut patch = Self::new();
+        if original.correspondent != edited.correspondent {
+            patch.correspondent = Some(edited.correspondent);
+        }
+        if original.document_type != edited.document_type {
+            patch.document_type = Some(edited.document_type);
+        }
+        if original.storage_path != edited.storage_path {
+            patch.storage_path = Some(edited.storage_path);
+        }
+        if original.title != edited.title {
+            patch.title.clone_from(&edited.title);
+        }
+        if original.content != edited.content {
+            patch.content.clone_from(&edited.content);
+        }
+        if original.tags != edited.tags {
+            patch.tags = Some(edited.tags.clone());
+        }
+        if original.created != edited.created {
+            patch.created.clone_from(&edited.created);
+        }
+        if original.deleted_at != edited.deleted_at {
+            patch.deleted_at.clone_from(&edited.deleted_at);
+        }
+        if original.archive_serial_number != edited.archive_serial_number {
+            patch.archive_serial_number = Some(edited.archive_serial_number);
+        }
+        if original.owner != edited.owner {
+            patch.owner = Some(edited.owner);
+        }
+        if original.permissions != edited.permissions {
+            patch.set_permissions = Some((&edited.permissions).into());
+        }
+        if original.custom_fields != edited.custom_fields {
+            patch.custom_fields = Some(edited.custom_fields.clone());
+        }
+        patch
+    }
+
+    #[must_use]
+    pub fn correspondent(mut self, value: impl Into<Option<model::CorrespondentId>>) -> Self {
+        self.correspondent = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn document_type(mut self, value: i32) -> Self {
-        self.document_type = Some(value);
+    pub fn document_type(mut self, value: impl Into<Option<model::DocumentTypeId>>) -> Self {
+        self.document_type = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn storage_path(mut self, value: i32) -> Self {
-        self.storage_path = Some(value);
+    pub fn storage_path(mut self, value: impl Into<Option<model::StoragePathId>>) -> Self {
+        self.storage_path = Some(value.into());
         self
     }
 
@@ -66,43 +114,54 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn tags(mut self, value: Vec<i32>) -> Self {
+    pub fn tags(mut self, value: Vec<model::TagId>) -> Self {
         self.tags = Some(value);
         self
     }
 
     #[must_use]
-    pub fn created(mut self, value: String) -> Self {
+    pub fn created(mut self, value: model::Date) -> Self {
         self.created = Some(value);
         self
     }
 
     #[must_use]
-    pub fn deleted_at(mut self, value: String) -> Self {
+    pub fn deleted_at(mut self, value: model::DateTime) -> Self {
         self.deleted_at = Some(value);
         self
     }
 
     #[must_use]
-    pub fn archive_serial_number(mut self, value: i64) -> Self {
-        self.archive_serial_number = Some(value);
+    pub fn archive_serial_number(mut self, value: impl Into<Option<model::Asn>>) -> Self {
+        self.archive_serial_number = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
-        self.owner = Some(value);
+    pub fn owner(mut self, value: impl Into<Option<model::UserId>>) -> Self {
+        self.owner = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::Permissions) -> Self {
+    pub fn set_permissions(mut self, value: model::SetPermissions) -> Self {
         self.set_permissions = Some(value);
         self
     }
 
+    /// Sets both the owner and the permissions.
+    #[must_use]
+    pub fn permissions(mut self, value: model::PermissionsForm) -> Self {
+        self.owner = Some(value.owner);
+        self.set_permissions = Some(value.set_permissions);
+        self
+    }
+
     #[must_use]
-    pub fn custom_fields(mut self, value: HashMap<i32, serde_json::Value>) -> Self {
+    pub fn custom_fields(
+        mut self,
+        value: HashMap<model::CustomFieldId, serde_json::Value>,
+    ) -> Self {
         self.custom_fields = Some(value);
         self
     }
@@ -113,3 +172,66 @@ impl Patch {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{Patch, model};
+
+    fn document() -> model::Document {
+        serde_json::from_value(json!({
+            "id": 1,
+            "correspondent": 2,
+            "document_type": 3,
+            "storage_path": null,
+            "title": "invoice",
+            "content": "",
+            "tags": [4],
+            "created": "2025-01-02",
+            "modified": "2025-01-02T03:04:05Z",
+            "added": "2025-01-02T03:04:05Z",
+            "deleted_at": null,
+            "archive_serial_number": 5,
+            "original_file_name": "invoice.pdf",
+            "archived_file_name": null,
+            "owner": 6,
+            "permissions": {},
+            "page_count": 1,
+            "mime_type": "application/pdf",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn diff_unchanged() {
+        let original = document();
+        let patch = Patch::diff(&original, &original.clone());
+        assert_eq!(patch, Patch::new());
+        assert_eq!(serde_json::to_value(&patch).unwrap(), json!({}));
+    }
+
+    #[test]
+    fn diff_changed() {
+        let original = document();
+        let mut edited = original.clone();
+        edited.title = Some("receipt".to_string());
+        edited.correspondent = None;
+        edited.storage_path = Some(7.into());
+        edited.archive_serial_number = None;
+        let patch = Patch::diff(&original, &edited);
+        assert_eq!(
+            serde_json::to_value(&patch).unwrap(),
+            json!({
+                "title": "receipt",
+                "correspondent": null,
+                "storage_path": 7,
+                "archive_serial_number": null,
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<Patch>(serde_json::to_value(&patch).unwrap()).unwrap(),
+            patch
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::multipart::{Form, Part};
+use crate::schema::model;
+
+/// Metadata of a document uploaded with
+/// [`Documents::post_document`](crate::services::Documents::post_document).
+#[skip_serializing_none]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Upload {
+    pub title: Option<String>,
+    pub created: Option<model::Date>,
+    pub correspondent: Option<model::CorrespondentId>,
+    pub document_type: Option<model::DocumentTypeId>,
+    pub storage_path: Option<model::StoragePathId>,
+    pub tags: Vec<model::TagId>,
+    pub archive_serial_number: Option<model::Asn>,
+    pub custom_fields: Vec<model::CustomFieldId>,
+}
+
+#[must_use]
+pub fn upload() -> Upload {
+    Upload::new()
+}
+
+impl Upload {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn title(mut self, value: String) -> Self {
+        self.title = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn created(mut self, value: model::Date) -> Self {
+        self.created = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn correspondent(mut self, value: model::CorrespondentId) -> Self {
+        self.correspondent = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn document_type(mut self, value: model::DocumentTypeId) -> Self {
+        self.document_type = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn storage_path(mut self, value: model::StoragePathId) -> Self {
+        self.storage_path = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn tags(mut self, value: Vec<model::TagId>) -> Self {
+        self.tags = value;
+        self
+    }
+
+    #[must_use]
+    pub fn archive_serial_number(mut self, value: model::Asn) -> Self {
+        self.archive_serial_number = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn custom_fields(mut self, value: Vec<model::CustomFieldId>) -> Self {
+        self.custom_fields = value;
+        self
+    }
+
+    /// Builds the form expected by the server, with the given file.
+    #[must_use]
+    pub fn form(&self, document: Part) -> Form {
+        let mut form = Form::new().part("document", document);
+        if let Some(title) = &self.title {
+            form = form.text("title", title);
+        }
+        if let Some(created) = &self.created {
+            form = form.text("created", ToString::to_string(created));
+        }
+        if let Some(correspondent) = self.correspondent {
+            form = form.text("correspondent", correspondent.to_string());
+        }
+        if let Some(document_type) = self.document_type {
+            form = form.text("document_type", document_type.to_string());
+        }
+        if let Some(storage_path) = self.storage_path {
+            form = form.text("storage_path", storage_path.to_string());
+        }
+        for tag in &self.tags {
+            form = form.text("tags", tag.to_string());
+        }
+        if let Some(archive_serial_number) = self.archive_serial_number {
+            form = form.text("archive_serial_number", archive_serial_number.to_string());
+        }
+        for custom_field in &self.custom_fields {
+            form = form.text("custom_fields", custom_field.to_string());
+        }
+        form
+    }
+}
@@ -4,7 +4,7 @@ use serde_with::skip_serializing_none;
 use crate::schema::model;
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
     pub name: String,
     pub permissions: Vec<model::PermissionClass>,
@@ -21,6 +21,19 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(original: &model::Group, edited: &model::Group) -> Self {
+        let mut patch = Self::new();
+        if original.name != edited.name {
+            patch.name = Some(edited.name.clone());
+        }
+        if original.permissions != edited.permissions {
+            patch.permissions = Some(edited.permissions.clone());
+        }
+        patch
+    }
+
     #[must_use]
     pub fn name(mut self, value: String) -> Self {
         self.name = Some(value);
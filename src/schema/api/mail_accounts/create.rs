@@ -4,24 +4,29 @@ use serde_with::skip_serializing_none;
 use crate::schema::model;
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
     pub name: String,
     pub imap_server: String,
     pub imap_port: Option<i64>,
     pub imap_security: Option<model::ImapSecurity>,
     pub username: String,
-    pub password: String,
+    pub password: model::Secret,
     pub character_set: Option<String>,
     pub is_token: Option<bool>,
-    pub owner: Option<i32>,
-    pub set_permissions: Option<model::Permissions>,
+    pub owner: Option<model::UserId>,
+    pub set_permissions: Option<model::SetPermissions>,
     pub account_type: Option<model::AccountType>,
-    pub expiration: Option<String>,
+    pub expiration: Option<model::DateTime>,
 }
 
 #[must_use]
-pub fn create(name: String, imap_server: String, username: String, password: String) -> Create {
+pub fn create(
+    name: impl Into<String>,
+    imap_server: impl Into<String>,
+    username: impl Into<String>,
+    password: impl Into<String>,
+) -> Create {
     Create::new(name, imap_server, username, password)
 }
 
@@ -37,7 +42,7 @@ impl From<&model::MailAccount> for Create {
             character_set: item.character_set.clone(),
             is_token: item.is_token,
             owner: item.owner,
-            set_permissions: Some(item.permissions.clone()),
+            set_permissions: Some((&item.permissions).into()),
             account_type: item.account_type,
             expiration: item.expiration.clone(),
         }
@@ -46,14 +51,19 @@ impl From<&model::MailAccount> for Create {
 
 impl Create {
     #[must_use]
-    pub fn new(name: String, imap_server: String, username: String, password: String) -> Self {
+    pub fn new(
+        name: impl Into<String>,
+        imap_server: impl Into<String>,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
         Self {
-            name,
-            imap_server,
+            name: name.into(),
+            imap_server: imap_server.into(),
             imap_port: None,
             imap_security: None,
-            username,
-            password,
+            username: username.into(),
+            password: model::Secret::new(password),
             character_set: None,
             is_token: None,
             owner: None,
@@ -88,17 +98,25 @@ impl Create {
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
+    pub fn owner(mut self, value: model::UserId) -> Self {
         self.owner = Some(value);
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::Permissions) -> Self {
+    pub fn set_permissions(mut self, value: model::SetPermissions) -> Self {
         self.set_permissions = Some(value);
         self
     }
 
+    /// Sets both the owner and the permissions.
+    #[must_use]
+    pub fn permissions(mut self, value: model::PermissionsForm) -> Self {
+        self.owner = value.owner;
+        self.set_permissions = Some(value.set_permissions);
+        self
+    }
+
     #[must_use]
     pub fn account_type(mut self, value: model::AccountType) -> Self {
         self.account_type = Some(value);
@@ -106,7 +124,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn expiration(mut self, value: String) -> Self {
+    pub fn expiration(mut self, value: model::DateTime) -> Self {
         self.expiration = Some(value);
         self
     }
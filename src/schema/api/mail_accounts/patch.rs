@@ -11,13 +11,15 @@ pub struct Patch {
     pub imap_port: Option<i64>,
     pub imap_security: Option<model::ImapSecurity>,
     pub username: Option<String>,
-    pub password: Option<String>,
+    pub password: Option<model::Secret>,
     pub character_set: Option<String>,
     pub is_token: Option<bool>,
-    pub owner: Option<i32>,
-    pub set_permissions: Option<model::Permissions>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub owner: Option<Option<model::UserId>>,
+    pub set_permissions: Option<model::SetPermissions>,
     pub account_type: Option<model::AccountType>,
-    pub expiration: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub expiration: Option<Option<model::DateTime>>,
 }
 
 #[must_use]
@@ -31,6 +33,49 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(original: &model::MailAccount, edited: &model::MailAccount) -> Self {
+        let mut patch = Self::new();
+        if original.name != edited.name {
+            patch.name = Some(edited.name.clone());
+        }
+        if original.imap_server != edited.imap_server {
+            patch.imap_server = Some(edited.imap_server.clone());
+        }
+        if original.imap_port != edited.imap_port {
+            patch.imap_port = edited.imap_port;
+        }
+        if original.imap_security != edited.imap_security {
+            patch.imap_security = edited.imap_security;
+        }
+        if original.username != edited.username {
+            patch.username = Some(edited.username.clone());
+        }
+        if original.password != edited.password {
+            patch.password = Some(edited.password.clone());
+        }
+        if original.character_set != edited.character_set {
+            patch.character_set.clone_from(&edited.character_set);
+        }
+        if original.is_token != edited.is_token {
+            patch.is_token = edited.is_token;
+        }
+        if original.owner != edited.owner {
+            patch.owner = Some(edited.owner);
+        }
+        if original.permissions != edited.permissions {
+            patch.set_permissions = Some((&edited.permissions).into());
+        }
+        if original.account_type != edited.account_type {
+            patch.account_type = edited.account_type;
+        }
+        if original.expiration != edited.expiration {
+            patch.expiration = Some(edited.expiration.clone());
+        }
+        patch
+    }
+
     #[must_use]
     pub fn name(mut self, value: String) -> Self {
         self.name = Some(value);
@@ -63,7 +108,7 @@ impl Patch {
 
     #[must_use]
     pub fn password(mut self, value: String) -> Self {
-        self.password = Some(value);
+        self.password = Some(value.into());
         self
     }
 
@@ -80,17 +125,25 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
-        self.owner = Some(value);
+    pub fn owner(mut self, value: impl Into<Option<model::UserId>>) -> Self {
+        self.owner = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::Permissions) -> Self {
+    pub fn set_permissions(mut self, value: model::SetPermissions) -> Self {
         self.set_permissions = Some(value);
         self
     }
 
+    /// Sets both the owner and the permissions.
+    #[must_use]
+    pub fn permissions(mut self, value: model::PermissionsForm) -> Self {
+        self.owner = Some(value.owner);
+        self.set_permissions = Some(value.set_permissions);
+        self
+    }
+
     #[must_use]
     pub fn account_type(mut self, value: model::AccountType) -> Self {
         self.account_type = Some(value);
@@ -98,8 +151,8 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn expiration(mut self, value: String) -> Self {
-        self.expiration = Some(value);
+    pub fn expiration(mut self, value: impl Into<Option<model::DateTime>>) -> Self {
+        self.expiration = Some(value.into());
         self
     }
 }
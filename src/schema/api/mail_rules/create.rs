@@ -7,7 +7,7 @@ use crate::schema::model;
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
     pub name: String,
-    pub account: i32,
+    pub account: model::MailAccountId,
     pub enabled: Option<bool>,
     pub folder: Option<String>,
     pub filter_from: Option<String>,
@@ -20,21 +20,21 @@ pub struct Create {
     pub action: Option<model::MailRuleAction>,
     pub action_parameter: Option<String>,
     pub assign_title_from: Option<model::AssignTitleFrom>,
-    pub assign_tags: Option<Vec<i32>>,
+    pub assign_tags: Option<Vec<model::TagId>>,
     pub assign_correspondent_from: Option<model::AssignCorrespondentFrom>,
-    pub assign_correspondent: Option<i32>,
-    pub assign_document_type: Option<i32>,
+    pub assign_correspondent: Option<model::CorrespondentId>,
+    pub assign_document_type: Option<model::DocumentTypeId>,
     pub assign_owner_from_rule: Option<bool>,
     pub order: Option<i32>,
     pub attachment_type: Option<model::AttachmentType>,
     pub consumption_scope: Option<model::ConsumptionScope>,
     pub pdf_layout: Option<model::PdfLayout>,
-    pub owner: Option<i32>,
-    pub set_permissions: Option<model::Permissions>,
+    pub owner: Option<model::UserId>,
+    pub set_permissions: Option<model::SetPermissions>,
 }
 
 #[must_use]
-pub fn create(name: String, account: i32) -> Create {
+pub fn create(name: impl Into<String>, account: model::MailAccountId) -> Create {
     Create::new(name, account)
 }
 
@@ -65,16 +65,24 @@ impl From<&model::MailRule> for Create {
             consumption_scope: item.consumption_scope,
             pdf_layout: item.pdf_layout,
             owner: item.owner,
-            set_permissions: Some(item.permissions.clone()),
+            set_permissions: Some((&item.permissions).into()),
         }
     }
 }
 
+/// A rule without a name nor an account, which must both be set before the
+/// rule is created.
+impl Default for Create {
+    fn default() -> Self {
+        Self::new(String::new(), model::MailAccountId::new(0))
+    }
+}
+
 impl Create {
     #[must_use]
-    pub fn new(name: String, account: i32) -> Self {
+    pub fn new(name: impl Into<String>, account: model::MailAccountId) -> Self {
         Self {
-            name,
+            name: name.into(),
             account,
             enabled: None,
             folder: None,
@@ -168,6 +176,22 @@ impl Create {
         self
     }
 
+    /// Moves the consumed mails to the given folder.
+    #[must_use]
+    pub fn move_to_folder(mut self, folder: String) -> Self {
+        self.action = Some(model::MailRuleAction::MoveToFolder);
+        self.action_parameter = Some(folder);
+        self
+    }
+
+    /// Tags the consumed mails with the given keyword.
+    #[must_use]
+    pub fn tag_the_mail(mut self, keyword: String) -> Self {
+        self.action = Some(model::MailRuleAction::TagTheMail);
+        self.action_parameter = Some(keyword);
+        self
+    }
+
     #[must_use]
     pub fn assign_title_from(mut self, value: model::AssignTitleFrom) -> Self {
         self.assign_title_from = Some(value);
@@ -175,7 +199,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn assign_tags(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_tags(mut self, value: Vec<model::TagId>) -> Self {
         self.assign_tags = Some(value);
         self
     }
@@ -187,13 +211,13 @@ impl Create {
     }
 
     #[must_use]
-    pub fn assign_correspondent(mut self, value: i32) -> Self {
+    pub fn assign_correspondent(mut self, value: model::CorrespondentId) -> Self {
         self.assign_correspondent = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_document_type(mut self, value: i32) -> Self {
+    pub fn assign_document_type(mut self, value: model::DocumentTypeId) -> Self {
         self.assign_document_type = Some(value);
         self
     }
@@ -229,14 +253,22 @@ impl Create {
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
+    pub fn owner(mut self, value: model::UserId) -> Self {
         self.owner = Some(value);
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::Permissions) -> Self {
+    pub fn set_permissions(mut self, value: model::SetPermissions) -> Self {
         self.set_permissions = Some(value);
         self
     }
+
+    /// Sets both the owner and the permissions.
+    #[must_use]
+    pub fn permissions(mut self, value: model::PermissionsForm) -> Self {
+        self.owner = value.owner;
+        self.set_permissions = Some(value.set_permissions);
+        self
+    }
 }
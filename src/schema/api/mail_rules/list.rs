@@ -1,10 +1,23 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::schema::api::Ordering;
+use crate::schema::utils::order_by;
+
+order_by! {
+    /// Field by which mail rules can be ordered.
+    pub enum OrderBy {
+        Id => "id",
+        Name => "name",
+        Order => "order",
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct List {
     full_perms: bool,
+    pub ordering: Option<Ordering<OrderBy>>,
     pub page: Option<i32>,
     pub page_size: Option<i32>,
 }
@@ -18,6 +31,7 @@ impl Default for List {
     fn default() -> Self {
         Self {
             full_perms: true,
+            ordering: None,
             page: None,
             page_size: None,
         }
@@ -30,6 +44,12 @@ impl List {
         Self::default()
     }
 
+    #[must_use]
+    pub fn ordering(mut self, value: Ordering<OrderBy>) -> Self {
+        self.ordering = Some(value);
+        self
+    }
+
     #[must_use]
     pub fn page(mut self, value: i32) -> Self {
         self.page = Some(value);
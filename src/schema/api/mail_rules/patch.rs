@@ -7,30 +7,40 @@ use crate::schema::model;
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Patch {
     pub name: Option<String>,
-    pub account: Option<i32>,
+    pub account: Option<model::MailAccountId>,
     pub enabled: Option<bool>,
     pub folder: Option<String>,
-    pub filter_from: Option<String>,
-    pub filter_to: Option<String>,
-    pub filter_subject: Option<String>,
-    pub filter_body: Option<String>,
-    pub filter_attachment_filename_include: Option<String>,
-    pub filter_attachment_filename_exclude: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub filter_from: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub filter_to: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub filter_subject: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub filter_body: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub filter_attachment_filename_include: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub filter_attachment_filename_exclude: Option<Option<String>>,
     pub maximum_age: Option<i64>,
     pub action: Option<model::MailRuleAction>,
-    pub action_parameter: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub action_parameter: Option<Option<String>>,
     pub assign_title_from: Option<model::AssignTitleFrom>,
-    pub assign_tags: Option<Vec<i32>>,
+    pub assign_tags: Option<Vec<model::TagId>>,
     pub assign_correspondent_from: Option<model::AssignCorrespondentFrom>,
-    pub assign_correspondent: Option<i32>,
-    pub assign_document_type: Option<i32>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub assign_correspondent: Option<Option<model::CorrespondentId>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub assign_document_type: Option<Option<model::DocumentTypeId>>,
     pub assign_owner_from_rule: Option<bool>,
     pub order: Option<i32>,
     pub attachment_type: Option<model::AttachmentType>,
     pub consumption_scope: Option<model::ConsumptionScope>,
     pub pdf_layout: Option<model::PdfLayout>,
-    pub owner: Option<i32>,
-    pub set_permissions: Option<model::Permissions>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub owner: Option<Option<model::UserId>>,
+    pub set_permissions: Option<model::SetPermissions>,
 }
 
 #[must_use]
@@ -44,6 +54,92 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(original: &model::MailRule, edited: &model::MailRule) -> Self {
+        let mut patch = Self::new();
+        if original.name != edited.name {
+            patch.name = Some(edited.name.clone());
+        }
+        if original.account != edited.account {
+            patch.account = Some(edited.account);
+        }
+        if original.enabled != edited.enabled {
+            patch.enabled = edited.enabled;
+        }
+        if original.folder != edited.folder {
+            patch.folder.clone_from(&edited.folder);
+        }
+        if original.filter_from != edited.filter_from {
+            patch.filter_from = Some(edited.filter_from.clone());
+        }
+        if original.filter_to != edited.filter_to {
+            patch.filter_to = Some(edited.filter_to.clone());
+        }
+        if original.filter_subject != edited.filter_subject {
+            patch.filter_subject = Some(edited.filter_subject.clone());
+        }
+        if original.filter_body != edited.filter_body {
+            patch.filter_body = Some(edited.filter_body.clone());
+        }
+        if original.filter_attachment_filename_include != edited.filter_attachment_filename_include
+        {
+            patch.filter_attachment_filename_include =
+                Some(edited.filter_attachment_filename_include.clone());
+        }
+        if original.filter_attachment_filename_exclude != edited.filter_attachment_filename_exclude
+        {
+            patch.filter_attachment_filename_exclude =
+                Some(edited.filter_attachment_filename_exclude.clone());
+        }
+        if original.maximum_age != edited.maximum_age {
+            patch.maximum_age = edited.maximum_age;
+        }
+        if original.action != edited.action {
+            patch.action = edited.action;
+        }
+        if original.action_parameter != edited.action_parameter {
+            patch.action_parameter = Some(edited.action_parameter.clone());
+        }
+        if original.assign_title_from != edited.assign_title_from {
+            patch.assign_title_from = edited.assign_title_from;
+        }
+        if original.assign_tags != edited.assign_tags {
+            patch.assign_tags.clone_from(&edited.assign_tags);
+        }
+        if original.assign_correspondent_from != edited.assign_correspondent_from {
+            patch.assign_correspondent_from = edited.assign_correspondent_from;
+        }
+        if original.assign_correspondent != edited.assign_correspondent {
+            patch.assign_correspondent = Some(edited.assign_correspondent);
+        }
+        if original.assign_document_type != edited.assign_document_type {
+            patch.assign_document_type = Some(edited.assign_document_type);
+        }
+        if original.assign_owner_from_rule != edited.assign_owner_from_rule {
+            patch.assign_owner_from_rule = edited.assign_owner_from_rule;
+        }
+        if original.order != edited.order {
+            patch.order = edited.order;
+        }
+        if original.attachment_type != edited.attachment_type {
+            patch.attachment_type = edited.attachment_type;
+        }
+        if original.consumption_scope != edited.consumption_scope {
+            patch.consumption_scope = edited.consumption_scope;
+        }
+        if original.pdf_layout != edited.pdf_layout {
+            patch.pdf_layout = edited.pdf_layout;
+        }
+        if original.owner != edited.owner {
+            patch.owner = Some(edited.owner);
+        }
+        if original.permissions != edited.permissions {
+            patch.set_permissions = Some((&edited.permissions).into());
+        }
+        patch
+    }
+
     #[must_use]
     pub fn name(mut self, value: String) -> Self {
         self.name = Some(value);
@@ -51,7 +147,7 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn account(mut self, value: i32) -> Self {
+    pub fn account(mut self, value: model::MailAccountId) -> Self {
         self.account = Some(value);
         self
     }
@@ -69,38 +165,38 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn filter_from(mut self, value: String) -> Self {
-        self.filter_from = Some(value);
+    pub fn filter_from(mut self, value: impl Into<Option<String>>) -> Self {
+        self.filter_from = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn filter_to(mut self, value: String) -> Self {
-        self.filter_to = Some(value);
+    pub fn filter_to(mut self, value: impl Into<Option<String>>) -> Self {
+        self.filter_to = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn filter_subject(mut self, value: String) -> Self {
-        self.filter_subject = Some(value);
+    pub fn filter_subject(mut self, value: impl Into<Option<String>>) -> Self {
+        self.filter_subject = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn filter_body(mut self, value: String) -> Self {
-        self.filter_body = Some(value);
+    pub fn filter_body(mut self, value: impl Into<Option<String>>) -> Self {
+        self.filter_body = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn filter_attachment_filename_include(mut self, value: String) -> Self {
-        self.filter_attachment_filename_include = Some(value);
+    pub fn filter_attachment_filename_include(mut self, value: impl Into<Option<String>>) -> Self {
+        self.filter_attachment_filename_include = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn filter_attachment_filename_exclude(mut self, value: String) -> Self {
-        self.filter_attachment_filename_exclude = Some(value);
+    pub fn filter_attachment_filename_exclude(mut self, value: impl Into<Option<String>>) -> Self {
+        self.filter_attachment_filename_exclude = Some(value.into());
         self
     }
 
@@ -117,8 +213,24 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn action_parameter(mut self, value: String) -> Self {
-        self.action_parameter = Some(value);
+    pub fn action_parameter(mut self, value: impl Into<Option<String>>) -> Self {
+        self.action_parameter = Some(value.into());
+        self
+    }
+
+    /// Moves the consumed mails to the given folder.
+    #[must_use]
+    pub fn move_to_folder(mut self, folder: String) -> Self {
+        self.action = Some(model::MailRuleAction::MoveToFolder);
+        self.action_parameter = Some(Some(folder));
+        self
+    }
+
+    /// Tags the consumed mails with the given keyword.
+    #[must_use]
+    pub fn tag_the_mail(mut self, keyword: String) -> Self {
+        self.action = Some(model::MailRuleAction::TagTheMail);
+        self.action_parameter = Some(Some(keyword));
         self
     }
 
@@ -129,7 +241,7 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn assign_tags(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_tags(mut self, value: Vec<model::TagId>) -> Self {
         self.assign_tags = Some(value);
         self
     }
@@ -141,14 +253,17 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn assign_correspondent(mut self, value: i32) -> Self {
-        self.assign_correspondent = Some(value);
+    pub fn assign_correspondent(
+        mut self,
+        value: impl Into<Option<model::CorrespondentId>>,
+    ) -> Self {
+        self.assign_correspondent = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn assign_document_type(mut self, value: i32) -> Self {
-        self.assign_document_type = Some(value);
+    pub fn assign_document_type(mut self, value: impl Into<Option<model::DocumentTypeId>>) -> Self {
+        self.assign_document_type = Some(value.into());
         self
     }
 
@@ -183,14 +298,22 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
-        self.owner = Some(value);
+    pub fn owner(mut self, value: impl Into<Option<model::UserId>>) -> Self {
+        self.owner = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::Permissions) -> Self {
+    pub fn set_permissions(mut self, value: model::SetPermissions) -> Self {
         self.set_permissions = Some(value);
         self
     }
+
+    /// Sets both the owner and the permissions.
+    #[must_use]
+    pub fn permissions(mut self, value: model::PermissionsForm) -> Self {
+        self.owner = Some(value.owner);
+        self.set_permissions = Some(value.set_permissions);
+        self
+    }
 }
@@ -6,11 +6,16 @@ pub mod documents;
 pub mod groups;
 pub mod mail_accounts;
 pub mod mail_rules;
+mod ordering;
+pub use ordering::*;
 pub mod profile;
 pub mod saved_views;
+pub mod search;
 pub mod share_links;
 pub mod storage_paths;
 pub mod tags;
 pub mod tasks;
+pub mod token;
+pub mod ui_settings;
 pub mod users;
 pub mod workflows;
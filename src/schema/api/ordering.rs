@@ -0,0 +1,79 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Order of the results of a listing: a field, such as
+/// [`documents::OrderBy::Created`](super::documents::OrderBy::Created), and a
+/// direction. It is sent as the `ordering` parameter, the name of the field,
+/// prefixed with `-` when descending.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Ordering<F> {
+    Asc(F),
+    Desc(F),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("unknown ordering field {0:?}")]
+pub struct OrderingParseError(pub String);
+
+impl<F> Ordering<F> {
+    #[must_use]
+    pub fn field(&self) -> &F {
+        match self {
+            Self::Asc(field) | Self::Desc(field) => field,
+        }
+    }
+
+    #[must_use]
+    pub fn is_descending(&self) -> bool {
+        matches!(self, Self::Desc(_))
+    }
+
+    /// Same field, in the opposite direction.
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        match self {
+            Self::Asc(field) => Self::Desc(field),
+            Self::Desc(field) => Self::Asc(field),
+        }
+    }
+}
+
+impl<F: fmt::Display> fmt::Display for Ordering<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Asc(field) => write!(f, "{field}"),
+            Self::Desc(field) => write!(f, "-{field}"),
+        }
+    }
+}
+
+impl<F: FromStr> FromStr for Ordering<F> {
+    type Err = F::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix('-') {
+            Some(field) => field.parse().map(Self::Desc),
+            None => s.parse().map(Self::Asc),
+        }
+    }
+}
+
+impl<F: fmt::Display> Serialize for Ordering<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, F> Deserialize<'de> for Ordering<F>
+where
+    F: FromStr,
+    F::Err: fmt::Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
@@ -1,2 +1,4 @@
 mod patch;
 pub use patch::*;
+mod totp;
+pub use totp::*;
@@ -1,11 +1,13 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::schema::model;
+
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Patch {
     pub email: Option<String>,
-    pub password: Option<String>,
+    pub password: Option<model::Secret>,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
 }
@@ -21,6 +23,25 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(original: &model::Profile, edited: &model::Profile) -> Self {
+        let mut patch = Self::new();
+        if original.email != edited.email {
+            patch.email = Some(edited.email.clone().unwrap_or_default());
+        }
+        if original.password != edited.password {
+            patch.password.clone_from(&edited.password);
+        }
+        if original.first_name != edited.first_name {
+            patch.first_name = Some(edited.first_name.clone().unwrap_or_default());
+        }
+        if original.last_name != edited.last_name {
+            patch.last_name = Some(edited.last_name.clone().unwrap_or_default());
+        }
+        patch
+    }
+
     #[must_use]
     pub fn email(mut self, value: String) -> Self {
         self.email = Some(value);
@@ -29,7 +50,7 @@ impl Patch {
 
     #[must_use]
     pub fn password(mut self, value: String) -> Self {
-        self.password = Some(value);
+        self.password = Some(value.into());
         self
     }
 
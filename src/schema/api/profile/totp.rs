@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::schema::model;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActivateTotp {
+    pub secret: model::Secret,
+    pub code: String,
+}
+
+#[must_use]
+pub fn activate_totp(secret: String, code: String) -> ActivateTotp {
+    ActivateTotp::new(secret, code)
+}
+
+impl ActivateTotp {
+    #[must_use]
+    pub fn new(secret: String, code: String) -> Self {
+        Self {
+            secret: secret.into(),
+            code,
+        }
+    }
+}
@@ -2,30 +2,28 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::schema::model;
-use crate::schema::utils;
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
     pub name: String,
     pub show_on_dashboard: bool,
     pub show_in_sidebar: bool,
     pub sort_field: Option<String>,
     pub sort_reverse: Option<bool>,
-    #[serde(with = "utils::saved_view_filter_rules")]
-    pub filter_rules: Vec<(model::RuleType, String)>,
+    pub filter_rules: Vec<model::FilterRule>,
     pub display_fields: Option<serde_json::Value>,
     pub page_size: Option<i64>,
     pub display_mode: Option<model::DisplayMode>,
-    pub owner: Option<i32>,
+    pub owner: Option<model::UserId>,
 }
 
 #[must_use]
 pub fn create(
-    name: String,
+    name: impl Into<String>,
     show_on_dashboard: bool,
     show_in_sidebar: bool,
-    filter_rules: Vec<(model::RuleType, String)>,
+    filter_rules: Vec<model::FilterRule>,
 ) -> Create {
     Create::new(name, show_on_dashboard, show_in_sidebar, filter_rules)
 }
@@ -50,13 +48,13 @@ impl From<&model::SavedView> for Create {
 impl Create {
     #[must_use]
     pub fn new(
-        name: String,
+        name: impl Into<String>,
         show_on_dashboard: bool,
         show_in_sidebar: bool,
-        filter_rules: Vec<(model::RuleType, String)>,
+        filter_rules: Vec<model::FilterRule>,
     ) -> Self {
         Self {
-            name,
+            name: name.into(),
             show_on_dashboard,
             show_in_sidebar,
             sort_field: None,
@@ -100,7 +98,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
+    pub fn owner(mut self, value: model::UserId) -> Self {
         self.owner = Some(value);
         self
     }
@@ -2,7 +2,6 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::schema::model;
-use crate::schema::utils;
 
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -10,17 +9,18 @@ pub struct Patch {
     pub name: Option<String>,
     pub show_on_dashboard: Option<bool>,
     pub show_in_sidebar: Option<bool>,
-    pub sort_field: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub sort_field: Option<Option<String>>,
     pub sort_reverse: Option<bool>,
-    #[serde(
-        with = "utils::saved_view_filter_rules",
-        skip_serializing_if = "Vec::is_empty"
-    )]
-    pub filter_rules: Vec<(model::RuleType, String)>,
-    pub page_size: Option<i64>,
-    pub display_mode: Option<model::DisplayMode>,
-    pub owner: Option<i32>,
-    pub set_permissions: Option<model::Permissions>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filter_rules: Vec<model::FilterRule>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub page_size: Option<Option<i64>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub display_mode: Option<Option<model::DisplayMode>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub owner: Option<Option<model::UserId>>,
+    pub set_permissions: Option<model::SetPermissions>,
 }
 
 #[must_use]
@@ -34,6 +34,40 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(original: &model::SavedView, edited: &model::SavedView) -> Self {
+        let mut patch = Self::new();
+        if original.name != edited.name {
+            patch.name = Some(edited.name.clone());
+        }
+        if original.show_on_dashboard != edited.show_on_dashboard {
+            patch.show_on_dashboard = Some(edited.show_on_dashboard);
+        }
+        if original.show_in_sidebar != edited.show_in_sidebar {
+            patch.show_in_sidebar = Some(edited.show_in_sidebar);
+        }
+        if original.sort_field != edited.sort_field {
+            patch.sort_field = Some(edited.sort_field.clone());
+        }
+        if original.sort_reverse != edited.sort_reverse {
+            patch.sort_reverse = edited.sort_reverse;
+        }
+        if original.filter_rules != edited.filter_rules {
+            patch.filter_rules.clone_from(&edited.filter_rules);
+        }
+        if original.page_size != edited.page_size {
+            patch.page_size = Some(edited.page_size);
+        }
+        if original.display_mode != edited.display_mode {
+            patch.display_mode = Some(edited.display_mode);
+        }
+        if original.owner != edited.owner {
+            patch.owner = Some(edited.owner);
+        }
+        patch
+    }
+
     #[must_use]
     pub fn name(mut self, value: String) -> Self {
         self.name = Some(value);
@@ -53,8 +87,8 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn sort_field(mut self, value: String) -> Self {
-        self.sort_field = Some(value);
+    pub fn sort_field(mut self, value: impl Into<Option<String>>) -> Self {
+        self.sort_field = Some(value.into());
         self
     }
 
@@ -65,32 +99,40 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn filter_rules(mut self, value: Vec<(model::RuleType, String)>) -> Self {
+    pub fn filter_rules(mut self, value: Vec<model::FilterRule>) -> Self {
         self.filter_rules = value;
         self
     }
 
     #[must_use]
-    pub fn page_size(mut self, value: i64) -> Self {
-        self.page_size = Some(value);
+    pub fn page_size(mut self, value: impl Into<Option<i64>>) -> Self {
+        self.page_size = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn display_mode(mut self, value: model::DisplayMode) -> Self {
-        self.display_mode = Some(value);
+    pub fn display_mode(mut self, value: impl Into<Option<model::DisplayMode>>) -> Self {
+        self.display_mode = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
-        self.owner = Some(value);
+    pub fn owner(mut self, value: impl Into<Option<model::UserId>>) -> Self {
+        self.owner = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::Permissions) -> Self {
+    pub fn set_permissions(mut self, value: model::SetPermissions) -> Self {
         self.set_permissions = Some(value);
         self
     }
+
+    /// Sets both the owner and the permissions.
+    #[must_use]
+    pub fn permissions(mut self, value: model::PermissionsForm) -> Self {
+        self.owner = Some(value.owner);
+        self.set_permissions = Some(value.set_permissions);
+        self
+    }
 }
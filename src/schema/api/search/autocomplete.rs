@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Autocomplete {
+    pub term: String,
+    pub limit: Option<i32>,
+}
+
+#[must_use]
+pub fn autocomplete(term: String) -> Autocomplete {
+    Autocomplete::new(term)
+}
+
+impl Autocomplete {
+    #[must_use]
+    pub fn new(term: String) -> Self {
+        Self { term, limit: None }
+    }
+
+    #[must_use]
+    pub fn limit(mut self, value: i32) -> Self {
+        self.limit = Some(value);
+        self
+    }
+}
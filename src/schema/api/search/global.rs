@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Global {
+    pub query: String,
+    pub db_only: Option<bool>,
+}
+
+#[must_use]
+pub fn global(query: String) -> Global {
+    Global::new(query)
+}
+
+impl Global {
+    #[must_use]
+    pub fn new(query: String) -> Self {
+        Self {
+            query,
+            db_only: None,
+        }
+    }
+
+    /// Only search object names in the database, skipping the full-text
+    /// index used for documents.
+    #[must_use]
+    pub fn db_only(mut self, value: bool) -> Self {
+        self.db_only = Some(value);
+        self
+    }
+}
@@ -0,0 +1,4 @@
+mod autocomplete;
+pub use autocomplete::*;
+mod global;
+pub use global::*;
@@ -6,8 +6,8 @@ use crate::schema::model;
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
-    pub expiration: Option<String>,
-    pub document: Option<i32>,
+    pub expiration: Option<model::DateTime>,
+    pub document: Option<model::DocumentId>,
     pub file_version: Option<model::FileVersion>,
 }
 
@@ -33,19 +33,19 @@ impl Create {
     }
 
     #[must_use]
-    pub fn expiration(mut self, value: String) -> Self {
+    pub fn expiration(mut self, value: model::DateTime) -> Self {
         self.expiration = Some(value);
         self
     }
 
     #[must_use]
-    pub fn document(mut self, value: i32) -> Self {
+    pub fn document(mut self, value: model::DocumentId) -> Self {
         self.document = Some(value);
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::FileVersion) -> Self {
+    pub fn file_version(mut self, value: model::FileVersion) -> Self {
         self.file_version = Some(value);
         self
     }
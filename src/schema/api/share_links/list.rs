@@ -1,6 +1,18 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::schema::api::Ordering;
+use crate::schema::utils::order_by;
+
+order_by! {
+    /// Field by which share links can be ordered.
+    pub enum OrderBy {
+        Created => "created",
+        Expiration => "expiration",
+        Document => "document",
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct List {
@@ -48,7 +60,7 @@ pub struct List {
     pub expiration_month: Option<f64>,
     #[serde(rename = "expiration__year")]
     pub expiration_year: Option<f64>,
-    pub ordering: Option<String>,
+    pub ordering: Option<Ordering<OrderBy>>,
     pub page: Option<i32>,
     pub page_size: Option<i32>,
 }
@@ -197,7 +209,7 @@ impl List {
     }
 
     #[must_use]
-    pub fn ordering(mut self, value: String) -> Self {
+    pub fn ordering(mut self, value: Ordering<OrderBy>) -> Self {
         self.ordering = Some(value);
         self
     }
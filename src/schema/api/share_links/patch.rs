@@ -6,8 +6,9 @@ use crate::schema::model;
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Patch {
-    pub expiration: Option<String>,
-    pub document: Option<i32>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub expiration: Option<Option<model::DateTime>>,
+    pub document: Option<model::DocumentId>,
     pub file_version: Option<model::FileVersion>,
 }
 
@@ -22,20 +23,36 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
     #[must_use]
-    pub fn expiration(mut self, value: String) -> Self {
-        self.expiration = Some(value);
+    pub fn diff(original: &model::ShareLink, edited: &model::ShareLink) -> Self {
+        let mut patch = Self::new();
+        if original.expiration != edited.expiration {
+            patch.expiration = Some(edited.expiration.clone());
+        }
+        if original.document != edited.document {
+            patch.document = edited.document;
+        }
+        if original.file_version != edited.file_version {
+            patch.file_version = edited.file_version;
+        }
+        patch
+    }
+
+    #[must_use]
+    pub fn expiration(mut self, value: impl Into<Option<model::DateTime>>) -> Self {
+        self.expiration = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn document(mut self, value: i32) -> Self {
+    pub fn document(mut self, value: model::DocumentId) -> Self {
         self.document = Some(value);
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::FileVersion) -> Self {
+    pub fn file_version(mut self, value: model::FileVersion) -> Self {
         self.file_version = Some(value);
         self
     }
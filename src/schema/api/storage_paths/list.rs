@@ -1,15 +1,28 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::schema::utils;
+use crate::schema::api::Ordering;
+use crate::schema::model;
+use crate::schema::utils::{self, order_by};
+
+order_by! {
+    /// Field by which storage paths can be ordered.
+    pub enum OrderBy {
+        Name => "name",
+        Path => "path",
+        MatchingAlgorithm => "matching_algorithm",
+        Match => "match",
+        DocumentCount => "document_count",
+    }
+}
 
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct List {
     full_perms: bool,
-    pub id: Option<i32>,
+    pub id: Option<model::StoragePathId>,
     #[serde(rename = "id__in", with = "utils::comma_list")]
-    pub id_in: Option<Vec<i32>>,
+    pub id_in: Option<Vec<model::StoragePathId>>,
     #[serde(rename = "name__icontains")]
     pub name_icontains: Option<String>,
     #[serde(rename = "name__iendswith")]
@@ -26,7 +39,7 @@ pub struct List {
     pub path_iexact: Option<String>,
     #[serde(rename = "path__istartswith")]
     pub path_istartswith: Option<String>,
-    pub ordering: Option<String>,
+    pub ordering: Option<Ordering<OrderBy>>,
     pub page: Option<i32>,
     pub page_size: Option<i32>,
 }
@@ -58,13 +71,13 @@ impl List {
     }
 
     #[must_use]
-    pub fn id(mut self, value: i32) -> Self {
+    pub fn id(mut self, value: model::StoragePathId) -> Self {
         self.id = Some(value);
         self
     }
 
     #[must_use]
-    pub fn id_in(mut self, value: Vec<i32>) -> Self {
+    pub fn id_in(mut self, value: Vec<model::StoragePathId>) -> Self {
         self.id_in = Some(value);
         self
     }
@@ -118,7 +131,7 @@ impl List {
     }
 
     #[must_use]
-    pub fn ordering(mut self, value: String) -> Self {
+    pub fn ordering(mut self, value: Ordering<OrderBy>) -> Self {
         self.ordering = Some(value);
         self
     }
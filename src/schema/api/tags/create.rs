@@ -4,21 +4,21 @@ use serde_with::skip_serializing_none;
 use crate::schema::model;
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
     pub name: String,
-    pub color: Option<String>,
+    pub color: Option<model::Color>,
     #[serde(rename = "match")]
     pub matches: Option<String>,
     pub matching_algorithm: Option<model::MatchingAlgorithm>,
     pub is_insensitive: Option<bool>,
     pub is_inbox_tag: Option<bool>,
-    pub owner: Option<i32>,
-    pub set_permissions: Option<model::Permissions>,
+    pub owner: Option<model::UserId>,
+    pub set_permissions: Option<model::SetPermissions>,
 }
 
 #[must_use]
-pub fn create(name: String) -> Create {
+pub fn create(name: impl Into<String>) -> Create {
     Create::new(name)
 }
 
@@ -26,22 +26,22 @@ impl From<&model::Tag> for Create {
     fn from(item: &model::Tag) -> Self {
         Self {
             name: item.name.clone(),
-            color: item.color.clone(),
+            color: item.color,
             matches: item.matches.clone(),
             matching_algorithm: item.matching_algorithm,
             is_insensitive: item.is_insensitive,
             is_inbox_tag: item.is_inbox_tag,
             owner: item.owner,
-            set_permissions: Some(item.permissions.clone()),
+            set_permissions: Some((&item.permissions).into()),
         }
     }
 }
 
 impl Create {
     #[must_use]
-    pub fn new(name: String) -> Self {
+    pub fn new(name: impl Into<String>) -> Self {
         Self {
-            name,
+            name: name.into(),
             color: None,
             matches: None,
             matching_algorithm: None,
@@ -53,7 +53,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn color(mut self, value: String) -> Self {
+    pub fn color(mut self, value: model::Color) -> Self {
         self.color = Some(value);
         self
     }
@@ -83,14 +83,31 @@ impl Create {
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
+    pub fn owner(mut self, value: model::UserId) -> Self {
         self.owner = Some(value);
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::Permissions) -> Self {
+    pub fn set_permissions(mut self, value: model::SetPermissions) -> Self {
         self.set_permissions = Some(value);
         self
     }
+
+    /// Sets both the owner and the permissions.
+    #[must_use]
+    pub fn permissions(mut self, value: model::PermissionsForm) -> Self {
+        self.owner = value.owner;
+        self.set_permissions = Some(value.set_permissions);
+        self
+    }
+
+    /// Checks that the match is consistent with the matching algorithm, if
+    /// one is set, before sending the request.
+    pub fn validate_match(&self) -> Result<(), model::MatchError> {
+        match self.matching_algorithm {
+            Some(algorithm) => algorithm.validate(self.matches.as_deref().unwrap_or("")),
+            None => Ok(()),
+        }
+    }
 }
@@ -7,14 +7,16 @@ use crate::schema::model;
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Patch {
     pub name: Option<String>,
-    pub color: Option<String>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub color: Option<Option<model::Color>>,
     #[serde(rename = "match")]
     pub matches: Option<String>,
     pub matching_algorithm: Option<model::MatchingAlgorithm>,
     pub is_insensitive: Option<bool>,
     pub is_inbox_tag: Option<bool>,
-    pub owner: Option<i32>,
-    pub set_permissions: Option<model::Permissions>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub owner: Option<Option<model::UserId>>,
+    pub set_permissions: Option<model::SetPermissions>,
 }
 
 #[must_use]
@@ -28,6 +30,37 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(original: &model::Tag, edited: &model::Tag) -> Self {
+        let mut patch = Self::new();
+        if original.name != edited.name {
+            patch.name = Some(edited.name.clone());
+        }
+        if original.color != edited.color {
+            patch.color = Some(edited.color);
+        }
+        if original.matches != edited.matches {
+            patch.matches = Some(edited.matches.clone().unwrap_or_default());
+        }
+        if original.matching_algorithm != edited.matching_algorithm {
+            patch.matching_algorithm = edited.matching_algorithm;
+        }
+        if original.is_insensitive != edited.is_insensitive {
+            patch.is_insensitive = edited.is_insensitive;
+        }
+        if original.is_inbox_tag != edited.is_inbox_tag {
+            patch.is_inbox_tag = edited.is_inbox_tag;
+        }
+        if original.owner != edited.owner {
+            patch.owner = Some(edited.owner);
+        }
+        if original.permissions != edited.permissions {
+            patch.set_permissions = Some((&edited.permissions).into());
+        }
+        patch
+    }
+
     #[must_use]
     pub fn name(mut self, value: String) -> Self {
         self.name = Some(value);
@@ -35,8 +68,8 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn color(mut self, value: String) -> Self {
-        self.color = Some(value);
+    pub fn color(mut self, value: impl Into<Option<model::Color>>) -> Self {
+        self.color = Some(value.into());
         self
     }
 
@@ -65,14 +98,71 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
-        self.owner = Some(value);
+    pub fn owner(mut self, value: impl Into<Option<model::UserId>>) -> Self {
+        self.owner = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn set_permissions(mut self, value: model::Permissions) -> Self {
+    pub fn set_permissions(mut self, value: model::SetPermissions) -> Self {
         self.set_permissions = Some(value);
         self
     }
+
+    /// Sets both the owner and the permissions.
+    #[must_use]
+    pub fn permissions(mut self, value: model::PermissionsForm) -> Self {
+        self.owner = Some(value.owner);
+        self.set_permissions = Some(value.set_permissions);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{Patch, model};
+
+    fn tag() -> model::Tag {
+        serde_json::from_value(json!({
+            "id": 1,
+            "slug": "inbox",
+            "color": "#a6cee3",
+            "text_color": "#000000",
+            "document_count": 0,
+            "user_can_change": true,
+            "name": "inbox",
+            "match": "invoice",
+            "matching_algorithm": 1,
+            "is_insensitive": true,
+            "is_inbox_tag": true,
+            "owner": 2,
+            "permissions": {},
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn diff_cleared() {
+        let original = tag();
+        let mut edited = original.clone();
+        edited.matches = None;
+        edited.owner = None;
+        edited.is_inbox_tag = Some(false);
+        let patch = Patch::diff(&original, &edited);
+        assert_eq!(
+            serde_json::to_value(&patch).unwrap(),
+            json!({ "match": "", "owner": null, "is_inbox_tag": false })
+        );
+    }
+
+    #[test]
+    fn setters_clear() {
+        let patch = Patch::new().owner(None).name("inbox".to_string());
+        assert_eq!(
+            serde_json::to_value(&patch).unwrap(),
+            json!({ "owner": null, "name": "inbox" })
+        );
+    }
 }
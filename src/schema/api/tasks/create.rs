@@ -4,23 +4,23 @@ use serde_with::skip_serializing_none;
 use crate::schema::model;
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
     pub task_id: String,
     pub task_name: Option<model::TaskName>,
     pub task_file_name: Option<String>,
-    pub date_created: Option<String>,
-    pub date_done: Option<String>,
+    pub date_created: Option<model::DateTime>,
+    pub date_done: Option<model::DateTime>,
     #[serde(rename = "type")]
     pub view_type: Option<model::TaskViewType>,
     pub status: Option<model::TaskStatus>,
     pub result: Option<String>,
     pub acknowledged: Option<bool>,
-    pub owner: Option<i32>,
+    pub owner: Option<model::UserId>,
 }
 
 #[must_use]
-pub fn create(task_id: String) -> Create {
+pub fn create(task_id: impl Into<String>) -> Create {
     Create::new(task_id)
 }
 
@@ -43,9 +43,9 @@ impl From<&model::TaskView> for Create {
 
 impl Create {
     #[must_use]
-    pub fn new(task_id: String) -> Self {
+    pub fn new(task_id: impl Into<String>) -> Self {
         Self {
-            task_id,
+            task_id: task_id.into(),
             task_name: None,
             task_file_name: None,
             date_created: None,
@@ -71,13 +71,13 @@ impl Create {
     }
 
     #[must_use]
-    pub fn date_created(mut self, value: String) -> Self {
+    pub fn date_created(mut self, value: model::DateTime) -> Self {
         self.date_created = Some(value);
         self
     }
 
     #[must_use]
-    pub fn date_done(mut self, value: String) -> Self {
+    pub fn date_done(mut self, value: model::DateTime) -> Self {
         self.date_done = Some(value);
         self
     }
@@ -107,7 +107,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn owner(mut self, value: i32) -> Self {
+    pub fn owner(mut self, value: model::UserId) -> Self {
         self.owner = Some(value);
         self
     }
@@ -1,13 +1,24 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::schema::api::Ordering;
 use crate::schema::model;
+use crate::schema::utils::order_by;
+
+order_by! {
+    /// Field by which tasks can be ordered.
+    pub enum OrderBy {
+        Id => "id",
+        DateCreated => "date_created",
+        DateDone => "date_done",
+    }
+}
 
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct List {
     pub acknowledged: Option<bool>,
-    pub ordering: Option<String>,
+    pub ordering: Option<Ordering<OrderBy>>,
     pub status: Option<model::TaskStatus>,
     pub task_name: Option<model::TaskName>,
     pub view_type: Option<model::TaskViewType>,
@@ -31,7 +42,7 @@ impl List {
     }
 
     #[must_use]
-    pub fn ordering(mut self, value: String) -> Self {
+    pub fn ordering(mut self, value: Ordering<OrderBy>) -> Self {
         self.ordering = Some(value);
         self
     }
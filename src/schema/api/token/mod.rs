@@ -0,0 +1,2 @@
+mod obtain;
+pub use obtain::*;
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::schema::model;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Obtain {
+    pub username: String,
+    pub password: model::Secret,
+}
+
+#[must_use]
+pub fn obtain(username: String, password: String) -> Obtain {
+    Obtain::new(username, password)
+}
+
+impl Obtain {
+    #[must_use]
+    pub fn new(username: String, password: String) -> Self {
+        Self {
+            username,
+            password: password.into(),
+        }
+    }
+}
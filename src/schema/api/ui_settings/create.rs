@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+use crate::schema::model;
+
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Create {
+    pub settings: serde_json::Value,
+}
+
+#[must_use]
+pub fn create(settings: serde_json::Value) -> Create {
+    Create::new(settings)
+}
+
+impl From<&model::UiSettings> for Create {
+    fn from(item: &model::UiSettings) -> Self {
+        Self {
+            settings: item.settings.clone(),
+        }
+    }
+}
+
+impl Create {
+    #[must_use]
+    pub fn new(settings: serde_json::Value) -> Self {
+        Self { settings }
+    }
+
+    #[must_use]
+    pub fn settings(mut self, value: serde_json::Value) -> Self {
+        self.settings = value;
+        self
+    }
+}
@@ -0,0 +1,2 @@
+mod create;
+pub use create::*;
@@ -4,23 +4,23 @@ use serde_with::skip_serializing_none;
 use crate::schema::model;
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
     pub username: String,
     pub email: Option<String>,
-    pub password: Option<String>,
+    pub password: Option<model::Secret>,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
-    pub date_joined: Option<String>,
+    pub date_joined: Option<model::DateTime>,
     pub is_staff: Option<bool>,
     pub is_active: Option<bool>,
     pub is_superuser: Option<bool>,
-    pub groups: Option<Vec<i32>>,
+    pub groups: Option<Vec<model::GroupId>>,
     pub user_permissions: Option<Vec<model::PermissionClass>>,
 }
 
 #[must_use]
-pub fn create(username: String) -> Create {
+pub fn create(username: impl Into<String>) -> Create {
     Create::new(username)
 }
 
@@ -44,9 +44,9 @@ impl From<&model::User> for Create {
 
 impl Create {
     #[must_use]
-    pub fn new(username: String) -> Self {
+    pub fn new(username: impl Into<String>) -> Self {
         Self {
-            username,
+            username: username.into(),
             email: None,
             password: None,
             first_name: None,
@@ -68,7 +68,7 @@ impl Create {
 
     #[must_use]
     pub fn password(mut self, value: String) -> Self {
-        self.password = Some(value);
+        self.password = Some(value.into());
         self
     }
 
@@ -85,7 +85,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn date_joined(mut self, value: String) -> Self {
+    pub fn date_joined(mut self, value: model::DateTime) -> Self {
         self.date_joined = Some(value);
         self
     }
@@ -109,7 +109,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn groups(mut self, value: Vec<i32>) -> Self {
+    pub fn groups(mut self, value: Vec<model::GroupId>) -> Self {
         self.groups = Some(value);
         self
     }
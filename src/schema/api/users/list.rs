@@ -1,6 +1,21 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::schema::api::Ordering;
+use crate::schema::utils::order_by;
+
+order_by! {
+    /// Field by which users can be ordered.
+    pub enum OrderBy {
+        Id => "id",
+        Username => "username",
+        FirstName => "first_name",
+        LastName => "last_name",
+        Email => "email",
+        DateJoined => "date_joined",
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct List {
@@ -12,7 +27,7 @@ pub struct List {
     pub username_iexact: Option<String>,
     #[serde(rename = "name__istartswith")]
     pub username_istartswith: Option<String>,
-    pub ordering: Option<String>,
+    pub ordering: Option<Ordering<OrderBy>>,
     pub page: Option<i32>,
     pub page_size: Option<i32>,
 }
@@ -53,7 +68,7 @@ impl List {
     }
 
     #[must_use]
-    pub fn ordering(mut self, value: String) -> Self {
+    pub fn ordering(mut self, value: Ordering<OrderBy>) -> Self {
         self.ordering = Some(value);
         self
     }
@@ -8,14 +8,14 @@ use crate::schema::model;
 pub struct Patch {
     pub username: Option<String>,
     pub email: Option<String>,
-    pub password: Option<String>,
+    pub password: Option<model::Secret>,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
-    pub date_joined: Option<String>,
+    pub date_joined: Option<model::DateTime>,
     pub is_staff: Option<bool>,
     pub is_active: Option<bool>,
     pub is_superuser: Option<bool>,
-    pub groups: Option<Vec<i32>>,
+    pub groups: Option<Vec<model::GroupId>>,
     pub user_permissions: Option<Vec<model::PermissionClass>>,
 }
 
@@ -30,6 +30,46 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(original: &model::User, edited: &model::User) -> Self {
+        let mut patch = Self::new();
+        if original.username != edited.username {
+            patch.username = Some(edited.username.clone());
+        }
+        if original.email != edited.email {
+            patch.email = Some(edited.email.clone().unwrap_or_default());
+        }
+        if original.password != edited.password {
+            patch.password.clone_from(&edited.password);
+        }
+        if original.first_name != edited.first_name {
+            patch.first_name = Some(edited.first_name.clone().unwrap_or_default());
+        }
+        if original.last_name != edited.last_name {
+            patch.last_name = Some(edited.last_name.clone().unwrap_or_default());
+        }
+        if original.date_joined != edited.date_joined {
+            patch.date_joined.clone_from(&edited.date_joined);
+        }
+        if original.is_staff != edited.is_staff {
+            patch.is_staff = edited.is_staff;
+        }
+        if original.is_active != edited.is_active {
+            patch.is_active = edited.is_active;
+        }
+        if original.is_superuser != edited.is_superuser {
+            patch.is_superuser = edited.is_superuser;
+        }
+        if original.groups != edited.groups {
+            patch.groups.clone_from(&edited.groups);
+        }
+        if original.user_permissions != edited.user_permissions {
+            patch.user_permissions = Some(edited.user_permissions.clone());
+        }
+        patch
+    }
+
     #[must_use]
     pub fn username(mut self, value: String) -> Self {
         self.username = Some(value);
@@ -44,7 +84,7 @@ impl Patch {
 
     #[must_use]
     pub fn password(mut self, value: String) -> Self {
-        self.password = Some(value);
+        self.password = Some(value.into());
         self
     }
 
@@ -61,7 +101,7 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn date_joined(mut self, value: String) -> Self {
+    pub fn date_joined(mut self, value: model::DateTime) -> Self {
         self.date_joined = Some(value);
         self
     }
@@ -85,7 +125,7 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn groups(mut self, value: Vec<i32>) -> Self {
+    pub fn groups(mut self, value: Vec<model::GroupId>) -> Self {
         self.groups = Some(value);
         self
     }
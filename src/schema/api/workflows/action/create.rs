@@ -11,34 +11,34 @@ pub struct Create {
     #[serde(rename = "type")]
     pub action_type: Option<model::WorkflowActionType>,
     pub assign_title: Option<String>,
-    pub assign_tags: Option<Vec<i32>>,
-    pub assign_correspondent: Option<i32>,
-    pub assign_document_type: Option<i32>,
-    pub assign_storage_path: Option<i32>,
-    pub assign_owner: Option<i32>,
-    pub assign_view_users: Option<Vec<i32>>,
-    pub assign_view_groups: Option<Vec<i32>>,
-    pub assign_change_users: Option<Vec<i32>>,
-    pub assign_change_groups: Option<Vec<i32>>,
-    pub assign_custom_fields: Option<Vec<i32>>,
+    pub assign_tags: Option<Vec<model::TagId>>,
+    pub assign_correspondent: Option<model::CorrespondentId>,
+    pub assign_document_type: Option<model::DocumentTypeId>,
+    pub assign_storage_path: Option<model::StoragePathId>,
+    pub assign_owner: Option<model::UserId>,
+    pub assign_view_users: Option<Vec<model::UserId>>,
+    pub assign_view_groups: Option<Vec<model::GroupId>>,
+    pub assign_change_users: Option<Vec<model::UserId>>,
+    pub assign_change_groups: Option<Vec<model::GroupId>>,
+    pub assign_custom_fields: Option<Vec<model::CustomFieldId>>,
     pub assign_custom_fields_values: Option<serde_json::Value>,
     pub remove_all_tags: Option<bool>,
-    pub remove_tags: Option<Vec<i32>>,
+    pub remove_tags: Option<Vec<model::TagId>>,
     pub remove_all_correspondents: Option<bool>,
-    pub remove_correspondents: Option<Vec<i32>>,
+    pub remove_correspondents: Option<Vec<model::CorrespondentId>>,
     pub remove_all_document_types: Option<bool>,
-    pub remove_document_types: Option<Vec<i32>>,
+    pub remove_document_types: Option<Vec<model::DocumentTypeId>>,
     pub remove_all_storage_paths: Option<bool>,
-    pub remove_storage_paths: Option<Vec<i32>>,
-    pub remove_custom_fields: Option<Vec<i32>>,
+    pub remove_storage_paths: Option<Vec<model::StoragePathId>>,
+    pub remove_custom_fields: Option<Vec<model::CustomFieldId>>,
     pub remove_all_custom_fields: Option<bool>,
     pub remove_all_owners: Option<bool>,
-    pub remove_owners: Option<Vec<i32>>,
+    pub remove_owners: Option<Vec<model::UserId>>,
     pub remove_all_permissions: Option<bool>,
-    pub remove_view_users: Option<Vec<i32>>,
-    pub remove_view_groups: Option<Vec<i32>>,
-    pub remove_change_users: Option<Vec<i32>>,
-    pub remove_change_groups: Option<Vec<i32>>,
+    pub remove_view_users: Option<Vec<model::UserId>>,
+    pub remove_view_groups: Option<Vec<model::GroupId>>,
+    pub remove_change_users: Option<Vec<model::UserId>>,
+    pub remove_change_groups: Option<Vec<model::GroupId>>,
     pub email: Option<email::Create>,
     pub webhook: Option<webhook::Create>,
 }
@@ -106,61 +106,61 @@ impl Create {
     }
 
     #[must_use]
-    pub fn assign_tags(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_tags(mut self, value: Vec<model::TagId>) -> Self {
         self.assign_tags = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_correspondent(mut self, value: i32) -> Self {
+    pub fn assign_correspondent(mut self, value: model::CorrespondentId) -> Self {
         self.assign_correspondent = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_document_type(mut self, value: i32) -> Self {
+    pub fn assign_document_type(mut self, value: model::DocumentTypeId) -> Self {
         self.assign_document_type = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_storage_path(mut self, value: i32) -> Self {
+    pub fn assign_storage_path(mut self, value: model::StoragePathId) -> Self {
         self.assign_storage_path = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_owner(mut self, value: i32) -> Self {
+    pub fn assign_owner(mut self, value: model::UserId) -> Self {
         self.assign_owner = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_view_users(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_view_users(mut self, value: Vec<model::UserId>) -> Self {
         self.assign_view_users = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_view_groups(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_view_groups(mut self, value: Vec<model::GroupId>) -> Self {
         self.assign_view_groups = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_change_users(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_change_users(mut self, value: Vec<model::UserId>) -> Self {
         self.assign_change_users = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_change_groups(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_change_groups(mut self, value: Vec<model::GroupId>) -> Self {
         self.assign_change_groups = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_custom_fields(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_custom_fields(mut self, value: Vec<model::CustomFieldId>) -> Self {
         self.assign_custom_fields = Some(value);
         self
     }
@@ -178,7 +178,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn remove_tags(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_tags(mut self, value: Vec<model::TagId>) -> Self {
         self.remove_tags = Some(value);
         self
     }
@@ -190,7 +190,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn remove_correspondents(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_correspondents(mut self, value: Vec<model::CorrespondentId>) -> Self {
         self.remove_correspondents = Some(value);
         self
     }
@@ -202,7 +202,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn remove_document_types(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_document_types(mut self, value: Vec<model::DocumentTypeId>) -> Self {
         self.remove_document_types = Some(value);
         self
     }
@@ -214,13 +214,13 @@ impl Create {
     }
 
     #[must_use]
-    pub fn remove_storage_paths(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_storage_paths(mut self, value: Vec<model::StoragePathId>) -> Self {
         self.remove_storage_paths = Some(value);
         self
     }
 
     #[must_use]
-    pub fn remove_custom_fields(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_custom_fields(mut self, value: Vec<model::CustomFieldId>) -> Self {
         self.remove_custom_fields = Some(value);
         self
     }
@@ -238,7 +238,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn remove_owners(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_owners(mut self, value: Vec<model::UserId>) -> Self {
         self.remove_owners = Some(value);
         self
     }
@@ -250,25 +250,25 @@ impl Create {
     }
 
     #[must_use]
-    pub fn remove_view_users(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_view_users(mut self, value: Vec<model::UserId>) -> Self {
         self.remove_view_users = Some(value);
         self
     }
 
     #[must_use]
-    pub fn remove_view_groups(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_view_groups(mut self, value: Vec<model::GroupId>) -> Self {
         self.remove_view_groups = Some(value);
         self
     }
 
     #[must_use]
-    pub fn remove_change_users(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_change_users(mut self, value: Vec<model::UserId>) -> Self {
         self.remove_change_users = Some(value);
         self
     }
 
     #[must_use]
-    pub fn remove_change_groups(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_change_groups(mut self, value: Vec<model::GroupId>) -> Self {
         self.remove_change_groups = Some(value);
         self
     }
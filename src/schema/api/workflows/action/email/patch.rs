@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::schema::model;
+
 #[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Patch {
@@ -15,6 +17,17 @@ pub fn patch(subject: String, body: String, to: String) -> Patch {
     Patch::new(subject, body, to)
 }
 
+impl From<&model::WorkflowActionEmail> for Patch {
+    fn from(item: &model::WorkflowActionEmail) -> Self {
+        Self {
+            subject: item.subject.clone(),
+            body: item.body.clone(),
+            to: item.to.clone(),
+            include_document: item.include_document,
+        }
+    }
+}
+
 impl Patch {
     #[must_use]
     pub fn new(subject: String, body: String, to: String) -> Self {
@@ -26,6 +39,23 @@ impl Patch {
         }
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(
+        original: &model::WorkflowActionEmail,
+        edited: &model::WorkflowActionEmail,
+    ) -> Self {
+        let mut patch = Self::new(
+            edited.subject.clone(),
+            edited.body.clone(),
+            edited.to.clone(),
+        );
+        if original.include_document != edited.include_document {
+            patch.include_document = edited.include_document;
+        }
+        patch
+    }
+
     #[must_use]
     pub fn include_document(mut self, value: bool) -> Self {
         self.include_document = Some(value);
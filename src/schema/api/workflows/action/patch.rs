@@ -10,35 +10,40 @@ use crate::schema::model;
 pub struct Patch {
     #[serde(rename = "type")]
     pub action_type: Option<model::WorkflowActionType>,
-    pub assign_title: Option<String>,
-    pub assign_tags: Option<Vec<i32>>,
-    pub assign_correspondent: Option<i32>,
-    pub assign_document_type: Option<i32>,
-    pub assign_storage_path: Option<i32>,
-    pub assign_owner: Option<i32>,
-    pub assign_view_users: Option<Vec<i32>>,
-    pub assign_view_groups: Option<Vec<i32>>,
-    pub assign_change_users: Option<Vec<i32>>,
-    pub assign_change_groups: Option<Vec<i32>>,
-    pub assign_custom_fields: Option<Vec<i32>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub assign_title: Option<Option<String>>,
+    pub assign_tags: Option<Vec<model::TagId>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub assign_correspondent: Option<Option<model::CorrespondentId>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub assign_document_type: Option<Option<model::DocumentTypeId>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub assign_storage_path: Option<Option<model::StoragePathId>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub assign_owner: Option<Option<model::UserId>>,
+    pub assign_view_users: Option<Vec<model::UserId>>,
+    pub assign_view_groups: Option<Vec<model::GroupId>>,
+    pub assign_change_users: Option<Vec<model::UserId>>,
+    pub assign_change_groups: Option<Vec<model::GroupId>>,
+    pub assign_custom_fields: Option<Vec<model::CustomFieldId>>,
     pub assign_custom_fields_values: Option<serde_json::Value>,
     pub remove_all_tags: Option<bool>,
-    pub remove_tags: Option<Vec<i32>>,
+    pub remove_tags: Option<Vec<model::TagId>>,
     pub remove_all_correspondents: Option<bool>,
-    pub remove_correspondents: Option<Vec<i32>>,
+    pub remove_correspondents: Option<Vec<model::CorrespondentId>>,
     pub remove_all_document_types: Option<bool>,
-    pub remove_document_types: Option<Vec<i32>>,
+    pub remove_document_types: Option<Vec<model::DocumentTypeId>>,
     pub remove_all_storage_paths: Option<bool>,
-    pub remove_storage_paths: Option<Vec<i32>>,
-    pub remove_custom_fields: Option<Vec<i32>>,
+    pub remove_storage_paths: Option<Vec<model::StoragePathId>>,
+    pub remove_custom_fields: Option<Vec<model::CustomFieldId>>,
     pub remove_all_custom_fields: Option<bool>,
     pub remove_all_owners: Option<bool>,
-    pub remove_owners: Option<Vec<i32>>,
+    pub remove_owners: Option<Vec<model::UserId>>,
     pub remove_all_permissions: Option<bool>,
-    pub remove_view_users: Option<Vec<i32>>,
-    pub remove_view_groups: Option<Vec<i32>>,
-    pub remove_change_users: Option<Vec<i32>>,
-    pub remove_change_groups: Option<Vec<i32>>,
+    pub remove_view_users: Option<Vec<model::UserId>>,
+    pub remove_view_groups: Option<Vec<model::GroupId>>,
+    pub remove_change_users: Option<Vec<model::UserId>>,
+    pub remove_change_groups: Option<Vec<model::GroupId>>,
     pub email: Option<email::Patch>,
     pub webhook: Option<webhook::Patch>,
 }
@@ -54,6 +59,147 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn diff(original: &model::WorkflowAction, edited: &model::WorkflowAction) -> Self {
+        let mut patch = Self::new();
+        if original.action_type != edited.action_type {
+            patch.action_type = edited.action_type;
+        }
+        if original.assign_title != edited.assign_title {
+            patch.assign_title = Some(edited.assign_title.clone());
+        }
+        if original.assign_tags != edited.assign_tags {
+            patch.assign_tags.clone_from(&edited.assign_tags);
+        }
+        if original.assign_correspondent != edited.assign_correspondent {
+            patch.assign_correspondent = Some(edited.assign_correspondent);
+        }
+        if original.assign_document_type != edited.assign_document_type {
+            patch.assign_document_type = Some(edited.assign_document_type);
+        }
+        if original.assign_storage_path != edited.assign_storage_path {
+            patch.assign_storage_path = Some(edited.assign_storage_path);
+        }
+        if original.assign_owner != edited.assign_owner {
+            patch.assign_owner = Some(edited.assign_owner);
+        }
+        if original.assign_view_users != edited.assign_view_users {
+            patch
+                .assign_view_users
+                .clone_from(&edited.assign_view_users);
+        }
+        if original.assign_view_groups != edited.assign_view_groups {
+            patch
+                .assign_view_groups
+                .clone_from(&edited.assign_view_groups);
+        }
+        if original.assign_change_users != edited.assign_change_users {
+            patch
+                .assign_change_users
+                .clone_from(&edited.assign_change_users);
+        }
+        if original.assign_change_groups != edited.assign_change_groups {
+            patch
+                .assign_change_groups
+                .clone_from(&edited.assign_change_groups);
+        }
+        if original.assign_custom_fields != edited.assign_custom_fields {
+            patch
+                .assign_custom_fields
+                .clone_from(&edited.assign_custom_fields);
+        }
+        if original.assign_custom_fields_values != edited.assign_custom_fields_values {
+            patch
+                .assign_custom_fields_values
+                .clone_from(&edited.assign_custom_fields_values);
+        }
+        if original.remove_all_tags != edited.remove_all_tags {
+            patch.remove_all_tags = edited.remove_all_tags;
+        }
+        if original.remove_tags != edited.remove_tags {
+            patch.remove_tags.clone_from(&edited.remove_tags);
+        }
+        if original.remove_all_correspondents != edited.remove_all_correspondents {
+            patch.remove_all_correspondents = edited.remove_all_correspondents;
+        }
+        if original.remove_correspondents != edited.remove_correspondents {
+            patch
+                .remove_correspondents
+                .clone_from(&edited.remove_correspondents);
+        }
+        if original.remove_all_document_types != edited.remove_all_document_types {
+            patch.remove_all_document_types = edited.remove_all_document_types;
+        }
+        if original.remove_document_types != edited.remove_document_types {
+            patch
+                .remove_document_types
+                .clone_from(&edited.remove_document_types);
+        }
+        if original.remove_all_storage_paths != edited.remove_all_storage_paths {
+            patch.remove_all_storage_paths = edited.remove_all_storage_paths;
+        }
+        if original.remove_storage_paths != edited.remove_storage_paths {
+            patch
+                .remove_storage_paths
+                .clone_from(&edited.remove_storage_paths);
+        }
+        if original.remove_custom_fields != edited.remove_custom_fields {
+            patch
+                .remove_custom_fields
+                .clone_from(&edited.remove_custom_fields);
+        }
+        if original.remove_all_custom_fields != edited.remove_all_custom_fields {
+            patch.remove_all_custom_fields = edited.remove_all_custom_fields;
+        }
+        if original.remove_all_owners != edited.remove_all_owners {
+            patch.remove_all_owners = edited.remove_all_owners;
+        }
+        if original.remove_owners != edited.remove_owners {
+            patch.remove_owners.clone_from(&edited.remove_owners);
+        }
+        if original.remove_all_permissions != edited.remove_all_permissions {
+            patch.remove_all_permissions = edited.remove_all_permissions;
+        }
+        if original.remove_view_users != edited.remove_view_users {
+            patch
+                .remove_view_users
+                .clone_from(&edited.remove_view_users);
+        }
+        if original.remove_view_groups != edited.remove_view_groups {
+            patch
+                .remove_view_groups
+                .clone_from(&edited.remove_view_groups);
+        }
+        if original.remove_change_users != edited.remove_change_users {
+            patch
+                .remove_change_users
+                .clone_from(&edited.remove_change_users);
+        }
+        if original.remove_change_groups != edited.remove_change_groups {
+            patch
+                .remove_change_groups
+                .clone_from(&edited.remove_change_groups);
+        }
+        if original.email != edited.email {
+            patch.email = edited.email.as_ref().map(|email| match &original.email {
+                Some(original) => email::Patch::diff(original, email),
+                None => email::Patch::from(email),
+            });
+        }
+        if original.webhook != edited.webhook {
+            patch.webhook = edited
+                .webhook
+                .as_ref()
+                .map(|webhook| match &original.webhook {
+                    Some(original) => webhook::Patch::diff(original, webhook),
+                    None => webhook::Patch::from(webhook),
+                });
+        }
+        patch
+    }
+
     #[must_use]
     pub fn action_type(mut self, value: model::WorkflowActionType) -> Self {
         self.action_type = Some(value);
@@ -61,67 +207,70 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn assign_title(mut self, value: String) -> Self {
-        self.assign_title = Some(value);
+    pub fn assign_title(mut self, value: impl Into<Option<String>>) -> Self {
+        self.assign_title = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn assign_tags(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_tags(mut self, value: Vec<model::TagId>) -> Self {
         self.assign_tags = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_correspondent(mut self, value: i32) -> Self {
-        self.assign_correspondent = Some(value);
+    pub fn assign_correspondent(
+        mut self,
+        value: impl Into<Option<model::CorrespondentId>>,
+    ) -> Self {
+        self.assign_correspondent = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn assign_document_type(mut self, value: i32) -> Self {
-        self.assign_document_type = Some(value);
+    pub fn assign_document_type(mut self, value: impl Into<Option<model::DocumentTypeId>>) -> Self {
+        self.assign_document_type = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn assign_storage_path(mut self, value: i32) -> Self {
-        self.assign_storage_path = Some(value);
+    pub fn assign_storage_path(mut self, value: impl Into<Option<model::StoragePathId>>) -> Self {
+        self.assign_storage_path = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn assign_owner(mut self, value: i32) -> Self {
-        self.assign_owner = Some(value);
+    pub fn assign_owner(mut self, value: impl Into<Option<model::UserId>>) -> Self {
+        self.assign_owner = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn assign_view_users(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_view_users(mut self, value: Vec<model::UserId>) -> Self {
         self.assign_view_users = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_view_groups(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_view_groups(mut self, value: Vec<model::GroupId>) -> Self {
         self.assign_view_groups = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_change_users(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_change_users(mut self, value: Vec<model::UserId>) -> Self {
         self.assign_change_users = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_change_groups(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_change_groups(mut self, value: Vec<model::GroupId>) -> Self {
         self.assign_change_groups = Some(value);
         self
     }
 
     #[must_use]
-    pub fn assign_custom_fields(mut self, value: Vec<i32>) -> Self {
+    pub fn assign_custom_fields(mut self, value: Vec<model::CustomFieldId>) -> Self {
         self.assign_custom_fields = Some(value);
         self
     }
@@ -139,7 +288,7 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn remove_tags(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_tags(mut self, value: Vec<model::TagId>) -> Self {
         self.remove_tags = Some(value);
         self
     }
@@ -151,7 +300,7 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn remove_correspondents(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_correspondents(mut self, value: Vec<model::CorrespondentId>) -> Self {
         self.remove_correspondents = Some(value);
         self
     }
@@ -163,7 +312,7 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn remove_document_types(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_document_types(mut self, value: Vec<model::DocumentTypeId>) -> Self {
         self.remove_document_types = Some(value);
         self
     }
@@ -175,13 +324,13 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn remove_storage_paths(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_storage_paths(mut self, value: Vec<model::StoragePathId>) -> Self {
         self.remove_storage_paths = Some(value);
         self
     }
 
     #[must_use]
-    pub fn remove_custom_fields(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_custom_fields(mut self, value: Vec<model::CustomFieldId>) -> Self {
         self.remove_custom_fields = Some(value);
         self
     }
@@ -199,7 +348,7 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn remove_owners(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_owners(mut self, value: Vec<model::UserId>) -> Self {
         self.remove_owners = Some(value);
         self
     }
@@ -211,25 +360,25 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn remove_view_users(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_view_users(mut self, value: Vec<model::UserId>) -> Self {
         self.remove_view_users = Some(value);
         self
     }
 
     #[must_use]
-    pub fn remove_view_groups(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_view_groups(mut self, value: Vec<model::GroupId>) -> Self {
         self.remove_view_groups = Some(value);
         self
     }
 
     #[must_use]
-    pub fn remove_change_users(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_change_users(mut self, value: Vec<model::UserId>) -> Self {
         self.remove_change_users = Some(value);
         self
     }
 
     #[must_use]
-    pub fn remove_change_groups(mut self, value: Vec<i32>) -> Self {
+    pub fn remove_change_groups(mut self, value: Vec<model::GroupId>) -> Self {
         self.remove_change_groups = Some(value);
         self
     }
@@ -1,12 +1,13 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use url::Url;
 
 use crate::schema::model;
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
-    pub url: String,
+    pub url: Url,
     pub use_params: Option<bool>,
     pub as_json: Option<bool>,
     pub params: Option<serde_json::Value>,
@@ -16,7 +17,7 @@ pub struct Create {
 }
 
 #[must_use]
-pub fn create(url: String) -> Create {
+pub fn create(url: Url) -> Create {
     Create::new(url)
 }
 
@@ -36,7 +37,7 @@ impl From<&model::WorkflowActionWebhook> for Create {
 
 impl Create {
     #[must_use]
-    pub fn new(url: String) -> Self {
+    pub fn new(url: Url) -> Self {
         Self {
             url,
             use_params: None,
@@ -1,26 +1,46 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use url::Url;
+
+use crate::schema::model;
 
 #[skip_serializing_none]
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Patch {
-    pub url: String,
+    pub url: Url,
     pub use_params: Option<bool>,
     pub as_json: Option<bool>,
-    pub params: Option<serde_json::Value>,
-    pub body: Option<String>,
-    pub headers: Option<serde_json::Value>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub params: Option<Option<serde_json::Value>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub body: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub headers: Option<Option<serde_json::Value>>,
     pub include_document: Option<bool>,
 }
 
 #[must_use]
-pub fn patch(url: String) -> Patch {
+pub fn patch(url: Url) -> Patch {
     Patch::new(url)
 }
 
+impl From<&model::WorkflowActionWebhook> for Patch {
+    fn from(item: &model::WorkflowActionWebhook) -> Self {
+        Self {
+            url: item.url.clone(),
+            use_params: item.use_params,
+            as_json: item.as_json,
+            params: item.params.clone().map(Some),
+            body: item.body.clone().map(Some),
+            headers: item.headers.clone().map(Some),
+            include_document: item.include_document,
+        }
+    }
+}
+
 impl Patch {
     #[must_use]
-    pub fn new(url: String) -> Self {
+    pub fn new(url: Url) -> Self {
         Self {
             url,
             use_params: None,
@@ -32,6 +52,34 @@ impl Patch {
         }
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(
+        original: &model::WorkflowActionWebhook,
+        edited: &model::WorkflowActionWebhook,
+    ) -> Self {
+        let mut patch = Self::new(edited.url.clone());
+        if original.use_params != edited.use_params {
+            patch.use_params = edited.use_params;
+        }
+        if original.as_json != edited.as_json {
+            patch.as_json = edited.as_json;
+        }
+        if original.params != edited.params {
+            patch.params = Some(edited.params.clone());
+        }
+        if original.body != edited.body {
+            patch.body = Some(edited.body.clone());
+        }
+        if original.headers != edited.headers {
+            patch.headers = Some(edited.headers.clone());
+        }
+        if original.include_document != edited.include_document {
+            patch.include_document = edited.include_document;
+        }
+        patch
+    }
+
     #[must_use]
     pub fn use_params(mut self, value: bool) -> Self {
         self.use_params = Some(value);
@@ -45,20 +93,20 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn params(mut self, value: serde_json::Value) -> Self {
-        self.params = Some(value);
+    pub fn params(mut self, value: impl Into<Option<serde_json::Value>>) -> Self {
+        self.params = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn body(mut self, value: String) -> Self {
-        self.body = Some(value);
+    pub fn body(mut self, value: impl Into<Option<String>>) -> Self {
+        self.body = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn headers(mut self, value: serde_json::Value) -> Self {
-        self.headers = Some(value);
+    pub fn headers(mut self, value: impl Into<Option<serde_json::Value>>) -> Self {
+        self.headers = Some(value.into());
         self
     }
 
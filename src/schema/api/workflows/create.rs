@@ -6,7 +6,7 @@ use super::trigger;
 use crate::schema::model;
 
 #[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Create {
     pub name: String,
     pub triggers: Vec<trigger::Create>,
@@ -16,7 +16,7 @@ pub struct Create {
 }
 
 #[must_use]
-pub fn create(name: String) -> Create {
+pub fn create(name: impl Into<String>) -> Create {
     Create::new(name)
 }
 
@@ -34,9 +34,9 @@ impl From<&model::Workflow> for Create {
 
 impl Create {
     #[must_use]
-    pub fn new(name: String) -> Self {
+    pub fn new(name: impl Into<String>) -> Self {
         Self {
-            name,
+            name: name.into(),
             order: None,
             enabled: None,
             triggers: Vec::new(),
@@ -1,9 +1,22 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+use crate::schema::api::Ordering;
+use crate::schema::utils::order_by;
+
+order_by! {
+    /// Field by which workflows can be ordered.
+    pub enum OrderBy {
+        Id => "id",
+        Name => "name",
+        Order => "order",
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct List {
+    pub ordering: Option<Ordering<OrderBy>>,
     pub page: Option<i32>,
     pub page_size: Option<i32>,
 }
@@ -19,6 +32,12 @@ impl List {
         Self::default()
     }
 
+    #[must_use]
+    pub fn ordering(mut self, value: Ordering<OrderBy>) -> Self {
+        self.ordering = Some(value);
+        self
+    }
+
     #[must_use]
     pub fn page(mut self, value: i32) -> Self {
         self.page = Some(value);
@@ -11,19 +11,19 @@ pub struct Create {
     pub sources: Option<Vec<model::Source>>,
     pub filter_path: Option<String>,
     pub filter_filename: Option<String>,
-    pub filter_mailrule: Option<i32>,
+    pub filter_mailrule: Option<model::MailRuleId>,
     pub matching_algorithm: Option<model::WorkflowTriggerMatchingAlgorithm>,
     #[serde(rename = "match")]
     pub matches: Option<String>,
     pub is_insensitive: Option<bool>,
-    pub filter_has_tags: Option<Vec<i32>>,
-    pub filter_has_correspondent: Option<i32>,
-    pub filter_has_document_type: Option<i32>,
+    pub filter_has_tags: Option<Vec<model::TagId>>,
+    pub filter_has_correspondent: Option<model::CorrespondentId>,
+    pub filter_has_document_type: Option<model::DocumentTypeId>,
     pub schedule_offset_days: Option<i64>,
     pub schedule_is_recurring: Option<bool>,
     pub schedule_recurring_interval_days: Option<i64>,
     pub schedule_date_field: Option<model::ScheduleDateField>,
-    pub schedule_date_custom_field: Option<i32>,
+    pub schedule_date_custom_field: Option<model::CustomFieldId>,
 }
 
 #[must_use]
@@ -54,6 +54,14 @@ impl From<&model::WorkflowTrigger> for Create {
     }
 }
 
+/// A trigger on the start of the consumption of a document, the default of
+/// the server.
+impl Default for Create {
+    fn default() -> Self {
+        Self::new(model::WorkflowTriggerType::ConsumptionStarted)
+    }
+}
+
 impl Create {
     #[must_use]
     pub fn new(trigger_type: model::WorkflowTriggerType) -> Self {
@@ -96,7 +104,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn filter_mailrule(mut self, value: i32) -> Self {
+    pub fn filter_mailrule(mut self, value: model::MailRuleId) -> Self {
         self.filter_mailrule = Some(value);
         self
     }
@@ -120,19 +128,19 @@ impl Create {
     }
 
     #[must_use]
-    pub fn filter_has_tags(mut self, value: Vec<i32>) -> Self {
+    pub fn filter_has_tags(mut self, value: Vec<model::TagId>) -> Self {
         self.filter_has_tags = Some(value);
         self
     }
 
     #[must_use]
-    pub fn filter_has_correspondent(mut self, value: i32) -> Self {
+    pub fn filter_has_correspondent(mut self, value: model::CorrespondentId) -> Self {
         self.filter_has_correspondent = Some(value);
         self
     }
 
     #[must_use]
-    pub fn filter_has_document_type(mut self, value: i32) -> Self {
+    pub fn filter_has_document_type(mut self, value: model::DocumentTypeId) -> Self {
         self.filter_has_document_type = Some(value);
         self
     }
@@ -162,7 +170,7 @@ impl Create {
     }
 
     #[must_use]
-    pub fn schedule_date_custom_field(mut self, value: i32) -> Self {
+    pub fn schedule_date_custom_field(mut self, value: model::CustomFieldId) -> Self {
         self.schedule_date_custom_field = Some(value);
         self
     }
@@ -9,21 +9,27 @@ pub struct Patch {
     #[serde(rename = "type")]
     pub trigger_type: Option<model::WorkflowTriggerType>,
     pub sources: Option<Vec<model::Source>>,
-    pub filter_path: Option<String>,
-    pub filter_filename: Option<String>,
-    pub filter_mailrule: Option<i32>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub filter_path: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub filter_filename: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub filter_mailrule: Option<Option<model::MailRuleId>>,
     pub matching_algorithm: Option<model::WorkflowTriggerMatchingAlgorithm>,
     #[serde(rename = "match")]
     pub matches: Option<String>,
     pub is_insensitive: Option<bool>,
-    pub filter_has_tags: Option<Vec<i32>>,
-    pub filter_has_correspondent: Option<i32>,
-    pub filter_has_document_type: Option<i32>,
+    pub filter_has_tags: Option<Vec<model::TagId>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub filter_has_correspondent: Option<Option<model::CorrespondentId>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub filter_has_document_type: Option<Option<model::DocumentTypeId>>,
     pub schedule_offset_days: Option<i64>,
     pub schedule_is_recurring: Option<bool>,
     pub schedule_recurring_interval_days: Option<i64>,
     pub schedule_date_field: Option<model::ScheduleDateField>,
-    pub schedule_date_custom_field: Option<i32>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    pub schedule_date_custom_field: Option<Option<model::CustomFieldId>>,
 }
 
 #[must_use]
@@ -37,6 +43,61 @@ impl Patch {
         Self::default()
     }
 
+    /// Patch of the fields that differ between `original` and `edited`.
+    #[must_use]
+    pub fn diff(original: &model::WorkflowTrigger, edited: &model::WorkflowTrigger) -> Self {
+        let mut patch = Self::new();
+        if original.trigger_type != edited.trigger_type {
+            patch.trigger_type = Some(edited.trigger_type);
+        }
+        if original.sources != edited.sources {
+            patch.sources.clone_from(&edited.sources);
+        }
+        if original.filter_path != edited.filter_path {
+            patch.filter_path = Some(edited.filter_path.clone());
+        }
+        if original.filter_filename != edited.filter_filename {
+            patch.filter_filename = Some(edited.filter_filename.clone());
+        }
+        if original.filter_mailrule != edited.filter_mailrule {
+            patch.filter_mailrule = Some(edited.filter_mailrule);
+        }
+        if original.matching_algorithm != edited.matching_algorithm {
+            patch.matching_algorithm = edited.matching_algorithm;
+        }
+        if original.matches != edited.matches {
+            patch.matches = Some(edited.matches.clone().unwrap_or_default());
+        }
+        if original.is_insensitive != edited.is_insensitive {
+            patch.is_insensitive = edited.is_insensitive;
+        }
+        if original.filter_has_tags != edited.filter_has_tags {
+            patch.filter_has_tags.clone_from(&edited.filter_has_tags);
+        }
+        if original.filter_has_correspondent != edited.filter_has_correspondent {
+            patch.filter_has_correspondent = Some(edited.filter_has_correspondent);
+        }
+        if original.filter_has_document_type != edited.filter_has_document_type {
+            patch.filter_has_document_type = Some(edited.filter_has_document_type);
+        }
+        if original.schedule_offset_days != edited.schedule_offset_days {
+            patch.schedule_offset_days = edited.schedule_offset_days;
+        }
+        if original.schedule_is_recurring != edited.schedule_is_recurring {
+            patch.schedule_is_recurring = edited.schedule_is_recurring;
+        }
+        if original.schedule_recurring_interval_days != edited.schedule_recurring_interval_days {
+            patch.schedule_recurring_interval_days = edited.schedule_recurring_interval_days;
+        }
+        if original.schedule_date_field != edited.schedule_date_field {
+            patch.schedule_date_field = edited.schedule_date_field;
+        }
+        if original.schedule_date_custom_field != edited.schedule_date_custom_field {
+            patch.schedule_date_custom_field = Some(edited.schedule_date_custom_field);
+        }
+        patch
+    }
+
     #[must_use]
     pub fn trigger_type(mut self, value: model::WorkflowTriggerType) -> Self {
         self.trigger_type = Some(value);
@@ -50,20 +111,20 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn filter_path(mut self, value: String) -> Self {
-        self.filter_path = Some(value);
+    pub fn filter_path(mut self, value: impl Into<Option<String>>) -> Self {
+        self.filter_path = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn filter_filename(mut self, value: String) -> Self {
-        self.filter_filename = Some(value);
+    pub fn filter_filename(mut self, value: impl Into<Option<String>>) -> Self {
+        self.filter_filename = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn filter_mailrule(mut self, value: i32) -> Self {
-        self.filter_mailrule = Some(value);
+    pub fn filter_mailrule(mut self, value: impl Into<Option<model::MailRuleId>>) -> Self {
+        self.filter_mailrule = Some(value.into());
         self
     }
 
@@ -86,20 +147,26 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn filter_has_tags(mut self, value: Vec<i32>) -> Self {
+    pub fn filter_has_tags(mut self, value: Vec<model::TagId>) -> Self {
         self.filter_has_tags = Some(value);
         self
     }
 
     #[must_use]
-    pub fn filter_has_correspondent(mut self, value: i32) -> Self {
-        self.filter_has_correspondent = Some(value);
+    pub fn filter_has_correspondent(
+        mut self,
+        value: impl Into<Option<model::CorrespondentId>>,
+    ) -> Self {
+        self.filter_has_correspondent = Some(value.into());
         self
     }
 
     #[must_use]
-    pub fn filter_has_document_type(mut self, value: i32) -> Self {
-        self.filter_has_document_type = Some(value);
+    pub fn filter_has_document_type(
+        mut self,
+        value: impl Into<Option<model::DocumentTypeId>>,
+    ) -> Self {
+        self.filter_has_document_type = Some(value.into());
         self
     }
 
@@ -128,8 +195,11 @@ impl Patch {
     }
 
     #[must_use]
-    pub fn schedule_date_custom_field(mut self, value: i32) -> Self {
-        self.schedule_date_custom_field = Some(value);
+    pub fn schedule_date_custom_field(
+        mut self,
+        value: impl Into<Option<model::CustomFieldId>>,
+    ) -> Self {
+        self.schedule_date_custom_field = Some(value.into());
         self
     }
 }
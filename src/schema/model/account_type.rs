@@ -1,11 +1,9 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::schema::utils::repr_enum;
 
-#[repr(u8)]
-#[derive(
-    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize_repr, Deserialize_repr,
-)]
-pub enum AccountType {
-    Imap = 1,
-    GMailOAuth = 2,
-    OutlookOAuth = 3,
+repr_enum! {
+    pub enum AccountType: u8 {
+        Imap = 1,
+        GMailOAuth = 2,
+        OutlookOAuth = 3,
+    }
 }
@@ -1,9 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-#[readonly::make]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Actor {
-    #[readonly]
-    pub id: i32,
-    pub username: String,
+model! {
+    #[readonly::make]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Actor {
+        #[readonly]
+        pub id: super::UserId,
+        pub username: String,
+    }
 }
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+/// Archive serial number of a document: the number written on its paper
+/// original, so that it can be found back in the archive. Labels usually
+/// print it zero-padded, such as `ASN00042`, which is also accepted when
+/// parsing.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Asn(pub u32);
+
+impl Asn {
+    /// Prefix of the archive serial numbers in barcodes.
+    pub const PREFIX: &str = "ASN";
+
+    #[must_use]
+    pub const fn new(value: u32) -> Self {
+        Self(value)
+    }
+
+    #[must_use]
+    pub const fn value(self) -> u32 {
+        self.0
+    }
+
+    /// Following number; `None` on overflow.
+    #[must_use]
+    pub fn next(self) -> Option<Self> {
+        self.0.checked_add(1).map(Self)
+    }
+
+    /// Number padded with zeros to the given width, such as `00042`.
+    #[must_use]
+    pub fn padded(self, width: usize) -> String {
+        format!("{:0width$}", self.0)
+    }
+
+    /// Text of the barcode of the number, such as `ASN00042`.
+    #[must_use]
+    pub fn label(self, width: usize) -> String {
+        format!("{}{}", Self::PREFIX, self.padded(width))
+    }
+}
+
+impl fmt::Display for Asn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Asn {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.strip_prefix(Self::PREFIX).unwrap_or(s).parse().map(Self)
+    }
+}
+
+impl From<u32> for Asn {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Asn> for u32 {
+    fn from(asn: Asn) -> Self {
+        asn.0
+    }
+}
@@ -1,10 +1,8 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::schema::utils::repr_enum;
 
-#[repr(u8)]
-#[derive(
-    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize_repr, Deserialize_repr,
-)]
-pub enum AttachmentType {
-    OnlyProcessAttachments = 1,
-    ProcessAllFiles = 2,
+repr_enum! {
+    pub enum AttachmentType: u8 {
+        OnlyProcessAttachments = 1,
+        ProcessAllFiles = 2,
+    }
 }
@@ -1,13 +1,15 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-#[readonly::make]
-#[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct BasicUser {
-    #[readonly]
-    pub id: i32,
-    pub username: String,
-    pub first_name: Option<String>,
-    pub last_name: Option<String>,
+model! {
+    #[readonly::make]
+    #[skip_serializing_none]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct BasicUser {
+        #[readonly]
+        pub id: super::UserId,
+        pub username: String,
+        pub first_name: Option<String>,
+        pub last_name: Option<String>,
+    }
 }
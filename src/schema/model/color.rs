@@ -0,0 +1,134 @@
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Color of a tag. The server represents it as a `#rrggbb` string, and
+/// rejects any other format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("invalid color {0:?}, expected a hexadecimal color such as #a6cee3")]
+pub struct ColorParseError(pub String);
+
+impl Color {
+    pub const BLACK: Self = Self::new(0x00, 0x00, 0x00);
+    pub const WHITE: Self = Self::new(0xff, 0xff, 0xff);
+
+    #[must_use]
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Color of text displayed on top of this one: black on light colors,
+    /// white on dark ones, using the same threshold as the server does when
+    /// computing `Tag::text_color`.
+    #[must_use]
+    pub fn text_color(&self) -> Self {
+        let channel = |value: u8| (f64::from(value) / 255.0).powi(2);
+        let luminance =
+            (0.299 * channel(self.red) + 0.587 * channel(self.green) + 0.114 * channel(self.blue))
+                .sqrt();
+        if luminance > 0.53 {
+            Self::BLACK
+        } else {
+            Self::WHITE
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ColorParseError(s.to_string());
+        let hex = s.strip_prefix('#').ok_or_else(error)?;
+        if hex.len() != 6 || !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(error());
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| error());
+        Ok(Self::new(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+impl From<[u8; 3]> for Color {
+    fn from([red, green, blue]: [u8; 3]) -> Self {
+        Self::new(red, green, blue)
+    }
+}
+
+impl From<Color> for [u8; 3] {
+    fn from(color: Color) -> Self {
+        [color.red, color.green, color.blue]
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((red, green, blue): (u8, u8, u8)) -> Self {
+        Self::new(red, green, blue)
+    }
+}
+
+impl From<Color> for (u8, u8, u8) {
+    fn from(color: Color) -> Self {
+        (color.red, color.green, color.blue)
+    }
+}
+
+#[cfg(feature = "rgb")]
+impl From<rgb::RGB8> for Color {
+    fn from(color: rgb::RGB8) -> Self {
+        Self::new(color.r, color.g, color.b)
+    }
+}
+
+#[cfg(feature = "rgb")]
+impl From<Color> for rgb::RGB8 {
+    fn from(color: Color) -> Self {
+        Self::new(color.red, color.green, color.blue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, ColorParseError};
+
+    #[test]
+    fn parse() {
+        assert_eq!("#a6cee3".parse(), Ok(Color::new(0xa6, 0xce, 0xe3)));
+        assert_eq!("#A6CEE3".parse(), Ok(Color::new(0xa6, 0xce, 0xe3)));
+        for invalid in [
+            "", "#", "a6cee3", "#a6cee", "#a6cee3f", "#a6ceg3", "#+6cee3", "#éécee",
+        ] {
+            assert_eq!(
+                invalid.parse::<Color>(),
+                Err(ColorParseError(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Color::new(0x0a, 0xce, 0xe3).to_string(), "#0acee3");
+        assert_eq!(Color::WHITE.to_string(), "#ffffff");
+    }
+
+    #[test]
+    fn text_color() {
+        assert_eq!(Color::WHITE.text_color(), Color::BLACK);
+        assert_eq!(Color::BLACK.text_color(), Color::WHITE);
+        assert_eq!(Color::new(0xa6, 0xce, 0xe3).text_color(), Color::BLACK);
+        assert_eq!(Color::new(0x1f, 0x78, 0xb4).text_color(), Color::WHITE);
+    }
+}
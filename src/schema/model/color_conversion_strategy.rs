@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum ColorConversionStrategy {
     LeaveColorUnchanged,
     #[serde(rename = "RGB")]
@@ -9,4 +10,8 @@ pub enum ColorConversionStrategy {
     Gray,
     #[serde(rename = "CMYK")]
     Cmyk,
+    /// Value unknown to this version of the library; it can't be sent back
+    /// to the server.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
@@ -1,11 +1,9 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::schema::utils::repr_enum;
 
-#[repr(u8)]
-#[derive(
-    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize_repr, Deserialize_repr,
-)]
-pub enum ConsumptionScope {
-    OnlyProcessAttachments = 1,
-    ProcessFullMail = 2,
-    ProcessFullMailAndAttachmentsAsSeparateDocuments = 3,
+repr_enum! {
+    pub enum ConsumptionScope: u8 {
+        OnlyProcessAttachments = 1,
+        ProcessFullMail = 2,
+        ProcessFullMailAndAttachmentsAsSeparateDocuments = 3,
+    }
 }
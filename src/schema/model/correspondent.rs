@@ -1,32 +1,41 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::fmt;
 
-#[readonly::make]
-#[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Correspondent {
-    #[readonly]
-    pub id: i32,
-    #[readonly]
-    pub slug: String,
-    #[serde(default)]
-    #[readonly]
-    pub document_count: i32,
-    #[readonly]
-    pub last_correspondence: Option<String>,
-    #[serde(default)]
-    #[readonly]
-    pub user_can_change: bool,
-    pub name: String,
-    #[serde(rename = "match")]
-    pub matches: String,
-    pub matching_algorithm: super::MatchingAlgorithm,
-    #[serde(default = "const_true")]
-    pub is_insensitive: bool,
-    pub owner: i32,
-    pub permissions: super::Permissions,
+model! {
+    #[readonly::make]
+    #[skip_serializing_none]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Correspondent {
+        #[readonly]
+        pub id: super::CorrespondentId,
+        #[readonly]
+        pub slug: String,
+        #[serde(default)]
+        #[readonly]
+        pub document_count: i32,
+        #[readonly]
+        pub last_correspondence: Option<super::Date>,
+        #[serde(default)]
+        #[readonly]
+        pub user_can_change: bool,
+        pub name: String,
+        #[serde(rename = "match")]
+        pub matches: String,
+        pub matching_algorithm: super::MatchingAlgorithm,
+        #[serde(default = "const_true")]
+        pub is_insensitive: bool,
+        pub owner: super::UserId,
+        pub permissions: super::Permissions,
+    }
 }
 
 fn const_true() -> bool {
     true
 }
+
+impl fmt::Display for Correspondent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
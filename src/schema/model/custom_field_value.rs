@@ -0,0 +1,107 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+use url::Url;
+
+use super::DataType;
+
+/// Value of a custom field of a document, typed according to the data type of
+/// the field; see [`Document::custom_field_values`](super::Document::custom_field_values).
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum CustomFieldValue {
+    String(String),
+    Url(Url),
+    Date(super::Date),
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    Monetary(super::Monetary),
+    /// Ids of the linked documents.
+    DocumentLink(Vec<super::DocumentId>),
+    /// Id of the selected option.
+    Select(String),
+}
+
+#[derive(Debug, Error)]
+pub enum CustomFieldValueError {
+    #[error("unknown custom field {0}")]
+    UnknownField(super::CustomFieldId),
+    #[error("invalid value for a {data_type:?} custom field: {value}")]
+    InvalidValue {
+        data_type: DataType,
+        value: serde_json::Value,
+        source: serde_json::Error,
+    },
+    #[error("unsupported custom field type, for value: {0}")]
+    UnsupportedType(serde_json::Value),
+}
+
+impl CustomFieldValue {
+    /// Decodes the raw value of a field of the given type; `None` if the field
+    /// has no value.
+    pub fn from_json(
+        data_type: DataType,
+        value: &serde_json::Value,
+    ) -> Result<Option<Self>, CustomFieldValueError> {
+        if value.is_null() {
+            return Ok(None);
+        }
+        let result = match data_type {
+            DataType::String => parse(value).map(Self::String),
+            DataType::Url => parse(value).map(Self::Url),
+            DataType::Date => parse(value).map(Self::Date),
+            DataType::Boolean => parse(value).map(Self::Boolean),
+            DataType::Integer => parse(value).map(Self::Integer),
+            DataType::Float => parse(value).map(Self::Float),
+            DataType::Monetary => parse(value).map(Self::Monetary),
+            DataType::Documentlink => parse(value).map(Self::DocumentLink),
+            // Older servers identify options by their index.
+            DataType::Select => match value.as_i64() {
+                Some(index) => Ok(Self::Select(index.to_string())),
+                None => parse(value).map(Self::Select),
+            },
+            DataType::Unknown => {
+                return Err(CustomFieldValueError::UnsupportedType(value.clone()));
+            }
+        };
+        result
+            .map(Some)
+            .map_err(|source| CustomFieldValueError::InvalidValue {
+                data_type,
+                value: value.clone(),
+                source,
+            })
+    }
+
+    #[must_use]
+    pub fn data_type(&self) -> DataType {
+        match self {
+            Self::String(_) => DataType::String,
+            Self::Url(_) => DataType::Url,
+            Self::Date(_) => DataType::Date,
+            Self::Boolean(_) => DataType::Boolean,
+            Self::Integer(_) => DataType::Integer,
+            Self::Float(_) => DataType::Float,
+            Self::Monetary(_) => DataType::Monetary,
+            Self::DocumentLink(_) => DataType::Documentlink,
+            Self::Select(_) => DataType::Select,
+        }
+    }
+
+    /// Raw value, as expected by the server.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+impl From<CustomFieldValue> for serde_json::Value {
+    fn from(value: CustomFieldValue) -> Self {
+        value.to_json()
+    }
+}
+
+fn parse<T: DeserializeOwned>(value: &serde_json::Value) -> Result<T, serde_json::Error> {
+    T::deserialize(value)
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum DataType {
     #[serde(rename = "string")]
     String,
@@ -20,4 +21,8 @@ pub enum DataType {
     Documentlink,
     #[serde(rename = "select")]
     Select,
+    /// Value unknown to this version of the library; it can't be sent back
+    /// to the server.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
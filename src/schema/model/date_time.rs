@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Point in time, such as the moment a document was added, as the raw ISO
+/// 8601 string sent by the server. With the `chrono` feature, it converts
+/// from and to a `chrono::DateTime<Utc>`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DateTime(String);
+
+/// Calendar date, such as the creation date of a document, as the raw
+/// `YYYY-MM-DD` string sent by the server. With the `chrono` feature, it
+/// converts from and to a `chrono::NaiveDate`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub struct Date(String);
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("invalid date {0:?}, expected a date such as 2024-03-05")]
+pub struct DateParseError(pub String);
+
+impl DateTime {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Parses the value sent by the server.
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(&self) -> Result<chrono::DateTime<chrono::Utc>, chrono::ParseError> {
+        self.0.parse()
+    }
+}
+
+impl Date {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Parses the value sent by the server.
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(&self) -> Result<chrono::NaiveDate, chrono::ParseError> {
+        self.0.parse()
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Raw value, which isn't checked.
+impl From<String> for DateTime {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<DateTime> for String {
+    fn from(value: DateTime) -> Self {
+        value.0
+    }
+}
+
+impl From<Date> for String {
+    fn from(value: Date) -> Self {
+        value.0
+    }
+}
+
+/// Only checks the shape of the date, not that the day exists.
+impl FromStr for Date {
+    type Err = DateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valid = s.len() == 10
+            && s.bytes().enumerate().all(|(i, c)| match i {
+                4 | 7 => c == b'-',
+                _ => c.is_ascii_digit(),
+            });
+        if valid {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(DateParseError(s.to_string()))
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for DateTime {
+    fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+        Self(value.to_rfc3339())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Date {
+    fn from(value: chrono::NaiveDate) -> Self {
+        Self(value.format("%Y-%m-%d").to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Date, DateParseError};
+
+    #[test]
+    fn parse_date() {
+        assert_eq!("2024-03-05".parse::<Date>().unwrap().as_str(), "2024-03-05");
+        for invalid in [
+            "",
+            "yesterday",
+            "2024-3-5",
+            "2024/03/05",
+            "2024-03-05T00:00",
+        ] {
+            assert_eq!(
+                invalid.parse::<Date>(),
+                Err(DateParseError(invalid.to_string()))
+            );
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        use super::DateTime;
+
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        assert_eq!(Date::from(date).as_str(), "2024-03-05");
+        assert_eq!(Date::from(date).to_chrono(), Ok(date));
+        let time = DateTime::from("2024-03-05T12:30:00.5+01:00".to_string());
+        let utc = time.to_chrono().unwrap();
+        assert_eq!(utc.to_rfc3339(), "2024-03-05T11:30:00.500+00:00");
+        assert_eq!(DateTime::from(utc).to_chrono(), Ok(utc));
+    }
+}
@@ -1,36 +1,89 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::collections::HashMap;
+use std::fmt;
 
 use crate::schema::utils;
 
-#[readonly::make]
-#[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Document {
-    #[readonly]
-    pub id: i32,
-    pub correspondent: Option<i32>,
-    pub document_type: Option<i32>,
-    pub storage_path: Option<i32>,
-    pub title: Option<String>,
-    pub content: Option<String>,
-    pub tags: Vec<i32>,
-    pub created: Option<String>,
-    pub created_date: Option<String>,
-    pub modified: String,
-    pub added: String,
-    pub deleted_at: Option<String>,
-    pub archive_serial_number: Option<i64>,
-    pub original_file_name: Option<String>,
-    pub archived_file_name: Option<String>,
-    pub owner: Option<i32>,
-    pub permissions: super::Permissions,
-    pub user_can_change: bool,
-    pub is_shared_by_requester: bool,
-    pub notes: Vec<super::Note>,
-    #[serde(with = "utils::custom_fields::map")]
-    pub custom_fields: HashMap<i32, serde_json::Value>,
-    pub page_count: Option<i32>,
-    pub mime_type: String,
+model! {
+    #[readonly::make]
+    #[skip_serializing_none]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Document {
+        #[readonly]
+        pub id: super::DocumentId,
+        pub correspondent: Option<super::CorrespondentId>,
+        pub document_type: Option<super::DocumentTypeId>,
+        pub storage_path: Option<super::StoragePathId>,
+        pub title: Option<String>,
+        pub content: Option<String>,
+        pub tags: Vec<super::TagId>,
+        #[serde(default, deserialize_with = "utils::lenient_date::deserialize")]
+        pub created: Option<super::Date>,
+        #[serde(default, deserialize_with = "utils::lenient_date::deserialize")]
+        pub created_date: Option<super::Date>,
+        pub modified: super::DateTime,
+        pub added: super::DateTime,
+        pub deleted_at: Option<super::DateTime>,
+        pub archive_serial_number: Option<super::Asn>,
+        pub original_file_name: Option<String>,
+        pub archived_file_name: Option<String>,
+        pub owner: Option<super::UserId>,
+        pub permissions: super::Permissions,
+        #[serde(default)]
+        #[readonly]
+        pub user_can_change: bool,
+        #[serde(default)]
+        #[readonly]
+        pub is_shared_by_requester: bool,
+        /// Empty on servers without notes, and called comments on the first
+        /// servers that had them.
+        #[serde(default, alias = "comments")]
+        pub notes: Vec<super::Note>,
+        /// Empty on servers without custom fields.
+        #[serde(default, with = "utils::custom_fields::map")]
+        pub custom_fields: HashMap<super::CustomFieldId, serde_json::Value>,
+        pub page_count: Option<i32>,
+        pub mime_type: String,
+        #[serde(rename = "__search_hit__")]
+        #[readonly]
+        pub search_hit: Option<super::SearchHit>,
+    }
+}
+
+impl Document {
+    /// Typed values of the custom fields of the document, by field id, given
+    /// the definitions of those fields; `None` for fields without a value.
+    pub fn custom_field_values(
+        &self,
+        fields: &[super::CustomField],
+    ) -> Result<
+        HashMap<super::CustomFieldId, Option<super::CustomFieldValue>>,
+        super::CustomFieldValueError,
+    > {
+        self.custom_fields
+            .iter()
+            .map(|(id, value)| {
+                let field = fields
+                    .iter()
+                    .find(|field| field.id == *id)
+                    .ok_or(super::CustomFieldValueError::UnknownField(*id))?;
+                let value = super::CustomFieldValue::from_json(field.data_type, value)?;
+                Ok((*id, value))
+            })
+            .collect()
+    }
+}
+
+/// The id and title of the document, such as `#123 'Electricity bill'`; the
+/// original file name stands in for a missing title.
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.id)?;
+        match (&self.title, &self.original_file_name) {
+            (Some(title), _) if !title.is_empty() => write!(f, " '{title}'"),
+            (_, Some(file_name)) => write!(f, " ({file_name})"),
+            _ => Ok(()),
+        }
+    }
 }
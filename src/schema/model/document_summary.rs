@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::schema::utils;
+
+model! {
+    /// A document without its content, its notes and its custom fields, as
+    /// returned by [`Documents::list_summaries`](crate::services::Documents::list_summaries),
+    /// for listings that only show the metadata of the documents.
+    #[readonly::make]
+    #[skip_serializing_none]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct DocumentSummary {
+        #[readonly]
+        pub id: super::DocumentId,
+        pub correspondent: Option<super::CorrespondentId>,
+        pub document_type: Option<super::DocumentTypeId>,
+        pub storage_path: Option<super::StoragePathId>,
+        pub title: Option<String>,
+        pub tags: Vec<super::TagId>,
+        #[serde(default, deserialize_with = "utils::lenient_date::deserialize")]
+        pub created: Option<super::Date>,
+        pub modified: super::DateTime,
+        pub added: super::DateTime,
+        pub archive_serial_number: Option<super::Asn>,
+        pub original_file_name: Option<String>,
+        pub owner: Option<super::UserId>,
+        pub page_count: Option<i32>,
+        pub mime_type: String,
+    }
+}
+
+impl DocumentSummary {
+    /// Fields of the documents to request from the server.
+    pub const FIELDS: &[&str] = &[
+        "id",
+        "correspondent",
+        "document_type",
+        "storage_path",
+        "title",
+        "tags",
+        "created",
+        "modified",
+        "added",
+        "archive_serial_number",
+        "original_file_name",
+        "owner",
+        "page_count",
+        "mime_type",
+    ];
+}
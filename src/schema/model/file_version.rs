@@ -1,9 +1,25 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum FileVersion {
     #[serde(rename = "archive")]
     Archive,
     #[serde(rename = "original")]
     Original,
+    /// Value unknown to this version of the library; it can't be sent back
+    /// to the server.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl fmt::Display for FileVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Archive => "archive",
+            Self::Original => "original",
+            Self::Unknown => "unknown",
+        })
+    }
 }
@@ -0,0 +1,323 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use super::{
+    Asn, CorrespondentId, CustomFieldId, Date, DocumentId, DocumentTypeId, RuleType,
+    SavedViewFilterRule, StoragePathId, TagId, UserId,
+};
+
+/// Rule of a saved view, with a value of the type expected by its
+/// [`RuleType`]. Rules that hold a single id, such as `HasTag`, are repeated
+/// for each id. Rules whose value can't be decoded, or whose type is unknown,
+/// are kept as is in `Other`.
+///
+/// Saved views can be built from a documents
+/// [`Filter`](crate::schema::api::documents::Filter), with
+/// [`Filter::to_rules`](crate::schema::api::documents::Filter::to_rules).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "SavedViewFilterRule", into = "SavedViewFilterRule")]
+pub enum FilterRule {
+    TitleContains(String),
+    ContentContains(String),
+    AsnIs(Asn),
+    /// Id of the correspondent; `None` for documents without one.
+    CorrespondentIs(Option<CorrespondentId>),
+    /// Id of the document type; `None` for documents without one.
+    DocumentTypeIs(Option<DocumentTypeId>),
+    IsInInbox(bool),
+    /// Id of a tag that the documents must have, among others.
+    HasTag(TagId),
+    HasAnyTag(bool),
+    CreatedBefore(Date),
+    CreatedAfter(Date),
+    CreationYearIs(i32),
+    CreationMonthIs(i32),
+    CreationDayIs(i32),
+    AddedBefore(Date),
+    AddedAfter(Date),
+    ModifiedBefore(Date),
+    ModifiedAfter(Date),
+    DoesNotHaveTag(TagId),
+    DoesNotHaveAsn(bool),
+    TitleOrContentContains(String),
+    FulltextQuery(String),
+    MoreLikeThis(DocumentId),
+    /// Id of a tag among which the documents must have at least one.
+    HasTagsIn(TagId),
+    AsnGreaterThan(Asn),
+    AsnLesserThan(Asn),
+    /// Id of the storage path; `None` for documents without one.
+    StoragePathIs(Option<StoragePathId>),
+    HasCorrespondentIn(CorrespondentId),
+    DoesNotHaveCorrespondentIn(CorrespondentId),
+    HasDocumentTypeIn(DocumentTypeId),
+    DoesNotHaveDocumentTypeIn(DocumentTypeId),
+    HasStoragePathIn(StoragePathId),
+    DoesNotHaveStoragePathIn(StoragePathId),
+    /// Id of the owner; `None` for documents without one.
+    OwnerIs(Option<UserId>),
+    HasOwnerIn(UserId),
+    DoesNotHaveOwner(bool),
+    DoesNotHaveOwnerIn(UserId),
+    /// Text contained in the value of any custom field.
+    HasCustomFieldValue(String),
+    /// Id of the user who shared the documents.
+    IsSharedByMe(UserId),
+    /// Id of a custom field that the documents must have, among others.
+    HasCustomFields(CustomFieldId),
+    /// Id of a custom field among which the documents must have at least one.
+    HasCustomFieldsIn(CustomFieldId),
+    DoesNotHaveCustomFieldsIn(CustomFieldId),
+    /// Whether the documents have any custom field; despite the name of the
+    /// rule, `true` selects the documents that have some.
+    DoesNotHaveCustomFields(bool),
+    CustomFieldsQuery(String),
+    CreatedTo(Date),
+    CreatedFrom(Date),
+    AddedTo(Date),
+    AddedFrom(Date),
+    MimeTypeIs(String),
+    Other(SavedViewFilterRule),
+}
+
+impl From<SavedViewFilterRule> for FilterRule {
+    #[allow(clippy::too_many_lines)]
+    fn from(rule: SavedViewFilterRule) -> Self {
+        let value = rule.value.as_deref();
+        let parsed = match rule.rule_type {
+            RuleType::TitleContains => text(value).map(Self::TitleContains),
+            RuleType::ContentContains => text(value).map(Self::ContentContains),
+            RuleType::AsnIs => parse(value).map(Self::AsnIs),
+            RuleType::CorrespondentIs => nullable(value, Self::CorrespondentIs),
+            RuleType::DocumentTypeIs => nullable(value, Self::DocumentTypeIs),
+            RuleType::IsInInbox => boolean(value).map(Self::IsInInbox),
+            RuleType::HasTag => parse(value).map(Self::HasTag),
+            RuleType::HasAnyTag => boolean(value).map(Self::HasAnyTag),
+            RuleType::CreatedBefore => parse(value).map(Self::CreatedBefore),
+            RuleType::CreatedAfter => parse(value).map(Self::CreatedAfter),
+            RuleType::CreationYearIs => parse(value).map(Self::CreationYearIs),
+            RuleType::CreationMonthIs => parse(value).map(Self::CreationMonthIs),
+            RuleType::CreationDayIs => parse(value).map(Self::CreationDayIs),
+            RuleType::AddedBefore => parse(value).map(Self::AddedBefore),
+            RuleType::AddedAfter => parse(value).map(Self::AddedAfter),
+            RuleType::ModifiedBefore => parse(value).map(Self::ModifiedBefore),
+            RuleType::ModifiedAfter => parse(value).map(Self::ModifiedAfter),
+            RuleType::DoesNotHaveTag => parse(value).map(Self::DoesNotHaveTag),
+            RuleType::DoesNotHaveAsn => boolean(value).map(Self::DoesNotHaveAsn),
+            RuleType::TitleOrContentContains => text(value).map(Self::TitleOrContentContains),
+            RuleType::FulltextQuery => text(value).map(Self::FulltextQuery),
+            RuleType::MoreLikeThis => parse(value).map(Self::MoreLikeThis),
+            RuleType::HasTagsIn => parse(value).map(Self::HasTagsIn),
+            RuleType::AsnGreaterThan => parse(value).map(Self::AsnGreaterThan),
+            RuleType::AsnLesserThan => parse(value).map(Self::AsnLesserThan),
+            RuleType::StoragePathIs => nullable(value, Self::StoragePathIs),
+            RuleType::HasCorrespondentIn => parse(value).map(Self::HasCorrespondentIn),
+            RuleType::DoesNotHaveCorrespondentIn => {
+                parse(value).map(Self::DoesNotHaveCorrespondentIn)
+            }
+            RuleType::HasDocumentTypeIn => parse(value).map(Self::HasDocumentTypeIn),
+            RuleType::DoesNotHaveDocumentTypeIn => {
+                parse(value).map(Self::DoesNotHaveDocumentTypeIn)
+            }
+            RuleType::HasStoragePathIn => parse(value).map(Self::HasStoragePathIn),
+            RuleType::DoesNotHaveStoragePathIn => parse(value).map(Self::DoesNotHaveStoragePathIn),
+            RuleType::OwnerIs => nullable(value, Self::OwnerIs),
+            RuleType::HasOwnerIn => parse(value).map(Self::HasOwnerIn),
+            RuleType::DoesNotHaveOwner => boolean(value).map(Self::DoesNotHaveOwner),
+            RuleType::DoesNotHaveOwnerIn => parse(value).map(Self::DoesNotHaveOwnerIn),
+            RuleType::HasCustomFieldValue => text(value).map(Self::HasCustomFieldValue),
+            RuleType::IsSharedByMe => parse(value).map(Self::IsSharedByMe),
+            RuleType::HasCustomFields => parse(value).map(Self::HasCustomFields),
+            RuleType::HasCustomFieldsIn => parse(value).map(Self::HasCustomFieldsIn),
+            RuleType::DoesNotHaveCustomFieldsIn => {
+                parse(value).map(Self::DoesNotHaveCustomFieldsIn)
+            }
+            RuleType::DoesNotHaveCustomFields => boolean(value).map(Self::DoesNotHaveCustomFields),
+            RuleType::CustomFieldsQuery => text(value).map(Self::CustomFieldsQuery),
+            RuleType::CreatedTo => parse(value).map(Self::CreatedTo),
+            RuleType::CreatedFrom => parse(value).map(Self::CreatedFrom),
+            RuleType::AddedTo => parse(value).map(Self::AddedTo),
+            RuleType::AddedFrom => parse(value).map(Self::AddedFrom),
+            RuleType::MimeTypeIs => text(value).map(Self::MimeTypeIs),
+            RuleType::Unknown(_) => None,
+        };
+        parsed.unwrap_or(Self::Other(rule))
+    }
+}
+
+impl From<FilterRule> for SavedViewFilterRule {
+    fn from(rule: FilterRule) -> Self {
+        use FilterRule as F;
+        let (rule_type, value) = match rule {
+            F::TitleContains(value) => (RuleType::TitleContains, Some(value)),
+            F::ContentContains(value) => (RuleType::ContentContains, Some(value)),
+            F::AsnIs(id) => (RuleType::AsnIs, Some(id.to_string())),
+            F::CorrespondentIs(id) => (RuleType::CorrespondentIs, id.map(|id| id.to_string())),
+            F::DocumentTypeIs(id) => (RuleType::DocumentTypeIs, id.map(|id| id.to_string())),
+            F::IsInInbox(flag) => (RuleType::IsInInbox, Some(flag.to_string())),
+            F::HasTag(id) => (RuleType::HasTag, Some(id.to_string())),
+            F::HasAnyTag(flag) => (RuleType::HasAnyTag, Some(flag.to_string())),
+            F::CreatedBefore(date) => (RuleType::CreatedBefore, Some(date.into())),
+            F::CreatedAfter(date) => (RuleType::CreatedAfter, Some(date.into())),
+            F::CreationYearIs(year) => (RuleType::CreationYearIs, Some(year.to_string())),
+            F::CreationMonthIs(month) => (RuleType::CreationMonthIs, Some(month.to_string())),
+            F::CreationDayIs(day) => (RuleType::CreationDayIs, Some(day.to_string())),
+            F::AddedBefore(date) => (RuleType::AddedBefore, Some(date.into())),
+            F::AddedAfter(date) => (RuleType::AddedAfter, Some(date.into())),
+            F::ModifiedBefore(date) => (RuleType::ModifiedBefore, Some(date.into())),
+            F::ModifiedAfter(date) => (RuleType::ModifiedAfter, Some(date.into())),
+            F::DoesNotHaveTag(id) => (RuleType::DoesNotHaveTag, Some(id.to_string())),
+            F::DoesNotHaveAsn(flag) => (RuleType::DoesNotHaveAsn, Some(flag.to_string())),
+            F::TitleOrContentContains(value) => (RuleType::TitleOrContentContains, Some(value)),
+            F::FulltextQuery(value) => (RuleType::FulltextQuery, Some(value)),
+            F::MoreLikeThis(id) => (RuleType::MoreLikeThis, Some(id.to_string())),
+            F::HasTagsIn(id) => (RuleType::HasTagsIn, Some(id.to_string())),
+            F::AsnGreaterThan(asn) => (RuleType::AsnGreaterThan, Some(asn.to_string())),
+            F::AsnLesserThan(asn) => (RuleType::AsnLesserThan, Some(asn.to_string())),
+            F::StoragePathIs(id) => (RuleType::StoragePathIs, id.map(|id| id.to_string())),
+            F::HasCorrespondentIn(id) => (RuleType::HasCorrespondentIn, Some(id.to_string())),
+            F::DoesNotHaveCorrespondentIn(id) => {
+                (RuleType::DoesNotHaveCorrespondentIn, Some(id.to_string()))
+            }
+            F::HasDocumentTypeIn(id) => (RuleType::HasDocumentTypeIn, Some(id.to_string())),
+            F::DoesNotHaveDocumentTypeIn(id) => {
+                (RuleType::DoesNotHaveDocumentTypeIn, Some(id.to_string()))
+            }
+            F::HasStoragePathIn(id) => (RuleType::HasStoragePathIn, Some(id.to_string())),
+            F::DoesNotHaveStoragePathIn(id) => {
+                (RuleType::DoesNotHaveStoragePathIn, Some(id.to_string()))
+            }
+            F::OwnerIs(id) => (RuleType::OwnerIs, id.map(|id| id.to_string())),
+            F::HasOwnerIn(id) => (RuleType::HasOwnerIn, Some(id.to_string())),
+            F::DoesNotHaveOwner(flag) => (RuleType::DoesNotHaveOwner, Some(flag.to_string())),
+            F::DoesNotHaveOwnerIn(id) => (RuleType::DoesNotHaveOwnerIn, Some(id.to_string())),
+            F::HasCustomFieldValue(value) => (RuleType::HasCustomFieldValue, Some(value)),
+            F::IsSharedByMe(id) => (RuleType::IsSharedByMe, Some(id.to_string())),
+            F::HasCustomFields(id) => (RuleType::HasCustomFields, Some(id.to_string())),
+            F::HasCustomFieldsIn(id) => (RuleType::HasCustomFieldsIn, Some(id.to_string())),
+            F::DoesNotHaveCustomFieldsIn(id) => {
+                (RuleType::DoesNotHaveCustomFieldsIn, Some(id.to_string()))
+            }
+            F::DoesNotHaveCustomFields(flag) => {
+                (RuleType::DoesNotHaveCustomFields, Some(flag.to_string()))
+            }
+            F::CustomFieldsQuery(value) => (RuleType::CustomFieldsQuery, Some(value)),
+            F::CreatedTo(date) => (RuleType::CreatedTo, Some(date.into())),
+            F::CreatedFrom(date) => (RuleType::CreatedFrom, Some(date.into())),
+            F::AddedTo(date) => (RuleType::AddedTo, Some(date.into())),
+            F::AddedFrom(date) => (RuleType::AddedFrom, Some(date.into())),
+            F::MimeTypeIs(value) => (RuleType::MimeTypeIs, Some(value)),
+            F::Other(rule) => return rule,
+        };
+        Self { rule_type, value }
+    }
+}
+
+fn text(value: Option<&str>) -> Option<String> {
+    value.map(str::to_string)
+}
+
+fn parse<T: FromStr>(value: Option<&str>) -> Option<T> {
+    value?.parse().ok()
+}
+
+/// Id of an object, where `null` stands for "none".
+fn nullable<T: FromStr>(
+    value: Option<&str>,
+    rule: fn(Option<T>) -> FilterRule,
+) -> Option<FilterRule> {
+    match value {
+        None | Some("" | "null") => Some(rule(None)),
+        Some(value) => value.parse().ok().map(|id| rule(Some(id))),
+    }
+}
+
+fn boolean(value: Option<&str>) -> Option<bool> {
+    match value? {
+        "true" | "1" => Some(true),
+        "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::FilterRule;
+    use crate::schema::model::{CorrespondentId, RuleType, SavedViewFilterRule, TagId};
+
+    fn rule(rule_type: RuleType, value: Option<&str>) -> SavedViewFilterRule {
+        SavedViewFilterRule {
+            rule_type,
+            value: value.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn typed_values() {
+        assert_eq!(
+            FilterRule::from(rule(RuleType::HasTag, Some("12"))),
+            FilterRule::HasTag(TagId::from(12))
+        );
+        assert_eq!(
+            FilterRule::from(rule(RuleType::IsInInbox, Some("1"))),
+            FilterRule::IsInInbox(true)
+        );
+        assert_eq!(
+            FilterRule::from(rule(RuleType::TitleContains, Some("bank"))),
+            FilterRule::TitleContains("bank".to_string())
+        );
+        assert_eq!(
+            FilterRule::from(rule(RuleType::CreatedAfter, Some("2024-03-05"))),
+            FilterRule::CreatedAfter("2024-03-05".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn nullable_ids() {
+        for value in [None, Some(""), Some("null")] {
+            assert_eq!(
+                FilterRule::from(rule(RuleType::CorrespondentIs, value)),
+                FilterRule::CorrespondentIs(None)
+            );
+        }
+        assert_eq!(
+            FilterRule::from(rule(RuleType::CorrespondentIs, Some("3"))),
+            FilterRule::CorrespondentIs(Some(CorrespondentId::from(3)))
+        );
+        assert_eq!(
+            SavedViewFilterRule::from(FilterRule::CorrespondentIs(None)),
+            rule(RuleType::CorrespondentIs, None)
+        );
+    }
+
+    #[test]
+    fn undecodable_rules_are_kept() {
+        for kept in [
+            rule(RuleType::HasTag, Some("tag")),
+            rule(RuleType::HasTag, None),
+            rule(RuleType::IsInInbox, Some("yes")),
+            rule(RuleType::Unknown(200), Some("1")),
+            rule(RuleType::CreatedAfter, Some("yesterday")),
+        ] {
+            let filter_rule = FilterRule::from(kept.clone());
+            assert_eq!(filter_rule, FilterRule::Other(kept.clone()));
+            assert_eq!(SavedViewFilterRule::from(filter_rule), kept);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let rules = json!([
+            {"rule_type": 6, "value": "12"},
+            {"rule_type": 3, "value": null},
+            {"rule_type": 0, "value": "bank"},
+            {"rule_type": 200, "value": "1"},
+        ]);
+        let decoded: Vec<FilterRule> = serde_json::from_value(rules.clone()).unwrap();
+        assert_eq!(decoded[0], FilterRule::HasTag(TagId::from(12)));
+        assert_eq!(decoded[1], FilterRule::CorrespondentIs(None));
+        assert_eq!(serde_json::to_value(&decoded).unwrap(), rules);
+    }
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+model! {
+    /// Hits of a global search, grouped by type. Each group is capped by the
+    /// server, and is empty if the user isn't allowed to view the corresponding
+    /// objects.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct GlobalSearchResult {
+        pub total: i32,
+        #[serde(default)]
+        pub documents: Vec<super::Document>,
+        #[serde(default)]
+        pub saved_views: Vec<super::SavedView>,
+        #[serde(default)]
+        pub tags: Vec<super::Tag>,
+        #[serde(default)]
+        pub correspondents: Vec<super::Correspondent>,
+        #[serde(default)]
+        pub document_types: Vec<super::DocumentType>,
+        #[serde(default)]
+        pub storage_paths: Vec<super::StoragePath>,
+        #[serde(default)]
+        pub users: Vec<super::User>,
+        #[serde(default)]
+        pub groups: Vec<super::Group>,
+        #[serde(default)]
+        pub mail_rules: Vec<super::MailRule>,
+        #[serde(default)]
+        pub mail_accounts: Vec<super::MailAccount>,
+        #[serde(default)]
+        pub workflows: Vec<super::Workflow>,
+        #[serde(default)]
+        pub custom_fields: Vec<super::CustomField>,
+    }
+}
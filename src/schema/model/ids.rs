@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+/// Defines the type of the ids of a kind of object, so that the id of an
+/// object can't be passed where the id of another kind is expected; ids
+/// convert from and into plain integers.
+macro_rules! id_type {
+    ($($(#[$meta:meta])* $name:ident,)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(
+                Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+            )]
+            #[serde(transparent)]
+            pub struct $name(pub i32);
+
+            impl $name {
+                #[must_use]
+                pub const fn new(value: i32) -> Self {
+                    Self(value)
+                }
+
+                #[must_use]
+                pub const fn value(self) -> i32 {
+                    self.0
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+
+            impl FromStr for $name {
+                type Err = ParseIntError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    s.parse().map(Self)
+                }
+            }
+
+            impl From<i32> for $name {
+                fn from(value: i32) -> Self {
+                    Self(value)
+                }
+            }
+
+            impl From<$name> for i32 {
+                fn from(id: $name) -> Self {
+                    id.0
+                }
+            }
+        )*
+    };
+}
+
+id_type! {
+    CorrespondentId,
+    CustomFieldId,
+    DocumentId,
+    DocumentTypeId,
+    GroupId,
+    MailAccountId,
+    MailRuleId,
+    NoteId,
+    SavedViewId,
+    ShareLinkId,
+    SocialAccountId,
+    StoragePathId,
+    TagId,
+    /// Id of a task in the list of tasks, not to be confused with the UUID of
+    /// the task, its `task_id`.
+    TaskId,
+    UserId,
+    WorkflowActionId,
+    WorkflowId,
+    WorkflowTriggerId,
+}
@@ -1,11 +1,9 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::schema::utils::repr_enum;
 
-#[repr(u8)]
-#[derive(
-    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize_repr, Deserialize_repr,
-)]
-pub enum ImapSecurity {
-    NoEncryption = 1,
-    UseSSL = 2,
-    UseSTARTTLS = 3,
+repr_enum! {
+    pub enum ImapSecurity: u8 {
+        NoEncryption = 1,
+        UseSSL = 2,
+        UseSTARTTLS = 3,
+    }
 }
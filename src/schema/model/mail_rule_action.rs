@@ -1,13 +1,23 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::schema::utils::repr_enum;
 
-#[repr(u8)]
-#[derive(
-    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize_repr, Deserialize_repr,
-)]
-pub enum MailRuleAction {
-    Delete = 1,
-    MoveToFolder = 2,
-    MarkAsRead = 3,
-    FlagTheMail = 4,
-    TagTheMail = 5,
+repr_enum! {
+    /// What is done to a mail once it has been consumed.
+    pub enum MailRuleAction: u8 {
+        Delete = 1,
+        /// Moves the mail to the folder given as the action parameter.
+        MoveToFolder = 2,
+        MarkAsRead = 3,
+        FlagTheMail = 4,
+        /// Sets the keyword given as the action parameter, or, for Gmail
+        /// accounts, the label given as `gmail_label:<label>`.
+        TagTheMail = 5,
+    }
+}
+
+impl MailRuleAction {
+    /// Whether the action requires an `action_parameter`.
+    #[must_use]
+    pub fn takes_parameter(self) -> bool {
+        matches!(self, Self::MoveToFolder | Self::TagTheMail)
+    }
 }
@@ -1,15 +1,108 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
 
-#[repr(u8)]
-#[derive(
-    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize_repr, Deserialize_repr,
+use crate::schema::utils::repr_enum;
+
+repr_enum! {
+    pub enum MatchingAlgorithm: u8 {
+        None = 0,
+        AnyWord = 1,
+        AllWords = 2,
+        ExactMatch = 3,
+        RegularExpression = 4,
+        FuzzyWord = 5,
+        Automatic = 6,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error(
+    "unknown matching algorithm {0:?}, expected one of none, any, all, literal, regex, fuzzy or auto"
 )]
-pub enum MatchingAlgorithm {
-    None = 0,
-    AnyWord = 1,
-    AllWords = 2,
-    ExactMatch = 3,
-    RegularExpression = 4,
-    FuzzyWord = 5,
-    Automatic = 6,
+pub struct MatchingAlgorithmParseError(pub String);
+
+/// Inconsistency between a matching algorithm and the `match` string of an
+/// object.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum MatchError {
+    #[error("the {0} matching algorithm doesn't use a match")]
+    Unexpected(MatchingAlgorithm),
+    #[error("the {0} matching algorithm requires a match")]
+    Missing(MatchingAlgorithm),
+    #[error("invalid regular expression {pattern:?}: {reason}")]
+    InvalidRegex { pattern: String, reason: String },
+}
+
+impl MatchingAlgorithm {
+    /// Whether objects with this algorithm are matched against their `match`
+    /// string; it is ignored otherwise.
+    #[must_use]
+    pub fn uses_match(self) -> bool {
+        !matches!(self, Self::None | Self::Automatic | Self::Unknown(_))
+    }
+
+    /// Checks that the given `match` string is consistent with the algorithm:
+    /// required by the algorithms that use it, empty otherwise, and, with the
+    /// `regex` feature, a valid regular expression for `RegularExpression`.
+    /// Unknown algorithms accept anything.
+    pub fn validate(self, matches: &str) -> Result<(), MatchError> {
+        if let Self::Unknown(_) = self {
+            return Ok(());
+        }
+        if !self.uses_match() {
+            return match matches.trim() {
+                "" => Ok(()),
+                _ => Err(MatchError::Unexpected(self)),
+            };
+        }
+        if matches.trim().is_empty() {
+            return Err(MatchError::Missing(self));
+        }
+        #[cfg(feature = "regex")]
+        if self == Self::RegularExpression {
+            fancy_regex::Regex::new(matches).map_err(|error| MatchError::InvalidRegex {
+                pattern: matches.to_string(),
+                reason: error.to_string(),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for MatchingAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::None => "none",
+            Self::AnyWord => "any",
+            Self::AllWords => "all",
+            Self::ExactMatch => "literal",
+            Self::RegularExpression => "regex",
+            Self::FuzzyWord => "fuzzy",
+            Self::Automatic => "auto",
+            Self::Unknown(value) => return write!(f, "{value}"),
+        })
+    }
+}
+
+impl FromStr for MatchingAlgorithm {
+    type Err = MatchingAlgorithmParseError;
+
+    /// Parses the names used by [`Display`](fmt::Display), or the numeric
+    /// code of the algorithm.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "none" => Self::None,
+            "any" => Self::AnyWord,
+            "all" => Self::AllWords,
+            "literal" => Self::ExactMatch,
+            "regex" => Self::RegularExpression,
+            "fuzzy" => Self::FuzzyWord,
+            "auto" => Self::Automatic,
+            code => code
+                .parse::<u8>()
+                .map(Self::from)
+                .map_err(|_| MatchingAlgorithmParseError(s.to_string()))?,
+        })
+    }
 }
@@ -1,9 +1,38 @@
+/// Declares a model returned by the server. With the `unknown-fields` feature,
+/// an `extra` map is added to it, which keeps the fields of the response that
+/// the model doesn't know about; with the `strict` feature as well, decoding
+/// fails if that map isn't empty, as it does without `unknown-fields`.
+macro_rules! model {
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident {
+            $($fields:tt)*
+        }
+    ) => {
+        $(#[$attr])*
+        pub struct $name {
+            $($fields)*
+            /// Fields sent by the server that this version of the library
+            /// doesn't know about.
+            #[cfg(feature = "unknown-fields")]
+            #[serde(flatten)]
+            #[cfg_attr(
+                feature = "strict",
+                serde(deserialize_with = "crate::schema::utils::unknown_fields::deserialize")
+            )]
+            pub extra: std::collections::HashMap<String, serde_json::Value>,
+        }
+    };
+}
+
 mod account_type;
 pub use account_type::*;
 mod actor;
 pub use actor::*;
 mod application_configuration;
 pub use application_configuration::*;
+mod asn;
+pub use asn::*;
 mod assign_correspondent_from;
 pub use assign_correspondent_from::*;
 mod assign_title_from;
@@ -14,26 +43,40 @@ mod basic_user;
 pub use basic_user::*;
 mod color_conversion_strategy;
 pub use color_conversion_strategy::*;
+mod color;
+pub use color::*;
 mod consumption_scope;
 pub use consumption_scope::*;
 mod correspondent;
 pub use correspondent::*;
 mod custom_field;
 pub use custom_field::*;
+mod custom_field_value;
+pub use custom_field_value::*;
 mod data_type;
 pub use data_type::*;
+mod date_time;
+pub use date_time::*;
 mod display_mode;
 pub use display_mode::*;
 mod document_metadata;
 pub use document_metadata::*;
 mod document;
 pub use document::*;
+mod document_summary;
+pub use document_summary::*;
 mod document_type;
 pub use document_type::*;
 mod file_version;
 pub use file_version::*;
+mod filter_rule;
+pub use filter_rule::*;
+mod global_search_result;
+pub use global_search_result::*;
 mod group;
 pub use group::*;
+mod ids;
+pub use ids::*;
 mod imap_security;
 pub use imap_security::*;
 mod log_entry;
@@ -46,6 +89,8 @@ mod mail_rule;
 pub use mail_rule::*;
 mod matching_algorithm;
 pub use matching_algorithm::*;
+mod monetary;
+pub use monetary::*;
 mod note;
 pub use note::*;
 mod ocr_mode;
@@ -54,12 +99,18 @@ mod output_type;
 pub use output_type::*;
 mod paginated;
 pub use paginated::*;
+mod partial_document;
+pub use partial_document::*;
+mod path_template;
+pub use path_template::*;
 mod pdf_layout;
 pub use pdf_layout::*;
 mod permission_class;
 pub use permission_class::*;
 mod permissions;
 pub use permissions::*;
+mod permissions_form;
+pub use permissions_form::*;
 mod profile;
 pub use profile::*;
 mod rule_type;
@@ -70,6 +121,12 @@ mod saved_view;
 pub use saved_view::*;
 mod schedule_date_field;
 pub use schedule_date_field::*;
+mod search_hit;
+pub use search_hit::*;
+mod secret;
+pub use secret::*;
+mod server_version;
+pub use server_version::*;
 mod share_link;
 pub use share_link::*;
 mod skip_archive_file;
@@ -92,6 +149,12 @@ mod task_view;
 pub use task_view::*;
 mod task_view_type;
 pub use task_view_type::*;
+mod token;
+pub use token::*;
+mod totp;
+pub use totp::*;
+mod ui_settings;
+pub use ui_settings::*;
 mod unpaper_clean;
 pub use unpaper_clean::*;
 mod user;
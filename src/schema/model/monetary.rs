@@ -0,0 +1,179 @@
+use rust_decimal::Decimal;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::fmt;
+use std::ops::{Mul, Neg};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Value of a monetary custom field: an amount, and the ISO 4217 code of its
+/// currency, if any. The server represents it as a string such as `USD123.45`,
+/// or `123.45` when no currency is specified.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct Monetary {
+    pub currency: Option<String>,
+    pub amount: Decimal,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("invalid monetary value {0:?}, expected a currency code and an amount, such as USD123.45")]
+pub struct MonetaryParseError(pub String);
+
+impl Monetary {
+    #[must_use]
+    pub fn new(currency: Option<&str>, amount: Decimal) -> Self {
+        Self {
+            currency: currency.map(str::to_uppercase),
+            amount,
+        }
+    }
+
+    /// Sum of two values; `None` if their currencies differ, or on overflow.
+    #[must_use]
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        self.combine(other, Decimal::checked_add)
+    }
+
+    /// Difference of two values; `None` if their currencies differ, or on
+    /// overflow.
+    #[must_use]
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.combine(other, Decimal::checked_sub)
+    }
+
+    /// Product of a value and a factor; `None` on overflow.
+    #[must_use]
+    pub fn checked_mul(&self, factor: Decimal) -> Option<Self> {
+        Some(Self {
+            currency: self.currency.clone(),
+            amount: self.amount.checked_mul(factor)?,
+        })
+    }
+
+    fn combine(&self, other: &Self, op: fn(Decimal, Decimal) -> Option<Decimal>) -> Option<Self> {
+        if self.currency != other.currency {
+            return None;
+        }
+        Some(Self {
+            currency: self.currency.clone(),
+            amount: op(self.amount, other.amount)?,
+        })
+    }
+}
+
+impl fmt::Display for Monetary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let amount = self.amount.round_dp(2);
+        write!(f, "{}{amount:.2}", self.currency.as_deref().unwrap_or(""))
+    }
+}
+
+impl FromStr for Monetary {
+    type Err = MonetaryParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || MonetaryParseError(s.to_string());
+        let split = s
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(s.len());
+        let (currency, amount) = s.split_at(split);
+        if !matches!(currency.len(), 0 | 3) {
+            return Err(error());
+        }
+        let amount = Decimal::from_str(amount).map_err(|_| error())?;
+        Ok(Self::new(
+            Some(currency).filter(|currency| !currency.is_empty()),
+            amount,
+        ))
+    }
+}
+
+impl Mul<Decimal> for Monetary {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics on overflow; see [`Monetary::checked_mul`].
+    fn mul(self, factor: Decimal) -> Self {
+        Self {
+            amount: self.amount * factor,
+            ..self
+        }
+    }
+}
+
+impl Neg for Monetary {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            amount: -self.amount,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    use super::{Monetary, MonetaryParseError};
+
+    fn decimal(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "usd123.4".parse(),
+            Ok(Monetary::new(Some("USD"), decimal("123.4")))
+        );
+        assert_eq!("12.50".parse(), Ok(Monetary::new(None, decimal("12.50"))));
+        assert_eq!(
+            "EUR-12.50".parse(),
+            Ok(Monetary::new(Some("EUR"), decimal("-12.50")))
+        );
+        for invalid in ["", "USD", "US123", "EURO12", "USD12.5.0"] {
+            assert_eq!(
+                invalid.parse::<Monetary>(),
+                Err(MonetaryParseError(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Monetary::new(Some("usd"), decimal("123.4")).to_string(),
+            "USD123.40"
+        );
+        assert_eq!(Monetary::new(None, decimal("-0.5")).to_string(), "-0.50");
+    }
+
+    #[test]
+    fn arithmetic() {
+        let usd = |amount| Monetary::new(Some("USD"), decimal(amount));
+        assert_eq!(usd("1.25").checked_add(&usd("2")), Some(usd("3.25")));
+        assert_eq!(usd("1.25").checked_sub(&usd("2")), Some(usd("-0.75")));
+        assert_eq!(
+            usd("1").checked_add(&Monetary::new(Some("EUR"), Decimal::ONE)),
+            None
+        );
+        assert_eq!(
+            usd("1").checked_add(&Monetary::new(None, Decimal::ONE)),
+            None
+        );
+        assert_eq!(usd("2.5").checked_mul(decimal("2")), Some(usd("5.0")));
+        assert_eq!(usd("2.5") * decimal("-2"), usd("-5.0"));
+        assert_eq!(
+            Monetary::new(None, Decimal::MAX).checked_add(&Monetary::new(None, Decimal::ONE)),
+            None
+        );
+        assert_eq!(
+            Monetary::new(None, Decimal::MAX).checked_mul(decimal("2")),
+            None
+        );
+        assert_eq!(-usd("1.5"), usd("-1.5"));
+    }
+}
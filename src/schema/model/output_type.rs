@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum OutputType {
     #[serde(rename = "pdf")]
     Pdf,
@@ -12,4 +13,8 @@ pub enum OutputType {
     Pdfa2,
     #[serde(rename = "pdfa-3")]
     Pdfa3,
+    /// Value unknown to this version of the library; it can't be sent back
+    /// to the server.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
@@ -1,17 +1,29 @@
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+use crate::schema::model::DocumentId;
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Paginated<T> {
+    /// Total number of items of the listing, across all pages.
     pub count: i32,
     pub results: Vec<T>,
+    /// Ids of all the items of the listing, across all pages; only included
+    /// by some endpoints, and empty otherwise; see [`Paginated::document_ids`].
     #[serde(default)]
-    pub all: Vec<i32>,
+    pub(crate) all: Vec<i32>,
     pub(crate) next: Option<Url>,
     pub(crate) previous: Option<Url>,
 }
 
 impl<T> Paginated<T> {
+    /// Ids of all the documents of a document listing, across all pages, for
+    /// bulk operations on a whole selection; empty for the other listings.
+    #[must_use]
+    pub fn document_ids(&self) -> Vec<DocumentId> {
+        self.all.iter().copied().map(DocumentId::from).collect()
+    }
+
     #[must_use]
     pub fn raw_previous_url(&self) -> Option<&Url> {
         self.previous.as_ref()
@@ -21,4 +33,157 @@ impl<T> Paginated<T> {
     pub fn raw_next_url(&self) -> Option<&Url> {
         self.next.as_ref()
     }
+
+    /// Number of the current page, inferred from the `next` and `previous`
+    /// links. Pages are numbered from 1.
+    #[must_use]
+    pub fn current_page(&self) -> u32 {
+        if let Some(next) = &self.next {
+            page_number(next).saturating_sub(1)
+        } else if let Some(previous) = &self.previous {
+            page_number(previous).saturating_add(1)
+        } else {
+            1
+        }
+    }
+
+    /// Number of items per page, as used by the server, which caps the
+    /// requested `page_size`. Since all pages but the last one are full, it is
+    /// inferred from the number of items of the current page, or, on the last
+    /// page, from the number of items before it; `None` for a listing with a
+    /// single page.
+    #[must_use]
+    pub fn page_size(&self) -> Option<u32> {
+        let len = u32::try_from(self.results.len()).ok()?;
+        if self.next.is_some() {
+            return Some(len);
+        }
+        let previous_pages = self.current_page().checked_sub(1).filter(|&n| n > 0)?;
+        let before = u32::try_from(self.count).ok()?.checked_sub(len)?;
+        Some(before / previous_pages)
+    }
+
+    /// Total number of pages of the listing; at least 1, since an empty
+    /// listing still has an empty first page.
+    #[must_use]
+    pub fn page_count(&self) -> u32 {
+        if self.next.is_none() {
+            return self.current_page();
+        }
+        let count = u32::try_from(self.count).unwrap_or(0);
+        match self.page_size() {
+            Some(size @ 1..) => count.div_ceil(size).max(1),
+            _ => self.current_page() + 1,
+        }
+    }
+
+    /// URL of the given page of the same listing, derived from the `next` or
+    /// `previous` link by changing the `page` parameter; `None` if the listing
+    /// has a single page. Pages are numbered from 1.
+    #[must_use]
+    pub fn page_url(&self, number: u32) -> Option<Url> {
+        let link = self.next.as_ref().or(self.previous.as_ref())?;
+        let params: Vec<(String, String)> = link
+            .query_pairs()
+            .filter(|(key, _)| key != "page")
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        let mut url = link.clone();
+        url.query_pairs_mut()
+            .clear()
+            .extend_pairs(params)
+            .append_pair("page", &number.to_string());
+        Some(url)
+    }
+}
+
+fn query_param(url: &Url, name: &str) -> Option<String> {
+    url.query_pairs()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.into_owned())
+}
+
+/// Number of the page a link points to; the link to the first page has no
+/// `page` parameter.
+fn page_number(url: &Url) -> u32 {
+    query_param(url, "page")
+        .and_then(|page| page.parse().ok())
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::Paginated;
+
+    const URL: &str = "http://localhost:8000/api/tags/";
+
+    /// A page of a listing of `count` items, with `len` items.
+    fn page(count: i32, len: i32, next: Option<&str>, previous: Option<&str>) -> Paginated<i32> {
+        serde_json::from_value(json!({
+            "count": count,
+            "results": (0..len).collect::<Vec<_>>(),
+            "next": next.map(|query| format!("{URL}?{query}")),
+            "previous": previous.map(|query| format!("{URL}?{query}")),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn page_math() {
+        let first = page(60, 25, Some("page=2"), None);
+        assert_eq!(first.current_page(), 1);
+        assert_eq!(first.page_size(), Some(25));
+        assert_eq!(first.page_count(), 3);
+
+        let middle = page(60, 25, Some("page=3&page_size=25"), Some("page_size=25"));
+        assert_eq!(middle.current_page(), 2);
+        assert_eq!(middle.page_size(), Some(25));
+        assert_eq!(middle.page_count(), 3);
+
+        let last = page(60, 10, None, Some("page=2&page_size=25"));
+        assert_eq!(last.current_page(), 3);
+        assert_eq!(last.page_size(), Some(25));
+        assert_eq!(last.page_count(), 3);
+    }
+
+    #[test]
+    fn page_size_above_the_server_maximum() {
+        let first = page(250, 100, Some("page=2&page_size=100000"), None);
+        assert_eq!(first.page_size(), Some(100));
+        assert_eq!(first.page_count(), 3);
+
+        let last = page(250, 50, None, Some("page=2&page_size=100000"));
+        assert_eq!(last.current_page(), 3);
+        assert_eq!(last.page_size(), Some(100));
+        assert_eq!(last.page_count(), 3);
+    }
+
+    #[test]
+    fn empty_listing() {
+        let empty = page(0, 0, None, None);
+        assert_eq!(empty.current_page(), 1);
+        assert_eq!(empty.page_size(), None);
+        assert_eq!(empty.page_count(), 1);
+        assert_eq!(empty.page_url(1), None);
+    }
+
+    #[test]
+    fn page_url() {
+        let middle = page(
+            60,
+            25,
+            Some("page=3&page_size=25&ordering=name"),
+            Some("page_size=25&ordering=name"),
+        );
+        assert_eq!(
+            middle.page_url(1).unwrap().as_str(),
+            format!("{URL}?page_size=25&ordering=name&page=1")
+        );
+        assert_eq!(
+            middle.page_url(3).unwrap().as_str(),
+            format!("{URL}?page_size=25&ordering=name&page=3")
+        );
+    }
 }
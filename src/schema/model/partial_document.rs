@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+
+use crate::schema::utils;
+
+model! {
+    /// A document restricted to the fields selected with the `fields` parameter
+    /// of the documents listing, as returned by
+    /// [`Documents::list_partial`](crate::services::Documents::list_partial).
+    /// Every field is optional: a field that wasn't selected is `None`, as is a
+    /// selected field whose value is null.
+    #[readonly::make]
+    #[skip_serializing_none]
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+    #[serde(default)]
+    pub struct PartialDocument {
+        #[readonly]
+        pub id: Option<super::DocumentId>,
+        pub correspondent: Option<super::CorrespondentId>,
+        pub document_type: Option<super::DocumentTypeId>,
+        pub storage_path: Option<super::StoragePathId>,
+        pub title: Option<String>,
+        pub content: Option<String>,
+        pub tags: Option<Vec<super::TagId>>,
+        #[serde(deserialize_with = "utils::lenient_date::deserialize")]
+        pub created: Option<super::Date>,
+        #[serde(deserialize_with = "utils::lenient_date::deserialize")]
+        pub created_date: Option<super::Date>,
+        pub modified: Option<super::DateTime>,
+        pub added: Option<super::DateTime>,
+        pub deleted_at: Option<super::DateTime>,
+        pub archive_serial_number: Option<super::Asn>,
+        pub original_file_name: Option<String>,
+        pub archived_file_name: Option<String>,
+        pub owner: Option<super::UserId>,
+        pub permissions: Option<super::Permissions>,
+        #[readonly]
+        pub user_can_change: Option<bool>,
+        #[readonly]
+        pub is_shared_by_requester: Option<bool>,
+        #[serde(alias = "comments")]
+        pub notes: Option<Vec<super::Note>>,
+        #[serde(with = "utils::custom_fields::map_option")]
+        pub custom_fields: Option<HashMap<super::CustomFieldId, serde_json::Value>>,
+        pub page_count: Option<i32>,
+        pub mime_type: Option<String>,
+    }
+}
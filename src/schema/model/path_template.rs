@@ -0,0 +1,380 @@
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Template of the path of the documents of a storage path, such as
+/// `{{ created_year }}/{{ correspondent }}/{{ title }}`, checked when parsed:
+/// placeholders must be known to the server, and the text around them must
+/// be valid in a relative path. Both the `{{ name }}` syntax and the legacy
+/// `{name}` one are accepted; other Jinja expressions and blocks are kept as
+/// is, only their leading variable is checked.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct PathTemplate {
+    source: String,
+    parts: Vec<Part>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Part {
+    Text(String),
+    Placeholder(String),
+    Expression(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum PathTemplateError {
+    #[error("empty path template")]
+    Empty,
+    #[error("path templates must be relative")]
+    Absolute,
+    #[error("path templates can't refer to the parent directory")]
+    ParentDirectory,
+    #[error("illegal character {0:?} in path template")]
+    IllegalCharacter(char),
+    #[error("unclosed placeholder {0:?} in path template")]
+    Unclosed(String),
+    #[error("unknown placeholder {0:?} in path template")]
+    UnknownPlaceholder(String),
+    #[error("{0:?} can't be rendered outside of the server")]
+    Unsupported(String),
+}
+
+/// Names of the objects that a document refers to by id, needed to render a
+/// [`PathTemplate`]; missing ones are rendered as `none`, like the server does.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PathNames {
+    pub correspondent: Option<String>,
+    pub document_type: Option<String>,
+    pub owner_username: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// Variables available in the path templates of the server.
+pub const PATH_PLACEHOLDERS: &[&str] = &[
+    "title",
+    "correspondent",
+    "document_type",
+    "created",
+    "created_year",
+    "created_year_short",
+    "created_month",
+    "created_month_name",
+    "created_month_name_short",
+    "created_day",
+    "added",
+    "added_year",
+    "added_year_short",
+    "added_month",
+    "added_month_name",
+    "added_month_name_short",
+    "added_day",
+    "asn",
+    "tag_list",
+    "owner_username",
+    "original_name",
+    "doc_pk",
+    "tags",
+    "custom_fields",
+    "document",
+];
+
+const ILLEGAL_CHARACTERS: &[char] = &['\\', '<', '>', ':', '"', '|', '?', '*'];
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+impl PathTemplate {
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Names of the simple placeholders of the template, in order.
+    pub fn placeholders(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().filter_map(|part| match part {
+            Part::Placeholder(name) => Some(name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Preview of the path of the given document, without its extension.
+    /// Fails on templates that use Jinja expressions or blocks, which only
+    /// the server can evaluate.
+    pub fn render(
+        &self,
+        document: &super::Document,
+        names: &PathNames,
+    ) -> Result<String, PathTemplateError> {
+        let mut path = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Text(text) => path.push_str(text),
+                Part::Placeholder(name) => match value(name, document, names) {
+                    Some(value) => path.push_str(&sanitize(&value)),
+                    None => return Err(PathTemplateError::Unsupported(name.clone())),
+                },
+                Part::Expression(expression) => {
+                    return Err(PathTemplateError::Unsupported(expression.clone()));
+                }
+            }
+        }
+        Ok(path)
+    }
+}
+
+impl fmt::Display for PathTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+impl FromStr for PathTemplate {
+    type Err = PathTemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(PathTemplateError::Empty);
+        }
+        if s.starts_with('/') {
+            return Err(PathTemplateError::Absolute);
+        }
+        let mut parts = Vec::new();
+        let mut rest = s;
+        while let Some(start) = rest.find('{') {
+            let (text, tail) = rest.split_at(start);
+            parts.push(Part::Text(text.to_string()));
+            let (close, legacy) = match tail.as_bytes().get(1) {
+                Some(b'{') => ("}}", false),
+                Some(b'%') => ("%}", false),
+                Some(b'#') => ("#}", false),
+                _ => ("}", true),
+            };
+            let open = if legacy { 1 } else { 2 };
+            let end = tail[open..]
+                .find(close)
+                .ok_or_else(|| PathTemplateError::Unclosed(tail.to_string()))?;
+            let inner = tail[open..open + end].trim();
+            parts.push(match close {
+                "%}" | "#}" => Part::Expression(inner.to_string()),
+                _ => placeholder(inner)?,
+            });
+            rest = &tail[open + end + close.len()..];
+        }
+        parts.push(Part::Text(rest.to_string()));
+        parts.retain(|part| !matches!(part, Part::Text(text) if text.is_empty()));
+        for part in &parts {
+            if let Part::Text(text) = part {
+                check_text(text)?;
+            }
+        }
+        Ok(Self {
+            source: s.to_string(),
+            parts,
+        })
+    }
+}
+
+impl From<PathTemplate> for String {
+    fn from(template: PathTemplate) -> Self {
+        template.source
+    }
+}
+
+impl super::StoragePath {
+    /// Parsed template of the path of the storage path.
+    pub fn template(&self) -> Result<PathTemplate, PathTemplateError> {
+        self.path.parse()
+    }
+}
+
+fn placeholder(inner: &str) -> Result<Part, PathTemplateError> {
+    let name_end = inner
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(inner.len());
+    let name = &inner[..name_end];
+    if name.len() == inner.len() {
+        if !PATH_PLACEHOLDERS.contains(&name) {
+            return Err(PathTemplateError::UnknownPlaceholder(name.to_string()));
+        }
+        return Ok(Part::Placeholder(name.to_string()));
+    }
+    if !name.is_empty() && !PATH_PLACEHOLDERS.contains(&name) {
+        return Err(PathTemplateError::UnknownPlaceholder(name.to_string()));
+    }
+    Ok(Part::Expression(inner.to_string()))
+}
+
+fn check_text(text: &str) -> Result<(), PathTemplateError> {
+    if let Some(c) = text
+        .chars()
+        .find(|c| c.is_control() || ILLEGAL_CHARACTERS.contains(c))
+    {
+        return Err(PathTemplateError::IllegalCharacter(c));
+    }
+    if text.split('/').any(|component| component == "..") {
+        return Err(PathTemplateError::ParentDirectory);
+    }
+    Ok(())
+}
+
+fn sanitize(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            if c == '/' || c.is_control() || ILLEGAL_CHARACTERS.contains(&c) {
+                '-'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+fn value(name: &str, document: &super::Document, names: &PathNames) -> Option<String> {
+    let value = match name {
+        "title" => document.title.clone(),
+        "correspondent" => names.correspondent.clone(),
+        "document_type" => names.document_type.clone(),
+        "owner_username" => names.owner_username.clone(),
+        "tag_list" => Some(names.tags.join(",")).filter(|tags| !tags.is_empty()),
+        "asn" => document.archive_serial_number.map(|asn| asn.to_string()),
+        "original_name" => document.original_file_name.as_deref().map(|name| {
+            name.rsplit_once('.')
+                .map_or(name, |(stem, _)| stem)
+                .to_string()
+        }),
+        "doc_pk" => Some(format!("{:07}", document.id.0)),
+        _ => {
+            if let Some(part) = name.strip_prefix("created") {
+                let created = document.created.as_ref().map(ToString::to_string);
+                created.and_then(|date| date_part(&date, part))
+            } else if let Some(part) = name.strip_prefix("added") {
+                date_part(&ToString::to_string(&document.added), part)
+            } else {
+                return None;
+            }
+        }
+    };
+    Some(value.unwrap_or_else(|| "none".to_string()))
+}
+
+/// Part of a date formatted as `YYYY-MM-DD`, possibly followed by a time.
+fn date_part(date: &str, part: &str) -> Option<String> {
+    let (year, month, day) = (date.get(0..4)?, date.get(5..7)?, date.get(8..10)?);
+    let month_name = || MONTHS.get(month.parse::<usize>().ok()?.checked_sub(1)?);
+    Some(match part {
+        "" => format!("{year}-{month}-{day}"),
+        "_year" => year.to_string(),
+        "_year_short" => year[2..].to_string(),
+        "_month" => month.to_string(),
+        "_month_name" => month_name()?.to_string(),
+        "_month_name_short" => month_name()?[..3].to_string(),
+        "_day" => day.to_string(),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{PathNames, PathTemplate, PathTemplateError};
+    use crate::schema::model::Document;
+
+    fn document() -> Document {
+        serde_json::from_value(json!({
+            "id": 42,
+            "title": "Invoice 3/4",
+            "tags": [],
+            "created": "2024-03-05",
+            "modified": "2024-04-01T08:00:00Z",
+            "added": "2024-04-01T08:00:00Z",
+            "original_file_name": "scan.2024.pdf",
+            "permissions": {},
+            "mime_type": "application/pdf",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let template: PathTemplate = "{{ created_year }}/{correspondent}/{{ title|lower }}"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            template.placeholders().collect::<Vec<_>>(),
+            ["created_year", "correspondent"]
+        );
+        assert_eq!(
+            template.to_string(),
+            "{{ created_year }}/{correspondent}/{{ title|lower }}"
+        );
+        assert!(
+            "{% if tags %}{{ tags }}{% endif %}"
+                .parse::<PathTemplate>()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |s: &str| s.parse::<PathTemplate>().unwrap_err();
+        assert_eq!(error(" "), PathTemplateError::Empty);
+        assert_eq!(error("/{{ title }}"), PathTemplateError::Absolute);
+        assert_eq!(error("../{{ title }}"), PathTemplateError::ParentDirectory);
+        assert_eq!(
+            error("a:{{ title }}"),
+            PathTemplateError::IllegalCharacter(':')
+        );
+        assert_eq!(
+            error("{{ title"),
+            PathTemplateError::Unclosed("{{ title".to_string())
+        );
+        assert_eq!(
+            error("{{ name }}"),
+            PathTemplateError::UnknownPlaceholder("name".to_string())
+        );
+        assert_eq!(
+            error("{{ name|lower }}"),
+            PathTemplateError::UnknownPlaceholder("name".to_string())
+        );
+    }
+
+    #[test]
+    fn render() {
+        let template: PathTemplate =
+            "{{ created_year }}/{{ created_month_name_short }}/{{ correspondent }}/{{ title }}"
+                .parse()
+                .unwrap();
+        let names = PathNames::default();
+        assert_eq!(
+            template.render(&document(), &names).unwrap(),
+            "2024/Mar/none/Invoice 3-4"
+        );
+        let template: PathTemplate = "{{ added }}/{{ doc_pk }}-{{ original_name }}"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            template.render(&document(), &names).unwrap(),
+            "2024-04-01/0000042-scan.2024"
+        );
+        let template: PathTemplate = "{{ title|lower }}".parse().unwrap();
+        assert_eq!(
+            template.render(&document(), &names),
+            Err(PathTemplateError::Unsupported("title|lower".to_string()))
+        );
+    }
+}
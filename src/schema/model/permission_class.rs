@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum PermissionClass {
     #[serde(rename = "add_logentry")]
     LogEntryAdd,
@@ -146,4 +147,8 @@ pub enum PermissionClass {
     MailRuleDelete,
     #[serde(rename = "view_mailrule")]
     MailRuleView,
+    /// Value unknown to this version of the library; it can't be sent back
+    /// to the server.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
@@ -1,15 +1,19 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Permissions {
-    pub view: Option<PermissionsView>,
-    pub change: Option<PermissionsView>,
+model! {
+    #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Permissions {
+        pub view: Option<PermissionsView>,
+        pub change: Option<PermissionsView>,
+    }
 }
 
-#[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct PermissionsView {
-    pub users: Option<Vec<i32>>,
-    pub groups: Option<Vec<i32>>,
+model! {
+    #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct PermissionsView {
+        pub users: Option<Vec<super::UserId>>,
+        pub groups: Option<Vec<super::GroupId>>,
+    }
 }
 
 impl Permissions {
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+/// Permissions to assign to an object, as expected by the server on write;
+/// the read-side counterpart is [`Permissions`](super::Permissions).
+#[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermissionsForm {
+    pub owner: Option<super::UserId>,
+    pub set_permissions: SetPermissions,
+}
+
+/// Users and groups allowed to view and change an object. Unlike with the
+/// read-side model, all lists are always sent: an empty list revokes the
+/// corresponding permission from everyone but the owner.
+#[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SetPermissions {
+    pub view: PermissionsGrant,
+    pub change: PermissionsGrant,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermissionsGrant {
+    pub users: Vec<super::UserId>,
+    pub groups: Vec<super::GroupId>,
+}
+
+impl PermissionsForm {
+    #[must_use]
+    pub fn new(owner: Option<super::UserId>) -> Self {
+        Self {
+            owner,
+            set_permissions: SetPermissions::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn view_users(mut self, value: Vec<super::UserId>) -> Self {
+        self.set_permissions.view.users = value;
+        self
+    }
+
+    #[must_use]
+    pub fn view_groups(mut self, value: Vec<super::GroupId>) -> Self {
+        self.set_permissions.view.groups = value;
+        self
+    }
+
+    #[must_use]
+    pub fn change_users(mut self, value: Vec<super::UserId>) -> Self {
+        self.set_permissions.change.users = value;
+        self
+    }
+
+    #[must_use]
+    pub fn change_groups(mut self, value: Vec<super::GroupId>) -> Self {
+        self.set_permissions.change.groups = value;
+        self
+    }
+}
+
+impl SetPermissions {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<&super::Permissions> for SetPermissions {
+    fn from(permissions: &super::Permissions) -> Self {
+        Self {
+            view: permissions
+                .view
+                .as_ref()
+                .map(Into::into)
+                .unwrap_or_default(),
+            change: permissions
+                .change
+                .as_ref()
+                .map(Into::into)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&super::PermissionsView> for PermissionsGrant {
+    fn from(view: &super::PermissionsView) -> Self {
+        Self {
+            users: view.users.clone().unwrap_or_default(),
+            groups: view.groups.clone().unwrap_or_default(),
+        }
+    }
+}
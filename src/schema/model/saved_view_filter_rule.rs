@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// Rule of a saved view, as sent by the server, with its raw value; see
+/// [`FilterRule`](super::FilterRule) for its typed counterpart.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SavedViewFilterRule {
     pub rule_type: super::RuleType,
-    #[serde(default, skip_serializing_if = "String::is_empty")]
-    pub value: String,
+    #[serde(default)]
+    pub value: Option<String>,
 }
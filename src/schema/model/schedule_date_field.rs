@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum ScheduleDateField {
     #[serde(rename = "added")]
     Added,
@@ -10,4 +11,8 @@ pub enum ScheduleDateField {
     Modified,
     #[serde(rename = "custom_field")]
     CustomField,
+    /// Value unknown to this version of the library; it can't be sent back
+    /// to the server.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+model! {
+    /// Relevance information attached to documents returned by a search query,
+    /// such as a `more_like_id` listing.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct SearchHit {
+        pub score: Option<f64>,
+        #[serde(default)]
+        pub highlights: String,
+        #[serde(default)]
+        pub note_highlights: String,
+        pub rank: i32,
+    }
+}
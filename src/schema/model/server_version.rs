@@ -0,0 +1,131 @@
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Version of a Paperless-ngx server, such as `2.13.5`, as reported in the
+/// `X-Version` header of its responses. Versions compare in release order;
+/// a leading `v` and a pre-release suffix, such as in `v2.0.0-beta.rc1`, are
+/// accepted but ignored.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub struct ServerVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+#[error("invalid server version {0:?}, expected major.minor.patch")]
+pub struct ServerVersionParseError(pub String);
+
+/// Part of the API that isn't available on all the servers this crate can
+/// talk to; see [`ServerFeature::since`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ServerFeature {
+    ShareLinks,
+    CustomFields,
+    /// Workflows, which replaced consumption templates.
+    Workflows,
+}
+
+impl ServerVersion {
+    #[must_use]
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Whether this version is the given one or a later one.
+    #[must_use]
+    pub fn at_least(self, major: u32, minor: u32) -> bool {
+        self >= Self::new(major, minor, 0)
+    }
+
+    /// Whether a server of this version provides the given feature.
+    #[must_use]
+    pub fn supports(self, feature: ServerFeature) -> bool {
+        self >= feature.since()
+    }
+}
+
+impl ServerFeature {
+    /// First version of the server that provides the feature.
+    #[must_use]
+    pub const fn since(self) -> ServerVersion {
+        match self {
+            Self::ShareLinks => ServerVersion::new(1, 17, 0),
+            Self::CustomFields | Self::Workflows => ServerVersion::new(2, 0, 0),
+        }
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for ServerVersion {
+    type Err = ServerVersionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ServerVersionParseError(s.to_string());
+        let trimmed = s.trim();
+        let trimmed = trimmed.strip_prefix('v').unwrap_or(trimmed);
+        let release = trimmed.split(['-', '+']).next().unwrap_or_default();
+        let mut parts = release.split('.').map(str::parse::<u32>);
+        let mut next = || parts.next().transpose().map_err(|_| error());
+        let major = next()?.ok_or_else(error)?;
+        let minor = next()?.ok_or_else(error)?;
+        let patch = next()?.unwrap_or_default();
+        if next()?.is_some() {
+            return Err(error());
+        }
+        Ok(Self::new(major, minor, patch))
+    }
+}
+
+impl fmt::Display for ServerFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ShareLinks => "share links",
+            Self::CustomFields => "custom fields",
+            Self::Workflows => "workflows",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ServerFeature, ServerVersion, ServerVersionParseError};
+
+    #[test]
+    fn parse() {
+        let version = |s: &str| s.parse::<ServerVersion>();
+        assert_eq!(version("2.13.5"), Ok(ServerVersion::new(2, 13, 5)));
+        assert_eq!(version(" v1.17 "), Ok(ServerVersion::new(1, 17, 0)));
+        assert_eq!(version("2.0.0-beta.rc1"), Ok(ServerVersion::new(2, 0, 0)));
+        assert_eq!(version("2.1.0+build.7"), Ok(ServerVersion::new(2, 1, 0)));
+        for invalid in ["", "2", "2.", "2.x.0", "2.1.0.4", "-2.1.0", "v", "2..1"] {
+            assert_eq!(
+                version(invalid),
+                Err(ServerVersionParseError(invalid.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(ServerVersion::new(2, 10, 0) > ServerVersion::new(2, 9, 9));
+        assert!(ServerVersion::new(1, 17, 3).at_least(1, 17));
+        assert!(!ServerVersion::new(1, 16, 9).at_least(1, 17));
+        assert!(ServerVersion::new(2, 0, 0).supports(ServerFeature::Workflows));
+        assert!(!ServerVersion::new(1, 17, 0).supports(ServerFeature::CustomFields));
+        assert_eq!(ServerVersion::new(2, 13, 5).to_string(), "2.13.5");
+    }
+}
@@ -1,17 +1,52 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::fmt;
+use url::Url;
 
-#[readonly::make]
-#[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct ShareLink {
-    #[readonly]
-    pub id: i32,
-    #[readonly]
-    pub slug: String,
-    #[readonly]
-    pub created: String,
-    pub expiration: Option<String>,
-    pub document: Option<i32>,
-    pub file_version: Option<super::FileVersion>,
+model! {
+    #[readonly::make]
+    #[skip_serializing_none]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct ShareLink {
+        #[readonly]
+        pub id: super::ShareLinkId,
+        #[readonly]
+        pub slug: String,
+        #[readonly]
+        pub created: super::DateTime,
+        pub expiration: Option<super::DateTime>,
+        pub document: Option<super::DocumentId>,
+        pub file_version: Option<super::FileVersion>,
+    }
+}
+
+impl ShareLink {
+    /// Public URL of the link, on the server at the given base URL, such as
+    /// `https://paperless.example.com`.
+    #[must_use]
+    pub fn url(&self, base_url: &Url) -> Url {
+        let mut url = base_url.clone();
+        if let Ok(mut segments) = url.path_segments_mut() {
+            segments.pop_if_empty().extend(["share", &self.slug]);
+        }
+        url
+    }
+}
+
+/// The slug of the link and the document it shares, such as
+/// `a1b2c3 -> #123 (archive)`, with its expiration date, if any.
+impl fmt::Display for ShareLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.slug)?;
+        if let Some(document) = self.document {
+            write!(f, " -> #{document}")?;
+        }
+        if let Some(version) = self.file_version {
+            write!(f, " ({version})")?;
+        }
+        if let Some(expiration) = &self.expiration {
+            write!(f, ", expires {expiration}")?;
+        }
+        Ok(())
+    }
 }
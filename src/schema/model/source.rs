@@ -1,12 +1,10 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::schema::utils::repr_enum;
 
-#[repr(u8)]
-#[derive(
-    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize_repr, Deserialize_repr,
-)]
-pub enum Source {
-    ConsumeFolder = 1,
-    ApiUpload = 2,
-    MailFetch = 3,
-    WebUi = 4,
+repr_enum! {
+    pub enum Source: u8 {
+        ConsumeFolder = 1,
+        ApiUpload = 2,
+        MailFetch = 3,
+        WebUi = 4,
+    }
 }
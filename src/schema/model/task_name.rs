@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum TaskName {
     #[serde(rename = "consume_file")]
     ConsumeFile,
@@ -10,4 +12,20 @@ pub enum TaskName {
     CheckSanity,
     #[serde(rename = "index_optimize")]
     IndexOptimize,
+    /// Value unknown to this version of the library; it can't be sent back
+    /// to the server.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl fmt::Display for TaskName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ConsumeFile => "consume file",
+            Self::TrainClassifier => "train classifier",
+            Self::CheckSanity => "check sanity",
+            Self::IndexOptimize => "optimize index",
+            Self::Unknown => "unknown task",
+        })
+    }
 }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum TaskStatus {
     #[serde(rename = "FAILURE")]
     Failure,
@@ -16,4 +18,42 @@ pub enum TaskStatus {
     Started,
     #[serde(rename = "SUCCESS")]
     Success,
+    /// Value unknown to this version of the library; it can't be sent back
+    /// to the server.
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+impl TaskStatus {
+    /// Whether the task is over, and its status won't change anymore.
+    #[must_use]
+    pub fn is_terminal(self) -> bool {
+        matches!(self, Self::Success | Self::Failure | Self::Revoked)
+    }
+
+    #[must_use]
+    pub fn is_success(self) -> bool {
+        self == Self::Success
+    }
+
+    /// Whether the task failed or was revoked before completion.
+    #[must_use]
+    pub fn is_failure(self) -> bool {
+        matches!(self, Self::Failure | Self::Revoked)
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Failure => "failure",
+            Self::Pending => "pending",
+            Self::Received => "received",
+            Self::Retry => "retry",
+            Self::Revoked => "revoked",
+            Self::Started => "started",
+            Self::Success => "success",
+            Self::Unknown => "unknown",
+        })
+    }
 }
@@ -1,23 +1,61 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::fmt;
 
-#[readonly::make]
-#[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct TaskView {
-    #[readonly]
-    pub id: i32,
-    #[readonly]
-    pub related_document: Option<String>,
-    pub task_id: String,
-    pub task_name: Option<super::TaskName>,
-    pub task_file_name: Option<String>,
-    pub date_created: Option<String>,
-    pub date_done: Option<String>,
-    #[serde(rename = "type")]
-    pub view_type: Option<super::TaskViewType>,
-    pub status: Option<super::TaskStatus>,
-    pub result: Option<String>,
-    pub acknowledged: Option<bool>,
-    pub owner: Option<i32>,
+model! {
+    #[readonly::make]
+    #[skip_serializing_none]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct TaskView {
+        #[readonly]
+        pub id: super::TaskId,
+        #[readonly]
+        pub related_document: Option<String>,
+        pub task_id: String,
+        pub task_name: Option<super::TaskName>,
+        pub task_file_name: Option<String>,
+        pub date_created: Option<super::DateTime>,
+        pub date_done: Option<super::DateTime>,
+        #[serde(rename = "type")]
+        pub view_type: Option<super::TaskViewType>,
+        pub status: Option<super::TaskStatus>,
+        pub result: Option<String>,
+        pub acknowledged: Option<bool>,
+        pub owner: Option<super::UserId>,
+    }
+}
+
+impl TaskView {
+    /// Whether the task is over; a task whose status is missing or unknown
+    /// isn't.
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.status.is_some_and(super::TaskStatus::is_terminal)
+    }
+
+    /// Id of the document created by a successful consumption task.
+    #[must_use]
+    pub fn document_id(&self) -> Option<super::DocumentId> {
+        self.related_document.as_deref()?.parse().ok()
+    }
+}
+
+/// The file or the name of the task, and its status, such as
+/// `'scan.pdf': failure`, followed by the result of the task, if any.
+impl fmt::Display for TaskView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.task_file_name, self.task_name) {
+            (Some(file_name), _) => write!(f, "'{file_name}'")?,
+            (None, Some(name)) => write!(f, "{name}")?,
+            (None, None) => write!(f, "task {}", self.task_id)?,
+        }
+        match self.status {
+            Some(status) => write!(f, ": {status}")?,
+            None => f.write_str(": unknown")?,
+        }
+        match &self.result {
+            Some(result) if !result.is_empty() => write!(f, " ({result})"),
+            _ => Ok(()),
+        }
+    }
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum TaskViewType {
     #[serde(rename = "auto_task")]
     AutoTask,
@@ -8,4 +9,8 @@ pub enum TaskViewType {
     ScheduledTask,
     #[serde(rename = "manual_task")]
     ManualTask,
+    /// Value unknown to this version of the library; it can't be sent back
+    /// to the server.
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
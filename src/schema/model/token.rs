@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+model! {
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct AuthToken {
+        pub token: super::Secret,
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+model! {
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct TotpSecret {
+        /// `otpauth://` URI of the secret, for authenticator apps.
+        pub url: Url,
+        pub qr_svg: String,
+        pub secret: super::Secret,
+    }
+}
+
+model! {
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct TotpActivation {
+        pub success: bool,
+        #[serde(default)]
+        pub recovery_codes: Vec<String>,
+    }
+}
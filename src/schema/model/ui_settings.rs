@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+model! {
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct UiSettings {
+        pub user: UiSettingsUser,
+        pub settings: serde_json::Value,
+        #[serde(default)]
+        pub permissions: Vec<super::PermissionClass>,
+    }
+}
+
+model! {
+    #[readonly::make]
+    #[skip_serializing_none]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct UiSettingsUser {
+        #[readonly]
+        pub id: super::UserId,
+        pub username: String,
+        pub first_name: Option<String>,
+        pub last_name: Option<String>,
+        pub is_staff: bool,
+        pub is_superuser: bool,
+        #[serde(default)]
+        pub groups: Vec<super::GroupId>,
+    }
+}
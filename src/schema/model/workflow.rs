@@ -1,17 +1,19 @@
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-#[readonly::make]
-#[skip_serializing_none]
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Workflow {
-    #[readonly]
-    pub id: i32,
-    pub name: String,
-    pub(crate) triggers: Vec<super::WorkflowTrigger>,
-    pub(crate) actions: Vec<super::WorkflowAction>,
-    pub order: Option<i32>,
-    pub enabled: Option<bool>,
+model! {
+    #[readonly::make]
+    #[skip_serializing_none]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    pub struct Workflow {
+        #[readonly]
+        pub id: super::WorkflowId,
+        pub name: String,
+        pub(crate) triggers: Vec<super::WorkflowTrigger>,
+        pub(crate) actions: Vec<super::WorkflowAction>,
+        pub order: Option<i32>,
+        pub enabled: Option<bool>,
+    }
 }
 
 impl Workflow {
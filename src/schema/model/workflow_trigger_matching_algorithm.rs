@@ -1,14 +1,12 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::schema::utils::repr_enum;
 
-#[repr(u8)]
-#[derive(
-    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize_repr, Deserialize_repr,
-)]
-pub enum WorkflowTriggerMatchingAlgorithm {
-    None = 0,
-    AnyWord = 1,
-    AllWords = 2,
-    ExactMatch = 3,
-    RegularExpression = 4,
-    FuzzyWord = 5,
+repr_enum! {
+    pub enum WorkflowTriggerMatchingAlgorithm: u8 {
+        None = 0,
+        AnyWord = 1,
+        AllWords = 2,
+        ExactMatch = 3,
+        RegularExpression = 4,
+        FuzzyWord = 5,
+    }
 }
@@ -1,12 +1,10 @@
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use crate::schema::utils::repr_enum;
 
-#[repr(u8)]
-#[derive(
-    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize_repr, Deserialize_repr,
-)]
-pub enum WorkflowTriggerType {
-    ConsumptionStarted = 1,
-    DocumentAdded = 2,
-    DocumentUpdated = 3,
-    Scheduled = 4,
+repr_enum! {
+    pub enum WorkflowTriggerType: u8 {
+        ConsumptionStarted = 1,
+        DocumentAdded = 2,
+        DocumentUpdated = 3,
+        Scheduled = 4,
+    }
 }
@@ -1,5 +1,5 @@
-use serde::de::IntoDeserializer;
 use serde::Deserialize;
+use serde::de::IntoDeserializer;
 
 pub fn blank_enum<'de, T, D>(de: D) -> Result<Option<T>, D::Error>
 where
@@ -5,7 +5,7 @@ pub mod map {
     use crate::schema::model;
 
     pub fn serialize<S>(
-        values: &HashMap<i32, serde_json::Value>,
+        values: &HashMap<model::CustomFieldId, serde_json::Value>,
         serializer: S,
     ) -> Result<S::Ok, S::Error>
     where
@@ -21,7 +21,9 @@ pub mod map {
             .serialize(serializer)
     }
 
-    pub fn deserialize<'de, D>(de: D) -> Result<HashMap<i32, serde_json::Value>, D::Error>
+    pub fn deserialize<'de, D>(
+        de: D,
+    ) -> Result<HashMap<model::CustomFieldId, serde_json::Value>, D::Error>
     where
         D: Deserializer<'de>,
     {
@@ -36,9 +38,11 @@ pub mod map_option {
     use serde::{Deserializer, Serializer};
     use std::collections::HashMap;
 
+    use crate::schema::model;
+
     #[allow(clippy::ref_option)]
     pub fn serialize<S>(
-        values: &Option<HashMap<i32, serde_json::Value>>,
+        values: &Option<HashMap<model::CustomFieldId, serde_json::Value>>,
         serializer: S,
     ) -> Result<S::Ok, S::Error>
     where
@@ -51,7 +55,9 @@ pub mod map_option {
         }
     }
 
-    pub fn deserialize<'de, D>(de: D) -> Result<Option<HashMap<i32, serde_json::Value>>, D::Error>
+    pub fn deserialize<'de, D>(
+        de: D,
+    ) -> Result<Option<HashMap<model::CustomFieldId, serde_json::Value>>, D::Error>
     where
         D: Deserializer<'de>,
     {
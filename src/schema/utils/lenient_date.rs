@@ -0,0 +1,20 @@
+//! Deserializes an optional [`Date`](model::Date) that older servers send as
+//! a full date and time, such as the `created` field of documents before it
+//! became a plain date; only the date part is kept, as sent by the server.
+
+use serde::{Deserialize, Deserializer};
+
+use crate::schema::model;
+
+pub fn deserialize<'de, D>(de: D) -> Result<Option<model::Date>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(value) = Option::<String>::deserialize(de)? else {
+        return Ok(None);
+    };
+    let date = value
+        .split_once('T')
+        .map_or(value.as_str(), |(date, _)| date);
+    date.parse().map(Some).map_err(serde::de::Error::custom)
+}
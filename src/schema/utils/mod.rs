@@ -1,6 +1,12 @@
 mod blank_enum;
 pub(crate) use blank_enum::*;
+mod order_by;
+pub(crate) use order_by::*;
+mod repr_enum;
+pub(crate) use repr_enum::*;
 
 pub(crate) mod comma_list;
 pub(crate) mod custom_fields;
-pub(crate) mod saved_view_filter_rules;
+pub(crate) mod lenient_date;
+#[cfg(all(feature = "unknown-fields", feature = "strict"))]
+pub(crate) mod unknown_fields;
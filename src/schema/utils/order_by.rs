@@ -0,0 +1,37 @@
+/// Defines the enum of the fields by which a listing can be ordered, with
+/// the name of each field in the `ordering` parameter.
+macro_rules! order_by {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident => $field:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(match self {
+                    $(Self::$variant => $field,)*
+                })
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = $crate::schema::api::OrderingParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($field => Ok(Self::$variant),)*
+                    _ => Err($crate::schema::api::OrderingParseError(s.to_string())),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use order_by;
@@ -0,0 +1,58 @@
+/// Defines an enum represented by an integer on the wire, like `serde_repr`
+/// does, with an additional `Unknown` variant that holds the values that
+/// aren't known to this version of the library, so that a new value on the
+/// server doesn't fail the decoding of the whole response.
+macro_rules! repr_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident: $repr:ty {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[non_exhaustive]
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// Value unknown to this version of the library.
+            ///
+            /// It must only hold values that none of the other variants stand
+            /// for: an `Unknown` holding a known value serializes like the
+            /// corresponding variant, but doesn't compare equal to it. To
+            /// convert an integer, use `From`, which picks the right variant.
+            Unknown($repr),
+        }
+
+        impl From<$repr> for $name {
+            fn from(value: $repr) -> Self {
+                match value {
+                    $($value => Self::$variant,)*
+                    _ => Self::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$name> for $repr {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                <$repr>::from(*self).serialize(serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <$repr as serde::Deserialize>::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+pub(crate) use repr_enum;
@@ -0,0 +1,41 @@
+//! Deserializes the `extra` map of the models with both the `unknown-fields`
+//! and the `strict` features: as without `unknown-fields`, unknown fields make
+//! decoding fail, instead of being kept.
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
+
+pub fn deserialize<'de, D>(de: D) -> Result<HashMap<String, serde_json::Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let extra = HashMap::<String, serde_json::Value>::deserialize(de)?;
+    if extra.is_empty() {
+        return Ok(extra);
+    }
+    let mut unknown: Vec<_> = extra.into_keys().collect();
+    unknown.sort();
+    Err(D::Error::custom(format!(
+        "unknown fields: {}",
+        unknown.join(", ")
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::model::Actor;
+    use crate::utils::deserialize_strict;
+
+    #[test]
+    fn strict() {
+        let decode = |json: &str| {
+            deserialize_strict::<_, Actor>(&mut serde_json::Deserializer::from_str(json))
+                .map_err(|e| e.to_string())
+        };
+        let actor = decode(r#"{"id": 1, "username": "alice"}"#).unwrap();
+        assert!(actor.extra.is_empty());
+        let error = decode(r#"{"id": 1, "username": "alice", "b": 2, "a": 1}"#).unwrap_err();
+        assert!(error.contains("unknown fields: a, b"), "{error}");
+    }
+}
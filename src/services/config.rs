@@ -9,16 +9,24 @@ use crate::utils::{Method, body, params};
 
 pub type Item = ApplicationConfiguration;
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait Config<E = ()> {
+    async fn list(&self) -> Result<Response<Vec<Item>, E>>;
     async fn create(&self, body: &Create) -> Result<Response<Item, E>>;
     async fn retrieve(&self) -> Result<Response<Item, E>>;
     async fn patch(&self, body: &Patch) -> Result<Response<Item, E>>;
     async fn destroy(&self) -> Result<Response<(), E>>;
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<C: Client> Config<C::Extra> for C {
+    async fn list(&self) -> Result<Response<Vec<Item>, C::Extra>> {
+        self.request_json(Method::GET, "/api/config/", params::NONE, body::NONE)
+            .await
+    }
+
     async fn create(&self, body: &Create) -> Result<Response<Item, C::Extra>> {
         self.request_json(Method::POST, "/api/config/", params::NONE, Some(body))
             .await
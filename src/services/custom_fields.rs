@@ -2,19 +2,21 @@ use crate::clients::Client;
 use crate::error::Result;
 use crate::response::Response;
 use crate::schema::api::custom_fields::{Create, List, Patch};
-use crate::schema::model::{CustomField, Paginated};
+use crate::schema::model::{CustomField, CustomFieldId, Paginated, ServerFeature};
+use crate::services::require;
 use crate::utils::{Method, body, params};
 use async_trait::async_trait;
 
 pub type Item = CustomField;
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait CustomFields<E = ()> {
     async fn list(&self, params: &List) -> Result<Response<Paginated<Item>, E>>;
     async fn create(&self, body: &Create) -> Result<Response<Item, E>>;
-    async fn retrieve(&self, id: i32) -> Result<Response<Item, E>>;
-    async fn patch(&self, id: i32, body: &Patch) -> Result<Response<Item, E>>;
-    async fn destroy(&self, id: i32) -> Result<Response<(), E>>;
+    async fn retrieve(&self, id: CustomFieldId) -> Result<Response<Item, E>>;
+    async fn patch(&self, id: CustomFieldId, body: &Patch) -> Result<Response<Item, E>>;
+    async fn destroy(&self, id: CustomFieldId) -> Result<Response<(), E>>;
 
     async fn previous_page(
         &self,
@@ -24,36 +26,47 @@ pub trait CustomFields<E = ()> {
         &self,
         current: &Paginated<Item>,
     ) -> Result<Option<Response<Paginated<Item>, E>>>;
+    async fn page(
+        &self,
+        current: &Paginated<Item>,
+        number: u32,
+    ) -> Result<Option<Response<Paginated<Item>, E>>>;
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<C: Client> CustomFields<C::Extra> for C {
     async fn list(&self, params: &List) -> Result<Response<Paginated<Item>, C::Extra>> {
         let path = "/api/custom_fields/";
+        require(self, ServerFeature::CustomFields, Method::GET, path)?;
         self.request_json(Method::GET, path, params, body::NONE)
             .await
     }
 
     async fn create(&self, body: &Create) -> Result<Response<Item, C::Extra>> {
         let path = "/api/custom_fields/";
+        require(self, ServerFeature::CustomFields, Method::POST, path)?;
         self.request_json(Method::POST, path, params::NONE, Some(body))
             .await
     }
 
-    async fn retrieve(&self, id: i32) -> Result<Response<Item, C::Extra>> {
+    async fn retrieve(&self, id: CustomFieldId) -> Result<Response<Item, C::Extra>> {
         let path = format!("/api/custom_fields/{id}/");
+        require(self, ServerFeature::CustomFields, Method::GET, &path)?;
         self.request_json(Method::GET, &path, params::NONE, body::NONE)
             .await
     }
 
-    async fn patch(&self, id: i32, body: &Patch) -> Result<Response<Item, C::Extra>> {
+    async fn patch(&self, id: CustomFieldId, body: &Patch) -> Result<Response<Item, C::Extra>> {
         let path = format!("/api/custom_fields/{id}/");
+        require(self, ServerFeature::CustomFields, Method::PATCH, &path)?;
         self.request_json(Method::PATCH, &path, params::NONE, Some(body))
             .await
     }
 
-    async fn destroy(&self, id: i32) -> Result<Response<(), C::Extra>> {
+    async fn destroy(&self, id: CustomFieldId) -> Result<Response<(), C::Extra>> {
         let path = format!("/api/custom_fields/{id}/");
+        require(self, ServerFeature::CustomFields, Method::DELETE, &path)?;
         self.request_json(Method::DELETE, &path, params::NONE, body::NONE)
             .await
     }
@@ -71,4 +84,12 @@ impl<C: Client> CustomFields<C::Extra> for C {
     ) -> Result<Option<Response<Paginated<Item>, C::Extra>>> {
         C::next_page(self, current).await
     }
+
+    async fn page(
+        &self,
+        current: &Paginated<Item>,
+        number: u32,
+    ) -> Result<Option<Response<Paginated<Item>, C::Extra>>> {
+        C::page(self, current, number).await
+    }
 }
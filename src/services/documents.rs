@@ -1,30 +1,54 @@
-use crate::clients::Client;
+use crate::clients::{ByteStream, Client};
 use crate::error::Result;
+use crate::multipart::Part;
 use crate::response::Response;
-use crate::schema::api::documents::{History, List, Patch};
+use crate::schema::api::documents::{History, List, Patch, Upload};
 use crate::schema::model::{
-    Document, DocumentMetadata, LogEntry, Paginated, ShareLink, Suggestions,
+    Asn, Document, DocumentId, DocumentMetadata, DocumentSummary, LogEntry, Paginated,
+    PartialDocument, ServerFeature, ShareLink, Suggestions,
 };
+use crate::services::require;
 use crate::utils::{Method, body, params};
 use async_trait::async_trait;
 use bytes::Bytes;
 
 pub type Item = Document;
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait Documents<E = ()> {
     async fn list(&self, params: &List) -> Result<Response<Paginated<Item>, E>>;
-    async fn retrieve(&self, id: i32) -> Result<Response<Item, E>>;
-    async fn patch(&self, id: i32, body: &Patch) -> Result<Response<Item, E>>;
-    async fn destroy(&self, id: i32) -> Result<Response<(), E>>;
-    async fn preview(&self, id: i32) -> Result<Response<Bytes, E>>;
-
-    async fn thumbnail(&self, id: i32) -> Result<Response<Bytes, E>>;
-    async fn download(&self, id: i32, original: Option<bool>) -> Result<Response<Bytes, E>>;
-    async fn history(&self, id: i32, params: &History) -> Result<Response<Paginated<LogEntry>, E>>;
-    async fn metadata(&self, id: i32) -> Result<Response<DocumentMetadata, E>>;
-    async fn share_links(&self, id: i32) -> Result<Response<Vec<ShareLink>, E>>;
-    async fn sugestions(&self, id: i32) -> Result<Response<Suggestions, E>>;
+    /// Lists the documents without their content, which is by far the
+    /// largest part of a document; the `fields` parameter is ignored.
+    async fn list_summaries(
+        &self,
+        params: &List,
+    ) -> Result<Response<Paginated<DocumentSummary>, E>>;
+    /// Lists the documents with only the fields selected by the `fields`
+    /// parameter, such as `id`, `title` and `tags`.
+    async fn list_partial(&self, params: &List) -> Result<Response<Paginated<PartialDocument>, E>>;
+    async fn retrieve(&self, id: DocumentId) -> Result<Response<Item, E>>;
+    async fn patch(&self, id: DocumentId, body: &Patch) -> Result<Response<Item, E>>;
+    async fn destroy(&self, id: DocumentId) -> Result<Response<(), E>>;
+    async fn preview(&self, id: DocumentId) -> Result<Response<Bytes, E>>;
+
+    async fn thumbnail(&self, id: DocumentId) -> Result<Response<Bytes, E>>;
+    async fn download(&self, id: DocumentId, original: Option<bool>) -> Result<Response<Bytes, E>>;
+    async fn download_stream(
+        &self,
+        id: DocumentId,
+        original: Option<bool>,
+    ) -> Result<Response<ByteStream, E>>;
+    async fn history(
+        &self,
+        id: DocumentId,
+        params: &History,
+    ) -> Result<Response<Paginated<LogEntry>, E>>;
+    async fn metadata(&self, id: DocumentId) -> Result<Response<DocumentMetadata, E>>;
+    async fn share_links(&self, id: DocumentId) -> Result<Response<Vec<ShareLink>, E>>;
+    async fn sugestions(&self, id: DocumentId) -> Result<Response<Suggestions, E>>;
+    async fn next_asn(&self) -> Result<Response<Asn, E>>;
+    async fn post_document(&self, params: &Upload, document: Part) -> Result<Response<String, E>>;
 
     async fn previous_page(
         &self,
@@ -34,9 +58,15 @@ pub trait Documents<E = ()> {
         &self,
         current: &Paginated<Item>,
     ) -> Result<Option<Response<Paginated<Item>, E>>>;
+    async fn page(
+        &self,
+        current: &Paginated<Item>,
+        number: u32,
+    ) -> Result<Option<Response<Paginated<Item>, E>>>;
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<C: Client> Documents<C::Extra> for C {
     async fn list(&self, params: &List) -> Result<Response<Paginated<Item>, C::Extra>> {
         let path = "/api/document/";
@@ -44,47 +74,82 @@ impl<C: Client> Documents<C::Extra> for C {
             .await
     }
 
-    async fn retrieve(&self, id: i32) -> Result<Response<Item, C::Extra>> {
+    async fn list_summaries(
+        &self,
+        params: &List,
+    ) -> Result<Response<Paginated<DocumentSummary>, C::Extra>> {
+        let path = "/api/document/";
+        let fields = DocumentSummary::FIELDS.iter().map(ToString::to_string);
+        let params = params.clone().fields(fields.collect());
+        self.request_json(Method::GET, path, &params, body::NONE)
+            .await
+    }
+
+    async fn list_partial(
+        &self,
+        params: &List,
+    ) -> Result<Response<Paginated<PartialDocument>, C::Extra>> {
+        let path = "/api/document/";
+        self.request_json(Method::GET, path, params, body::NONE)
+            .await
+    }
+
+    async fn retrieve(&self, id: DocumentId) -> Result<Response<Item, C::Extra>> {
         let path = format!("/api/document/{id}/");
         let params = vec![("full_perms", true)];
         self.request_json(Method::GET, &path, &params, body::NONE)
             .await
     }
 
-    async fn patch(&self, id: i32, body: &Patch) -> Result<Response<Item, C::Extra>> {
+    async fn patch(&self, id: DocumentId, body: &Patch) -> Result<Response<Item, C::Extra>> {
         let path = format!("/api/document/{id}/");
         self.request_json(Method::PATCH, &path, params::NONE, Some(body))
             .await
     }
 
-    async fn destroy(&self, id: i32) -> Result<Response<(), C::Extra>> {
+    async fn destroy(&self, id: DocumentId) -> Result<Response<(), C::Extra>> {
         let path = format!("/api/document/{id}/");
         self.request_unit(Method::DELETE, &path, params::NONE, body::NONE)
             .await
     }
 
-    async fn preview(&self, id: i32) -> Result<Response<Bytes, C::Extra>> {
+    async fn preview(&self, id: DocumentId) -> Result<Response<Bytes, C::Extra>> {
         let path = format!("/api/document/{id}/preview");
         self.request_bytes(Method::GET, &path, params::NONE, body::NONE)
             .await
     }
 
-    async fn thumbnail(&self, id: i32) -> Result<Response<Bytes, C::Extra>> {
+    async fn thumbnail(&self, id: DocumentId) -> Result<Response<Bytes, C::Extra>> {
         let path = format!("/api/document/{id}/thumb");
         self.request_bytes(Method::GET, &path, params::NONE, body::NONE)
             .await
     }
 
-    async fn download(&self, id: i32, original: Option<bool>) -> Result<Response<Bytes, C::Extra>> {
+    async fn download(
+        &self,
+        id: DocumentId,
+        original: Option<bool>,
+    ) -> Result<Response<Bytes, C::Extra>> {
         let path = format!("/api/document/{id}/download");
         let params = original.map(|o| vec![("original", o)]);
         self.request_bytes(Method::GET, &path, &params, body::NONE)
             .await
     }
 
+    async fn download_stream(
+        &self,
+        id: DocumentId,
+        original: Option<bool>,
+    ) -> Result<Response<ByteStream, C::Extra>> {
+        let path = format!("/api/document/{id}/download");
+        let params = original.map(|o| vec![("original", o)]);
+        self.request_stream(Method::GET, &path, &params, body::NONE)
+            .await
+    }
+
     async fn history(
         &self,
-        id: i32,
+        id: DocumentId,
         params: &History,
     ) -> Result<Response<Paginated<LogEntry>, C::Extra>> {
         let path = format!("/api/document/{id}/history");
@@ -92,24 +157,41 @@ impl<C: Client> Documents<C::Extra> for C {
             .await
     }
 
-    async fn metadata(&self, id: i32) -> Result<Response<DocumentMetadata, C::Extra>> {
+    async fn metadata(&self, id: DocumentId) -> Result<Response<DocumentMetadata, C::Extra>> {
         let path = format!("/api/document/{id}/metadata");
         self.request_json(Method::GET, &path, params::NONE, body::NONE)
             .await
     }
 
-    async fn share_links(&self, id: i32) -> Result<Response<Vec<ShareLink>, C::Extra>> {
+    async fn share_links(&self, id: DocumentId) -> Result<Response<Vec<ShareLink>, C::Extra>> {
         let path = format!("/api/document/{id}/share_links");
+        require(self, ServerFeature::ShareLinks, Method::GET, &path)?;
         self.request_json(Method::GET, &path, params::NONE, body::NONE)
             .await
     }
 
-    async fn sugestions(&self, id: i32) -> Result<Response<Suggestions, C::Extra>> {
+    async fn sugestions(&self, id: DocumentId) -> Result<Response<Suggestions, C::Extra>> {
         let path = format!("/api/document/{id}/suggestions");
         self.request_json(Method::GET, &path, params::NONE, body::NONE)
             .await
     }
 
+    async fn next_asn(&self) -> Result<Response<Asn, C::Extra>> {
+        let path = "/api/documents/next_asn/";
+        self.request_json(Method::GET, path, params::NONE, body::NONE)
+            .await
+    }
+
+    async fn post_document(
+        &self,
+        params: &Upload,
+        document: Part,
+    ) -> Result<Response<String, C::Extra>> {
+        let path = "/api/documents/post_document/";
+        self.request_multipart(Method::POST, path, params::NONE, params.form(document))
+            .await
+    }
+
     async fn previous_page(
         &self,
         current: &Paginated<Item>,
@@ -123,4 +205,12 @@ impl<C: Client> Documents<C::Extra> for C {
     ) -> Result<Option<Response<Paginated<Item>, C::Extra>>> {
         C::next_page(self, current).await
     }
+
+    async fn page(
+        &self,
+        current: &Paginated<Item>,
+        number: u32,
+    ) -> Result<Option<Response<Paginated<Item>, C::Extra>>> {
+        C::page(self, current, number).await
+    }
 }
@@ -4,13 +4,15 @@ use crate::response::Response;
 use crate::utils::{Method, body, params};
 use async_trait::async_trait;
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait Logs<E = ()> {
     async fn list(&self) -> Result<Response<Vec<String>, E>>;
     async fn retrieve(&self, log_type: &str) -> Result<Response<Vec<String>, E>>;
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<C: Client> Logs<C::Extra> for C {
     async fn list(&self) -> Result<Response<Vec<String>, C::Extra>> {
         let path = "/api/logs/";
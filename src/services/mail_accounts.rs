@@ -2,21 +2,22 @@ use crate::clients::Client;
 use crate::error::Result;
 use crate::response::Response;
 use crate::schema::api::mail_accounts::{Create, List, Patch};
-use crate::schema::model::{MailAccount, Paginated};
+use crate::schema::model::{MailAccount, MailAccountId, Paginated};
 use crate::utils::{Method, body, params};
 use async_trait::async_trait;
 
 pub type Item = MailAccount;
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait MailAccounts<E> {
     async fn list(&self, params: &List) -> Result<Response<Paginated<Item>, E>>;
     async fn test(&self, body: &Create) -> Result<Response<Item, E>>;
     async fn create(&self, body: &Create) -> Result<Response<Item, E>>;
-    async fn retrieve(&self, id: i32) -> Result<Response<Item, E>>;
-    async fn patch(&self, id: i32, body: &Patch) -> Result<Response<Item, E>>;
-    async fn destroy(&self, id: i32) -> Result<Response<(), E>>;
-    async fn process(&self, id: i32) -> Result<Response<(), E>>;
+    async fn retrieve(&self, id: MailAccountId) -> Result<Response<Item, E>>;
+    async fn patch(&self, id: MailAccountId, body: &Patch) -> Result<Response<Item, E>>;
+    async fn destroy(&self, id: MailAccountId) -> Result<Response<(), E>>;
+    async fn process(&self, id: MailAccountId) -> Result<Response<(), E>>;
 
     async fn previous_page(
         &self,
@@ -26,9 +27,15 @@ pub trait MailAccounts<E> {
         &self,
         current: &Paginated<Item>,
     ) -> Result<Option<Response<Paginated<Item>, E>>>;
+    async fn page(
+        &self,
+        current: &Paginated<Item>,
+        number: u32,
+    ) -> Result<Option<Response<Paginated<Item>, E>>>;
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<C: Client> MailAccounts<C::Extra> for C {
     async fn list(&self, params: &List) -> Result<Response<Paginated<Item>, C::Extra>> {
         let path = "/api/mail_accounts/";
@@ -48,25 +55,25 @@ impl<C: Client> MailAccounts<C::Extra> for C {
             .await
     }
 
-    async fn retrieve(&self, id: i32) -> Result<Response<Item, C::Extra>> {
+    async fn retrieve(&self, id: MailAccountId) -> Result<Response<Item, C::Extra>> {
         let path = format!("/api/mail_accounts/{id}/");
         self.request_json(Method::GET, &path, params::NONE, body::NONE)
             .await
     }
 
-    async fn patch(&self, id: i32, body: &Patch) -> Result<Response<Item, C::Extra>> {
+    async fn patch(&self, id: MailAccountId, body: &Patch) -> Result<Response<Item, C::Extra>> {
         let path = format!("/api/mail_accounts/{id}/");
         self.request_json(Method::PATCH, &path, params::NONE, Some(body))
             .await
     }
 
-    async fn destroy(&self, id: i32) -> Result<Response<(), C::Extra>> {
+    async fn destroy(&self, id: MailAccountId) -> Result<Response<(), C::Extra>> {
         let path = format!("/api/mail_accounts/{id}/");
         self.request_unit(Method::DELETE, &path, params::NONE, body::NONE)
             .await
     }
 
-    async fn process(&self, id: i32) -> Result<Response<(), C::Extra>> {
+    async fn process(&self, id: MailAccountId) -> Result<Response<(), C::Extra>> {
         let path = format!("/api/mail_accounts/{id}/process/");
         self.request_unit(Method::POST, &path, params::NONE, body::NONE)
             .await
@@ -85,4 +92,12 @@ impl<C: Client> MailAccounts<C::Extra> for C {
     ) -> Result<Option<Response<Paginated<Item>, C::Extra>>> {
         C::next_page(self, current).await
     }
+
+    async fn page(
+        &self,
+        current: &Paginated<Item>,
+        number: u32,
+    ) -> Result<Option<Response<Paginated<Item>, C::Extra>>> {
+        C::page(self, current, number).await
+    }
 }
@@ -2,19 +2,20 @@ use crate::clients::Client;
 use crate::error::Result;
 use crate::response::Response;
 use crate::schema::api::mail_rules::{Create, List, Patch};
-use crate::schema::model::{MailRule, Paginated};
+use crate::schema::model::{MailRule, MailRuleId, Paginated};
 use crate::utils::{Method, body, params};
 use async_trait::async_trait;
 
 pub type Item = MailRule;
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait MailRules<E = ()> {
     async fn list(&self, params: &List) -> Result<Response<Paginated<Item>, E>>;
     async fn create(&self, body: &Create) -> Result<Response<Item, E>>;
-    async fn retrieve(&self, id: i32) -> Result<Response<Item, E>>;
-    async fn patch(&self, id: i32, body: &Patch) -> Result<Response<Item, E>>;
-    async fn destroy(&self, id: i32) -> Result<Response<(), E>>;
+    async fn retrieve(&self, id: MailRuleId) -> Result<Response<Item, E>>;
+    async fn patch(&self, id: MailRuleId, body: &Patch) -> Result<Response<Item, E>>;
+    async fn destroy(&self, id: MailRuleId) -> Result<Response<(), E>>;
 
     async fn previous_page(
         &self,
@@ -24,9 +25,15 @@ pub trait MailRules<E = ()> {
         &self,
         current: &Paginated<Item>,
     ) -> Result<Option<Response<Paginated<Item>, E>>>;
+    async fn page(
+        &self,
+        current: &Paginated<Item>,
+        number: u32,
+    ) -> Result<Option<Response<Paginated<Item>, E>>>;
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<C: Client> MailRules<C::Extra> for C {
     async fn list(&self, params: &List) -> Result<Response<Paginated<Item>, C::Extra>> {
         let path = "/api/mail_rules/";
@@ -41,19 +48,19 @@ impl<C: Client> MailRules<C::Extra> for C {
             .await
     }
 
-    async fn retrieve(&self, id: i32) -> Result<Response<Item, C::Extra>> {
+    async fn retrieve(&self, id: MailRuleId) -> Result<Response<Item, C::Extra>> {
         let path = format!("/api/mail_rules/{id}/");
         self.request_json(Method::GET, &path, params::NONE, body::NONE)
             .await
     }
 
-    async fn patch(&self, id: i32, body: &Patch) -> Result<Response<Item, C::Extra>> {
+    async fn patch(&self, id: MailRuleId, body: &Patch) -> Result<Response<Item, C::Extra>> {
         let path = format!("/api/mail_rules/{id}/");
         self.request_json(Method::PATCH, &path, params::NONE, Some(body))
             .await
     }
 
-    async fn destroy(&self, id: i32) -> Result<Response<(), C::Extra>> {
+    async fn destroy(&self, id: MailRuleId) -> Result<Response<(), C::Extra>> {
         let path = format!("/api/mail_rules/{id}/");
         self.request_unit(Method::DELETE, &path, params::NONE, body::NONE)
             .await
@@ -72,4 +79,12 @@ impl<C: Client> MailRules<C::Extra> for C {
     ) -> Result<Option<Response<Paginated<Item>, C::Extra>>> {
         C::next_page(self, current).await
     }
+
+    async fn page(
+        &self,
+        current: &Paginated<Item>,
+        number: u32,
+    ) -> Result<Option<Response<Paginated<Item>, C::Extra>>> {
+        C::page(self, current, number).await
+    }
 }
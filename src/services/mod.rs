@@ -18,8 +18,12 @@ mod mail_rules;
 pub use mail_rules::MailRules;
 mod profile;
 pub use profile::Profile;
+mod raw;
+pub use raw::Raw;
 mod saved_views;
 pub use saved_views::SavedViews;
+mod search;
+pub use search::Search;
 mod share_links;
 pub use share_links::ShareLinks;
 mod storage_paths;
@@ -28,7 +32,35 @@ mod tags;
 pub use tags::Tags;
 mod tasks;
 pub use tasks::Tasks;
+mod token;
+pub use token::Token;
+mod ui_settings;
+pub use ui_settings::UiSettings;
 mod users;
 pub use users::Users;
 mod workflows;
 pub use workflows::Workflows;
+
+use crate::clients::Client;
+use crate::error::{Error, Result};
+use crate::schema::model::ServerFeature;
+use crate::utils::Method;
+
+/// Fails with [`Error::Unsupported`] if the server is known not to provide
+/// the given feature; see [`Client::server_version`].
+pub(crate) fn require<C: Client>(
+    client: &C,
+    feature: ServerFeature,
+    method: Method,
+    endpoint: &str,
+) -> Result<()> {
+    match client.server_version() {
+        Some(version) if !version.supports(feature) => Err(Error::Unsupported {
+            method,
+            endpoint: endpoint.to_string(),
+            feature,
+            version,
+        }),
+        _ => Ok(()),
+    }
+}
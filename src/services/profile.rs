@@ -1,20 +1,36 @@
 use crate::clients::Client;
 use crate::error::Result;
 use crate::response::Response;
-use crate::schema::api::profile::Patch;
+use crate::schema::api::profile::{ActivateTotp, Patch};
 use crate::schema::model;
 use crate::utils::{Method, body, params};
 use async_trait::async_trait;
+use serde::Serialize;
 
 type Item = model::Profile;
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait Profile<E = ()> {
     async fn retrieve(&self) -> Result<Response<Item, E>>;
     async fn patch(&self, body: &Patch) -> Result<Response<Item, E>>;
+    async fn generate_auth_token(&self) -> Result<Response<model::Secret, E>>;
+
+    async fn totp_secret(&self) -> Result<Response<model::TotpSecret, E>>;
+    async fn activate_totp(
+        &self,
+        body: &ActivateTotp,
+    ) -> Result<Response<model::TotpActivation, E>>;
+    async fn deactivate_totp(&self) -> Result<Response<(), E>>;
+
+    async fn disconnect_social_account(
+        &self,
+        id: model::SocialAccountId,
+    ) -> Result<Response<model::SocialAccountId, E>>;
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<C: Client> Profile<C::Extra> for C {
     async fn retrieve(&self) -> Result<Response<Item, C::Extra>> {
         let path = "/api/profile/";
@@ -27,4 +43,46 @@ impl<C: Client> Profile<C::Extra> for C {
         self.request_json(Method::PATCH, path, params::NONE, Some(body))
             .await
     }
+
+    async fn generate_auth_token(&self) -> Result<Response<model::Secret, C::Extra>> {
+        let path = "/api/profile/generate_auth_token/";
+        self.request_json(Method::POST, path, params::NONE, body::NONE)
+            .await
+    }
+
+    async fn totp_secret(&self) -> Result<Response<model::TotpSecret, C::Extra>> {
+        let path = "/api/profile/totp/";
+        self.request_json(Method::GET, path, params::NONE, body::NONE)
+            .await
+    }
+
+    async fn activate_totp(
+        &self,
+        body: &ActivateTotp,
+    ) -> Result<Response<model::TotpActivation, C::Extra>> {
+        let path = "/api/profile/totp/";
+        self.request_json(Method::POST, path, params::NONE, Some(body))
+            .await
+    }
+
+    async fn deactivate_totp(&self) -> Result<Response<(), C::Extra>> {
+        let path = "/api/profile/totp/";
+        self.request_unit(Method::DELETE, path, params::NONE, body::NONE)
+            .await
+    }
+
+    async fn disconnect_social_account(
+        &self,
+        id: model::SocialAccountId,
+    ) -> Result<Response<model::SocialAccountId, C::Extra>> {
+        let path = "/api/profile/disconnect_social_account/";
+        let body = DisconnectSocialAccountInput { id };
+        self.request_json(Method::POST, path, params::NONE, Some(&body))
+            .await
+    }
+}
+
+#[derive(Serialize)]
+struct DisconnectSocialAccountInput {
+    id: model::SocialAccountId,
 }
@@ -0,0 +1,72 @@
+use crate::clients::Client;
+use crate::error::Result;
+use crate::response::Response;
+use crate::utils::Method;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Escape hatch for the endpoints that this library doesn't model yet: the
+/// requests are sent with the authentication and error handling of the
+/// client, and return its `Extra` information, but their parameters, body
+/// and result are chosen by the caller. The path is the full path of the
+/// endpoint, such as `/api/remote_version/`.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait Raw<E = ()> {
+    async fn call<P, B, T>(
+        &self,
+        method: Method,
+        path: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<T, E>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+        T: for<'a> Deserialize<'a>;
+
+    /// Like [`Raw::call`], but returns the response as an untyped JSON value.
+    async fn call_json<P, B>(
+        &self,
+        method: Method,
+        path: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<serde_json::Value, E>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<C: Client> Raw<C::Extra> for C {
+    async fn call<P, B, T>(
+        &self,
+        method: Method,
+        path: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<T, C::Extra>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+        T: for<'a> Deserialize<'a>,
+    {
+        self.request_json(method, path, params, body).await
+    }
+
+    async fn call_json<P, B>(
+        &self,
+        method: Method,
+        path: &str,
+        params: &P,
+        body: Option<&B>,
+    ) -> Result<Response<serde_json::Value, C::Extra>>
+    where
+        P: Serialize + Sync,
+        B: Serialize + Sync,
+    {
+        self.request_json(method, path, params, body).await
+    }
+}
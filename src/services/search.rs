@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+
+use crate::clients::Client;
+use crate::error::Result;
+use crate::response::Response;
+use crate::schema::api::search::{Autocomplete, Global};
+use crate::schema::model::GlobalSearchResult;
+use crate::utils::{Method, body};
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait Search<E = ()> {
+    async fn global(&self, params: &Global) -> Result<Response<GlobalSearchResult, E>>;
+    async fn autocomplete(&self, params: &Autocomplete) -> Result<Response<Vec<String>, E>>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<C: Client> Search<C::Extra> for C {
+    async fn global(&self, params: &Global) -> Result<Response<GlobalSearchResult, C::Extra>> {
+        let path = "/api/search/";
+        self.request_json(Method::GET, path, params, body::NONE)
+            .await
+    }
+
+    async fn autocomplete(&self, params: &Autocomplete) -> Result<Response<Vec<String>, C::Extra>> {
+        let path = "/api/search/autocomplete/";
+        self.request_json(Method::GET, path, params, body::NONE)
+            .await
+    }
+}
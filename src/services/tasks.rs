@@ -5,20 +5,22 @@ use crate::clients::Client;
 use crate::error::Result;
 use crate::response::Response;
 use crate::schema::api::tasks::{Create, List};
-use crate::schema::model::TaskView;
+use crate::schema::model::{TaskId, TaskView};
 use crate::utils::{Method, body, params};
 
 pub type Item = TaskView;
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait Tasks<E = ()> {
     async fn list(&self, params: &List) -> Result<Response<Vec<Item>, E>>;
-    async fn retrieve(&self, id: i32) -> Result<Response<Item, E>>;
+    async fn retrieve(&self, id: TaskId) -> Result<Response<Item, E>>;
     async fn run(&self, body: &Create) -> Result<Response<Item, E>>;
-    async fn acknowledge(&self, body: &[i32]) -> Result<Response<Vec<i32>, E>>;
+    async fn acknowledge(&self, body: &[TaskId]) -> Result<Response<Vec<TaskId>, E>>;
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<C: Client> Tasks<C::Extra> for C {
     async fn list(&self, params: &List) -> Result<Response<Vec<Item>, C::Extra>> {
         let path = "/api/tasks/";
@@ -26,7 +28,7 @@ impl<C: Client> Tasks<C::Extra> for C {
             .await
     }
 
-    async fn retrieve(&self, id: i32) -> Result<Response<Item, C::Extra>> {
+    async fn retrieve(&self, id: TaskId) -> Result<Response<Item, C::Extra>> {
         let path = format!("/api/tasks/{id}/");
         self.request_json(Method::GET, &path, params::NONE, body::NONE)
             .await
@@ -38,7 +40,7 @@ impl<C: Client> Tasks<C::Extra> for C {
             .await
     }
 
-    async fn acknowledge(&self, body: &[i32]) -> Result<Response<Vec<i32>, C::Extra>> {
+    async fn acknowledge(&self, body: &[TaskId]) -> Result<Response<Vec<TaskId>, C::Extra>> {
         let path = "/api/tasks/acknowledge/";
         let body = AcknowledgeInput { tasks: body };
         let mut resp: Response<AcknowledgeOutput, C::Extra> = self
@@ -51,10 +53,10 @@ impl<C: Client> Tasks<C::Extra> for C {
 
 #[derive(Serialize)]
 struct AcknowledgeInput<'a> {
-    tasks: &'a [i32],
+    tasks: &'a [TaskId],
 }
 
 #[derive(Deserialize)]
 struct AcknowledgeOutput {
-    result: Vec<i32>,
+    result: Vec<TaskId>,
 }
@@ -0,0 +1,26 @@
+use crate::clients::Client;
+use crate::error::Result;
+use crate::response::Response;
+use crate::schema::api::token::Obtain;
+use crate::schema::model::AuthToken;
+use crate::utils::{Method, params};
+use async_trait::async_trait;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait Token<E = ()> {
+    /// Returns the API token of the given user, creating it if needed; the
+    /// credentials are those of the user, not of the client. Tokens are
+    /// rotated with [`Profile::generate_auth_token`](crate::services::Profile::generate_auth_token).
+    async fn obtain(&self, body: &Obtain) -> Result<Response<AuthToken, E>>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<C: Client> Token<C::Extra> for C {
+    async fn obtain(&self, body: &Obtain) -> Result<Response<AuthToken, C::Extra>> {
+        let path = "/api/token/";
+        self.request_json(Method::POST, path, params::NONE, Some(body))
+            .await
+    }
+}
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::clients::Client;
+use crate::error::Result;
+use crate::response::Response;
+use crate::schema::api::ui_settings::Create;
+use crate::schema::model;
+use crate::utils::{Method, body, params};
+
+pub type Item = model::UiSettings;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait UiSettings<E = ()> {
+    async fn retrieve(&self) -> Result<Response<Item, E>>;
+    async fn update(&self, body: &Create) -> Result<Response<(), E>>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<C: Client> UiSettings<C::Extra> for C {
+    async fn retrieve(&self) -> Result<Response<Item, C::Extra>> {
+        let path = "/api/ui_settings/";
+        self.request_json(Method::GET, path, params::NONE, body::NONE)
+            .await
+    }
+
+    async fn update(&self, body: &Create) -> Result<Response<(), C::Extra>> {
+        let path = "/api/ui_settings/";
+        self.request_unit(Method::POST, path, params::NONE, Some(body))
+            .await
+    }
+}
@@ -4,17 +4,19 @@ use crate::clients::Client;
 use crate::error::Result;
 use crate::response::Response;
 use crate::schema::api::workflows::{Create, List, Patch};
-use crate::schema::model::{Paginated, Workflow};
+use crate::schema::model::{Paginated, ServerFeature, Workflow, WorkflowId};
+use crate::services::require;
 use crate::utils::{Method, body, params};
 
 pub type Item = Workflow;
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait Workflows<E = ()> {
     async fn list(&self, params: &List) -> Result<Response<Paginated<Item>, E>>;
     async fn create(&self, body: &Create) -> Result<Response<Item, E>>;
-    async fn retrieve(&self, id: i32) -> Result<Response<Item, E>>;
-    async fn patch(&self, id: i32, body: &Patch) -> Result<Response<Item, E>>;
+    async fn retrieve(&self, id: WorkflowId) -> Result<Response<Item, E>>;
+    async fn patch(&self, id: WorkflowId, body: &Patch) -> Result<Response<Item, E>>;
     async fn destroy(&self, item: &Item) -> Result<Response<(), E>>;
 
     /*
@@ -22,7 +24,7 @@ pub trait Workflows<E = ()> {
         &self,
         workflow: &mut Item,
         trigger: &trigger::Create,
-    ) -> Result<Response<i32, E>>;
+    ) -> Result<Response<WorkflowTriggerId, E>>;
     async fn patch_trigger(
         &self,
         item: &mut WorkflowTrigger,
@@ -31,20 +33,20 @@ pub trait Workflows<E = ()> {
     async fn remove_trigger(
         &self,
         workflow: &mut Workflow,
-        trigger: i32,
+        trigger: WorkflowTriggerId,
     ) -> Result<Response<(), E>>;
 
     async fn add_action(
         &self,
         workflow: &mut Item,
         action: &action::Create,
-    ) -> Result<Response<i32, E>>;
+    ) -> Result<Response<WorkflowActionId, E>>;
     async fn patch_action(
         &self,
         item: &mut WorkflowAction,
         body: action::Patch,
     ) -> Result<Response<(), E>>;
-    async fn remove_action(&self, workflow: &mut Workflow, action: i32) -> Result<Response<(), E>>;
+    async fn remove_action(&self, workflow: &mut Workflow, action: WorkflowActionId) -> Result<Response<(), E>>;
     */
 
     async fn previous_page(
@@ -55,30 +57,40 @@ pub trait Workflows<E = ()> {
         &self,
         current: &Paginated<Item>,
     ) -> Result<Option<Response<Paginated<Item>, E>>>;
+    async fn page(
+        &self,
+        current: &Paginated<Item>,
+        number: u32,
+    ) -> Result<Option<Response<Paginated<Item>, E>>>;
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl<C: Client> Workflows<C::Extra> for C {
     async fn list(&self, params: &List) -> Result<Response<Paginated<Item>, C::Extra>> {
         let path = "/api/workflows/";
+        require(self, ServerFeature::Workflows, Method::GET, path)?;
         self.request_json(Method::GET, path, params, body::NONE)
             .await
     }
 
     async fn create(&self, body: &Create) -> Result<Response<Item, C::Extra>> {
         let path = "/api/workflows/";
+        require(self, ServerFeature::Workflows, Method::POST, path)?;
         self.request_json(Method::POST, path, params::NONE, Some(body))
             .await
     }
 
-    async fn retrieve(&self, id: i32) -> Result<Response<Item, C::Extra>> {
+    async fn retrieve(&self, id: WorkflowId) -> Result<Response<Item, C::Extra>> {
         let path = format!("/api/workflows/{id}/");
+        require(self, ServerFeature::Workflows, Method::GET, &path)?;
         self.request_json(Method::GET, &path, params::NONE, body::NONE)
             .await
     }
 
-    async fn patch(&self, id: i32, body: &Patch) -> Result<Response<Item, C::Extra>> {
+    async fn patch(&self, id: WorkflowId, body: &Patch) -> Result<Response<Item, C::Extra>> {
         let path = format!("/api/workflows/{id}/");
+        require(self, ServerFeature::Workflows, Method::PATCH, &path)?;
         self.request_json(Method::PATCH, &path, params::NONE, Some(&body))
             .await
     }
@@ -86,6 +98,7 @@ impl<C: Client> Workflows<C::Extra> for C {
     async fn destroy(&self, item: &Item) -> Result<Response<(), C::Extra>> {
         // TODO: destroy triggers and actions
         let path = format!("/api/workflows/{}/", item.id);
+        require(self, ServerFeature::Workflows, Method::DELETE, &path)?;
         self.request_unit(Method::DELETE, &path, params::NONE, body::NONE)
             .await
     }
@@ -95,7 +108,7 @@ impl<C: Client> Workflows<C::Extra> for C {
         &self,
         workflow: &mut Item,
         trigger: &trigger::Create,
-    ) -> Result<Response<i32, C::Extra>> {
+    ) -> Result<Response<WorkflowTriggerId, C::Extra>> {
         unimplemented!();
         /*
         let path = "/api/workflow_triggers/";
@@ -129,7 +142,7 @@ impl<C: Client> Workflows<C::Extra> for C {
     async fn remove_trigger(
         &self,
         workflow: &mut Workflow,
-        trigger: i32,
+        trigger: WorkflowTriggerId,
     ) -> Result<Response<(), C::Extra>> {
         let position = workflow
             .triggers
@@ -153,7 +166,7 @@ impl<C: Client> Workflows<C::Extra> for C {
         &self,
         workflow: &mut Item,
         action: &action::Create,
-    ) -> Result<Response<i32, C::Extra>> {
+    ) -> Result<Response<WorkflowActionId, C::Extra>> {
         unimplemented!();
         /*
         let path = "/api/workflow_actions/";
@@ -181,7 +194,7 @@ impl<C: Client> Workflows<C::Extra> for C {
     async fn remove_action(
         &self,
         workflow: &mut Workflow,
-        action: i32,
+        action: WorkflowActionId,
     ) -> Result<Response<(), C::Extra>> {
         let position =
             workflow
@@ -216,4 +229,12 @@ impl<C: Client> Workflows<C::Extra> for C {
     ) -> Result<Option<Response<Paginated<Item>, C::Extra>>> {
         C::next_page(self, current).await
     }
+
+    async fn page(
+        &self,
+        current: &Paginated<Item>,
+        number: u32,
+    ) -> Result<Option<Response<Paginated<Item>, C::Extra>>> {
+        C::page(self, current, number).await
+    }
 }
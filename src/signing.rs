@@ -0,0 +1,37 @@
+use std::fmt;
+use url::Url;
+
+use crate::utils::Method;
+
+/// The parts of an outgoing request that a signature can cover. Multipart
+/// uploads are streamed and therefore expose an empty `body`.
+#[derive(Clone, Copy, Debug)]
+pub struct SignableRequest<'a> {
+    pub method: Method,
+    pub url: &'a Url,
+    pub body: &'a [u8],
+}
+
+/// Computes a signature for each outgoing request, for deployments where a
+/// signing gateway sits in front of the server. The returned headers are added
+/// to the request right before it is sent, after all other headers.
+///
+/// Any closure with the same signature as `sign` implements this trait.
+pub trait Signer: Send + Sync {
+    fn sign(&self, request: &SignableRequest<'_>) -> anyhow::Result<Vec<(String, String)>>;
+}
+
+impl<F> Signer for F
+where
+    F: Fn(&SignableRequest<'_>) -> anyhow::Result<Vec<(String, String)>> + Send + Sync,
+{
+    fn sign(&self, request: &SignableRequest<'_>) -> anyhow::Result<Vec<(String, String)>> {
+        self(request)
+    }
+}
+
+impl fmt::Debug for dyn Signer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Signer")
+    }
+}
@@ -25,3 +25,101 @@ pub mod body {
 pub mod params {
     pub const NONE: &Vec<(String, String)> = &Vec::new();
 }
+
+/// Waits for the given duration, with the timer of the runtime used by the
+/// `reqwest` client: `tokio`, or the one of the browser.
+#[cfg(all(feature = "reqwest", not(target_arch = "wasm32")))]
+pub(crate) async fn sleep(duration: std::time::Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(all(feature = "reqwest", target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: std::time::Duration) {
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// Replacement of the secrets in logs and recordings.
+#[cfg(any(all(feature = "reqwest", feature = "tracing"), feature = "vcr"))]
+pub(crate) const REDACTED: &str = "REDACTED";
+
+/// Names of the headers, query parameters and JSON fields that hold secrets:
+/// those whose names contain `password`, `token` or `secret` by default,
+/// regardless of case. Used to keep secrets out of the logs of the `reqwest`
+/// client and out of the cassettes of the `vcr` one.
+#[cfg(any(all(feature = "reqwest", feature = "tracing"), feature = "vcr"))]
+#[derive(Clone, Debug)]
+pub(crate) struct Redaction {
+    names: Vec<String>,
+}
+
+#[cfg(any(all(feature = "reqwest", feature = "tracing"), feature = "vcr"))]
+impl Redaction {
+    pub(crate) fn add(&mut self, name: &str) {
+        self.names.push(name.to_lowercase());
+    }
+
+    pub(crate) fn is_secret(&self, name: &str) -> bool {
+        let name = name.to_lowercase();
+        self.names.iter().any(|secret| name.contains(secret))
+    }
+
+    /// Replaces the values of the secret fields, at any depth.
+    pub(crate) fn json(&self, value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(fields) => {
+                for (name, field) in fields {
+                    if self.is_secret(name) {
+                        *field = serde_json::Value::String(REDACTED.to_string());
+                    } else {
+                        self.json(field);
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for item in items {
+                    self.json(item);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(any(all(feature = "reqwest", feature = "tracing"), feature = "vcr"))]
+impl Default for Redaction {
+    fn default() -> Self {
+        Self {
+            names: vec![
+                "password".to_string(),
+                "token".to_string(),
+                "secret".to_string(),
+            ],
+        }
+    }
+}
+
+/// Decodes a value like `R::deserialize`, but fails on fields that `R`
+/// doesn't know about instead of ignoring them, to detect changes of the
+/// schema of the server. Errors give the path of the offending fields, such
+/// as `results[3].title` or `results.3.title`. Used by all clients with the
+/// `strict` feature.
+#[cfg(feature = "strict")]
+pub(crate) fn deserialize_strict<'de, D, R>(de: D) -> Result<R, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    R: Deserialize<'de>,
+{
+    use serde::de::Error;
+    let mut unknown = Vec::new();
+    let mut ignored = |path: serde_ignored::Path<'_>| unknown.push(path.to_string());
+    let de = serde_ignored::Deserializer::new(de, &mut ignored);
+    let value = serde_path_to_error::deserialize(de).map_err(D::Error::custom)?;
+    if unknown.is_empty() {
+        Ok(value)
+    } else {
+        Err(D::Error::custom(format!(
+            "unknown fields: {}",
+            unknown.join(", ")
+        )))
+    }
+}
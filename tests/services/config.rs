@@ -0,0 +1,30 @@
+use paper_plane::clients::Client;
+use paper_plane::schema::api::config;
+use paper_plane::services::Config;
+
+use crate::utils::client;
+
+#[test]
+fn config_patch_preserves_other_fields() {
+    client::run_as_admin(async |client| {
+        let initial = client.config().retrieve().await?.value;
+        assert_eq!(vec![initial.clone()], client.config().list().await?.value);
+
+        let title = "rwkqpfhs-title".to_string();
+        let item = client
+            .config()
+            .patch(&config::patch().app_title(title.clone()))
+            .await?
+            .value;
+        assert_eq!(Some(title), item.app_title);
+        assert_eq!(initial.user_args, item.user_args);
+        assert_eq!(initial.barcode_tag_mapping, item.barcode_tag_mapping);
+        assert_eq!(item, client.config().retrieve().await?.value);
+
+        client
+            .config()
+            .patch(&config::patch().app_title(initial.app_title.unwrap_or_default()))
+            .await?;
+        Ok(())
+    })
+}
@@ -34,7 +34,7 @@ fn test_mail_accounts_basic_crud() {
         assert_eq!(name, item.name);
         assert_eq!(imap, item.imap_server);
         assert_eq!(username, item.username);
-        assert_eq!(password, item.password);
+        assert_eq!(password, item.password.expose());
         assert_eq!(
             1,
             client
@@ -64,7 +64,7 @@ fn test_mail_accounts_basic_crud() {
             .value;
         assert_eq!(imap, item.imap_server);
         assert_eq!(username, item.username);
-        assert_eq!(password, item.password);
+        assert_eq!(password, item.password.expose());
         assert_eq!(
             1,
             client
@@ -1,4 +1,4 @@
-// pub mod config;
+pub mod config;
 pub mod correspondents;
 pub mod custom_fields;
 pub mod document_types;
@@ -6,9 +6,11 @@ pub mod groups;
 pub mod mail_accounts;
 // pub mod mail_rules;
 // pub mod saved_views;
+pub mod search;
 // pub mod share_links;
 // pub mod storage_paths;
 // pub mod tags;
 // pub mod template;
+pub mod ui_settings;
 // pub mod users;
 // pub mod workflows;
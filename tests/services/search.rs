@@ -0,0 +1,31 @@
+use paper_plane::clients::Client;
+use paper_plane::schema::api::search;
+use paper_plane::services::Search;
+
+use crate::utils::client;
+
+#[test]
+fn search_autocomplete() {
+    client::run_as_admin(async |client| {
+        let terms = client
+            .search()
+            .autocomplete(&search::autocomplete("a".to_string()).limit(5))
+            .await?
+            .value;
+        assert!(terms.len() <= 5);
+        Ok(())
+    })
+}
+
+#[test]
+fn search_global() {
+    client::run_as_admin(async |client| {
+        let result = client
+            .search()
+            .global(&search::global("test".to_string()).db_only(true))
+            .await?
+            .value;
+        assert!(result.users.iter().any(|user| user.username == "test"));
+        Ok(())
+    })
+}
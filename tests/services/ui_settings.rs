@@ -0,0 +1,32 @@
+use paper_plane::clients::Client;
+use paper_plane::schema::api::ui_settings;
+use paper_plane::services::UiSettings;
+
+use crate::utils::client;
+
+#[test]
+fn ui_settings_roundtrip() {
+    client::run_as_admin(async |client| {
+        let initial = client.ui_settings().retrieve().await?.value;
+        assert_eq!("test", initial.user.username);
+        assert!(initial.user.is_superuser);
+
+        let mut settings = initial.settings.clone();
+        settings["dark_mode"] = serde_json::json!({ "enabled": "true" });
+        client
+            .ui_settings()
+            .update(&ui_settings::create(settings))
+            .await?;
+        let updated = client.ui_settings().retrieve().await?.value;
+        assert_eq!(
+            serde_json::json!({ "enabled": "true" }),
+            updated.settings["dark_mode"]
+        );
+
+        client
+            .ui_settings()
+            .update(&ui_settings::Create::from(&initial))
+            .await?;
+        Ok(())
+    })
+}
@@ -2,7 +2,7 @@ use paper_plane::auth::Auth;
 use paper_plane::clients::{Client as _, reqwest::Client};
 use paper_plane::error::Error;
 use paper_plane::schema::api::users;
-use paper_plane::schema::model::PermissionClass;
+use paper_plane::schema::model::{GroupId, PermissionClass};
 use paper_plane::services::Users;
 use tokio;
 
@@ -32,7 +32,7 @@ where
         .block_on(async { f(get()).await.unwrap_or_else(|e| panic!("{:?}", e)) })
 }
 
-pub fn run_as_user<F, R>(groups: Vec<i32>, permissions: Vec<PermissionClass>, f: F) -> R
+pub fn run_as_user<F, R>(groups: Vec<GroupId>, permissions: Vec<PermissionClass>, f: F) -> R
 where
     F: AsyncFnOnce(Client) -> Result<R, Error>,
 {